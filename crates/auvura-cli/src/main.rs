@@ -209,6 +209,20 @@ fn pii_type_name(pii_type: PiiType) -> &'static str {
         PiiType::PassportNumber => "passport",
         PiiType::NationalId => "national_id",
         PiiType::PhysicalAddress => "address",
+        PiiType::Timestamp => "timestamp",
+        PiiType::MedicareBeneficiaryId => "mbi",
+        PiiType::SocialHandle => "social_handle",
+        PiiType::AustralianAbn => "abn",
+        PiiType::AustralianAcn => "acn",
+        PiiType::Secret => "secret",
+        PiiType::PartiallyMasked => "partially_masked",
+        PiiType::VatNumber => "vat_number",
+        PiiType::GpsCoordinate => "gps_coordinate",
+        PiiType::DateOfBirth => "date_of_birth",
+        PiiType::MacAddress => "mac_address",
+        PiiType::UsPassport => "us_passport",
+        PiiType::DriversLicense => "drivers_license",
+        PiiType::BankAccountRouting => "bank_account_routing",
         PiiType::Other(name) => name,
     }
 }
@@ -59,6 +59,19 @@ fn bench_redact_long_text(c: &mut Criterion) {
     });
 }
 
+fn bench_redact_blocklist_absent(c: &mut Criterion) {
+    // None of the terms' first bytes occur, so the blocklist scan is skipped
+    let terms: Vec<String> = (0..200).map(|i| format!("Project{i}")).collect();
+    let policy = RedactionPolicy::builder()
+        .with_blocklist(terms.iter().map(String::as_str).collect())
+        .build();
+    let redactor = Redactor::new(vec![Box::new(EmailDetector::new())], policy);
+    let text = "this is lowercase text mentioning no codenames at all, repeated. ".repeat(50);
+    c.bench_function("redact_blocklist_200_terms_absent", |b| {
+        b.iter(|| redactor.redact(black_box(&text)))
+    });
+}
+
 fn bench_json_redact_simple(c: &mut Criterion) {
     let jr = JsonRedactor::new(build_redactor());
     let json = r#"{"name": "Alice", "email": "alice@example.com", "age": 30}"#;
@@ -97,6 +110,7 @@ criterion_group!(
     bench_redact_email,
     bench_redact_multiple_pii,
     bench_redact_long_text,
+    bench_redact_blocklist_absent,
     bench_json_redact_simple,
     bench_json_redact_nested,
     bench_json_redact_no_pii,
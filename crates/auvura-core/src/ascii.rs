@@ -0,0 +1,133 @@
+//! Byte-level ASCII detectors that need only `alloc`.
+//!
+//! These scan raw bytes instead of compiling regexes, so they build without
+//! the `std` feature for embedded or edge targets. Offsets are UTF-8 byte
+//! offsets like every other [`Detection`]; since only ASCII digits and
+//! separators are matched, spans always fall on char boundaries.
+//!
+//! Word boundaries are ASCII-only: a match must not touch an ASCII letter,
+//! digit, or `_` on either side. Any other character, including non-ASCII
+//! text, counts as a boundary.
+
+use crate::detection::{Confidence, Detection};
+use crate::types::PiiType;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+/// SSA validity of SSN components: area not 000, 666, or 900–999, group
+/// not 00, serial not 0000 (<https://www.ssa.gov/employer/stateweb.htm>)
+pub fn is_valid_ssn_parts(area: u16, group: u8, serial: u16) -> bool {
+    area != 0 && area != 666 && area < 900 && group != 0 && serial != 0
+}
+
+/// US Social Security Numbers as `###-##-####` or nine bare digits, in
+/// order. With `validate`, numbers outside the SSA ranges are skipped.
+pub fn detect_ssn(text: &str, validate: bool) -> Vec<Detection> {
+    let bytes = text.as_bytes();
+    let is_word = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+    let mut detections = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() || (i > 0 && is_word(bytes[i - 1])) {
+            i += 1;
+            continue;
+        }
+        let Some(end) = ssn_shape_at(bytes, i) else {
+            i += 1;
+            continue;
+        };
+        if bytes.get(end).is_some_and(|&b| is_word(b)) {
+            i += 1;
+            continue;
+        }
+
+        let mut digits = bytes[i..end].iter().filter(|b| b.is_ascii_digit());
+        let mut number = |n: usize| {
+            (&mut digits)
+                .take(n)
+                .fold(0u16, |acc, &b| acc * 10 + u16::from(b - b'0'))
+        };
+        let (area, group, serial) = (number(3), number(2) as u8, number(4));
+        if !validate || is_valid_ssn_parts(area, group, serial) {
+            detections.push(Detection {
+                pii_type: PiiType::Ssn,
+                confidence: Confidence::High,
+                start: i,
+                end,
+                original: text[i..end].to_string(),
+                metadata: None,
+            });
+        }
+        i = end;
+    }
+    detections
+}
+
+/// End of an SSN-shaped run starting at `start`, if there is one
+fn ssn_shape_at(bytes: &[u8], start: usize) -> Option<usize> {
+    let digits = |from: usize, n: usize| {
+        bytes
+            .get(from..from + n)
+            .is_some_and(|run| run.iter().all(u8::is_ascii_digit))
+    };
+    if digits(start, 3)
+        && bytes.get(start + 3) == Some(&b'-')
+        && digits(start + 4, 2)
+        && bytes.get(start + 6) == Some(&b'-')
+        && digits(start + 7, 4)
+    {
+        Some(start + 11)
+    } else if digits(start, 9) {
+        Some(start + 9)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn found(text: &str) -> Vec<&str> {
+        detect_ssn(text, true)
+            .iter()
+            .map(|d| &text[d.start..d.end])
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_hyphenated_and_bare_ssn() {
+        let detections = detect_ssn("SSN: 123-45-6789, alt 234567890.", true);
+        assert_eq!(detections.len(), 2);
+        assert_eq!(detections[0].pii_type, PiiType::Ssn);
+        assert_eq!(detections[0].original, "123-45-6789");
+        assert_eq!((detections[0].start, detections[0].end), (5, 16));
+        assert_eq!(detections[1].original, "234567890");
+    }
+
+    #[test]
+    fn test_requires_ascii_word_boundaries() {
+        assert!(found("x123-45-6789").is_empty());
+        assert!(found("123-45-67890").is_empty());
+        assert!(found("1234567890").is_empty());
+        assert_eq!(found("é123-45-6789é"), vec!["123-45-6789"]);
+    }
+
+    #[test]
+    fn test_validation_rejects_ssa_invalid_ranges() {
+        assert!(found("000-12-3456 666-12-3456 900-12-3456").is_empty());
+        assert!(found("123-00-4567 123-45-0000").is_empty());
+        assert_eq!(detect_ssn("000-12-3456", false).len(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_matches_regex_detector() {
+        use crate::detector::PiiDetector;
+        use crate::detectors::ssn::SSNDetector;
+
+        let text = "ids 123-45-6789 and 987654321; bad 000-11-2222, 12-345-6789";
+        let regex: Vec<_> = SSNDetector::new().detect(text);
+        assert_eq!(detect_ssn(text, true), regex);
+    }
+}
@@ -283,6 +283,7 @@ mod tests {
             start: 15,
             end: 35,
             original: "john@example.com".to_string(),
+            metadata: None,
         };
         let event = AuditEvent::from_detection(&detection, "████.███@███████.com");
         match event {
@@ -0,0 +1,397 @@
+use crate::redactor::Redactor;
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// Targeted redactor for crash logs and variable dumps.
+///
+/// Crash logs mix PII with structure that pattern detectors misread: hex
+/// addresses, `file.rs:42:5` locations, and dotted library versions look
+/// like phone numbers or IP addresses. This redactor splits each line into
+/// field *values* and the text around them:
+///
+/// - `key = value` / `key=value` variable dumps, including frame arguments
+///   like `login (ctx=0x7ffd, email="jane@example.com")`
+/// - `field: value` object prints, like `User { id: 7, email: "a@b.com" }`
+///   (`::` in symbols and `file:line` locations are not fields)
+///
+/// A value runs to the next `,` or `;`, or closing bracket, outside quotes
+/// and nested brackets, else to the end of the line. Values that are hex
+/// addresses, booleans, or (unless [`scrub_paths`] is set) file paths are
+/// kept. Everything else, numbers included, is redacted by the wrapped
+/// [`Redactor`], so `attempts = 3` survives but a card number doesn't.
+/// Text outside values, such as stack frames and panic messages, is
+/// redacted too, except for words that are hex addresses, `a::b` symbols,
+/// `file.rs:42:5` locations, or unscrubbed paths. Detectors run on whole
+/// lines, and their hits on kept spans are dropped.
+///
+/// [`scrub_paths`]: CrashLogRedactor::scrub_paths
+pub struct CrashLogRedactor {
+    redactor: Redactor,
+    scrub_paths: bool,
+}
+
+impl CrashLogRedactor {
+    /// Create a new `CrashLogRedactor` that keeps path values intact.
+    pub fn new(redactor: Redactor) -> Self {
+        Self {
+            redactor,
+            scrub_paths: false,
+        }
+    }
+
+    /// Also redact PII inside path values, e.g. a username in `/home/jane`
+    /// detected by a custom detector, or an email in a path segment.
+    pub fn scrub_paths(mut self, enabled: bool) -> Self {
+        self.scrub_paths = enabled;
+        self
+    }
+
+    /// Redact PII from a crash log, preserving its structure.
+    pub fn redact_log<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let mut out = String::new();
+        let mut copied = 0;
+        let mut line_start = 0;
+
+        for line in text.split_inclusive('\n') {
+            // Detectors see the whole line, so keys like `card =` count as
+            // context; only their hits on structural spans are dropped
+            let keep = self.structural_spans(line);
+            if let Cow::Owned(redacted) = self.redactor.redact_except(line, &keep) {
+                out.push_str(&text[copied..line_start]);
+                out.push_str(&redacted);
+                copied = line_start + line.len();
+            }
+            line_start += line.len();
+        }
+
+        if copied == 0 {
+            return Cow::Borrowed(text);
+        }
+        out.push_str(&text[copied..]);
+        Cow::Owned(out)
+    }
+
+    /// Consume the `CrashLogRedactor` and return the inner [`Redactor`].
+    pub fn into_inner(self) -> Redactor {
+        self.redactor
+    }
+
+    /// Spans of `line` copied verbatim: structural values, and structural
+    /// words outside values
+    fn structural_spans(&self, line: &str) -> Vec<(usize, usize)> {
+        let mut spans = Vec::new();
+        let mut pos = 0;
+        loop {
+            let value = next_value(line, pos);
+            let prose_end = value.map_or(line.len(), |(start, _)| start);
+            spans.extend(
+                words(line, pos..prose_end).filter(|&(s, e)| self.is_structural(&line[s..e])),
+            );
+            let Some((start, end)) = value else {
+                return spans;
+            };
+            if self.keeps(&line[start..end]) {
+                spans.push((start, end));
+            }
+            pos = end;
+        }
+    }
+
+    /// Whether a value is structural and copied without scanning
+    fn keeps(&self, value: &str) -> bool {
+        let unquoted = value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .unwrap_or(value);
+        is_address(unquoted)
+            || matches!(unquoted, "true" | "false" | "null" | "nil" | "None")
+            || (!self.scrub_paths && is_path(unquoted))
+    }
+
+    /// Whether a word outside values is structural and copied verbatim
+    fn is_structural(&self, word: &str) -> bool {
+        is_address(word)
+            || is_symbol(word)
+            || is_location(word)
+            || (!self.scrub_paths && is_path(word))
+    }
+}
+
+fn is_key_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'_' | b'.' | b'[' | b']')
+}
+
+/// Span of the first field value in `line` at or after `from`
+fn next_value(line: &str, from: usize) -> Option<(usize, usize)> {
+    let bytes = line.as_bytes();
+    let mut i = from;
+    while i < bytes.len() {
+        let b = bytes[i];
+        let prev = i.checked_sub(1).map(|p| bytes[p]);
+        let next = bytes.get(i + 1).copied();
+
+        let separator = match b {
+            // `key = value`, but not `==`, `!=`, `<=`, `>=`, `=>`
+            b'=' => {
+                !matches!(prev, Some(b'=' | b'!' | b'<' | b'>'))
+                    && !matches!(next, Some(b'=' | b'>'))
+                    && key_before(bytes, i, true)
+            }
+            // `field: value`, but not `a::b` or `file.rs:42`
+            b':' => {
+                prev != Some(b':')
+                    && matches!(next, Some(b' ' | b'\t'))
+                    && key_before(bytes, i, false)
+            }
+            b'"' => {
+                // Skip quoted text outside values
+                i = closing_quote(bytes, i).unwrap_or(bytes.len());
+                continue;
+            }
+            _ => false,
+        };
+        if !separator {
+            i += 1;
+            continue;
+        }
+
+        let mut start = i + 1;
+        while start < bytes.len() && matches!(bytes[start], b' ' | b'\t') {
+            start += 1;
+        }
+        let end = value_end(bytes, start);
+        if end > start {
+            return Some((start, end));
+        }
+        i = start.max(i + 1);
+    }
+    None
+}
+
+/// Whether `bytes[..sep]` ends with a key (spaces allowed before `=`)
+fn key_before(bytes: &[u8], sep: usize, allow_space: bool) -> bool {
+    let mut end = sep;
+    if allow_space {
+        while end > 0 && matches!(bytes[end - 1], b' ' | b'\t') {
+            end -= 1;
+        }
+    }
+    let mut start = end;
+    while start > 0 && is_key_byte(bytes[start - 1]) {
+        start -= 1;
+    }
+    start < end && (bytes[start].is_ascii_alphabetic() || bytes[start] == b'_')
+}
+
+/// Index just past the quote closing the one at `open`
+fn closing_quote(bytes: &[u8], open: usize) -> Option<usize> {
+    let mut i = open + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return Some(i + 1),
+            b'\n' => return None,
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// End of the value starting at `start` (trailing whitespace excluded)
+fn value_end(bytes: &[u8], start: usize) -> usize {
+    let mut depth = 0usize;
+    let mut i = start;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => {
+                i = closing_quote(bytes, i).unwrap_or(bytes.len());
+                continue;
+            }
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' if depth == 0 => break,
+            b')' | b']' | b'}' => depth -= 1,
+            b',' | b';' if depth == 0 => break,
+            b'\r' | b'\n' => break,
+            _ => {}
+        }
+        i += 1;
+    }
+    let mut end = i.min(bytes.len());
+    while end > start && bytes[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    end
+}
+
+fn is_address(value: &str) -> bool {
+    value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+        .is_some_and(|hex| !hex.is_empty() && hex.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+/// Spans of the ASCII-whitespace separated words in `line[range]`, with
+/// wrapping brackets, quotes, and punctuation trimmed
+fn words(line: &str, range: Range<usize>) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let wrapping = |c: char| "()[]{}<>'\"`,;".contains(c);
+    let mut pos = range.start;
+    std::iter::from_fn(move || {
+        let bytes = line.as_bytes();
+        while pos < range.end && bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        if pos >= range.end {
+            return None;
+        }
+        let start = pos;
+        while pos < range.end && !bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        let word = &line[start..pos];
+        let lead = word.trim_start_matches(wrapping);
+        let core = lead.trim_end_matches(wrapping);
+        let core_start = start + (word.len() - lead.len());
+        Some((core_start, core_start + core.len()))
+    })
+}
+
+/// `module::function` style symbols
+fn is_symbol(word: &str) -> bool {
+    word.contains("::") && !word.contains('@')
+}
+
+/// `file.rs:42` or `file.rs:42:5` source locations
+fn is_location(word: &str) -> bool {
+    let mut file = word;
+    for _ in 0..2 {
+        match file.rsplit_once(':') {
+            Some((head, line)) if !line.is_empty() && line.bytes().all(|b| b.is_ascii_digit()) => {
+                file = head
+            }
+            _ => break,
+        }
+    }
+    // The extension must be alphabetic, so `10.0.0.1:8080` isn't a location
+    file.len() < word.len()
+        && !file.contains('@')
+        && file.rsplit_once('.').is_some_and(|(name, ext)| {
+            !name.is_empty() && !ext.is_empty() && ext.bytes().all(|b| b.is_ascii_alphabetic())
+        })
+}
+
+fn is_path(value: &str) -> bool {
+    let b = value.as_bytes();
+    let drive = b.len() > 2 && b[0].is_ascii_alphabetic() && b[1] == b':' && b[2] == b'\\';
+    !value.contains(char::is_whitespace)
+        && (drive
+            || ["/", "./", "../", "~/"]
+                .iter()
+                .any(|p| value.starts_with(p)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::{
+        credit_card::CreditCardDetector, email::EmailDetector, ip::Ipv4Detector, ssn::SSNDetector,
+    };
+    use crate::policy::RedactionPolicy;
+
+    fn crash_redactor() -> CrashLogRedactor {
+        CrashLogRedactor::new(Redactor::new(
+            vec![
+                Box::new(EmailDetector::new()),
+                Box::new(Ipv4Detector::new()),
+            ],
+            RedactionPolicy::default(),
+        ))
+    }
+
+    #[test]
+    fn test_redacts_email_in_variable_dump() {
+        let input = "\
+#1 0x000055d4c2a1 in auth::login (ctx=0x7ffd1234, email=\"jane@example.com\") at src/auth.rs:88
+locals:
+    email = \"jane@example.com\"
+    attempts = 3
+";
+        let expected = "\
+#1 0x000055d4c2a1 in auth::login (ctx=0x7ffd1234, email=\"████@███████.com\") at src/auth.rs:88
+locals:
+    email = \"████@███████.com\"
+    attempts = 3
+";
+        assert_eq!(crash_redactor().redact_log(input), expected);
+    }
+
+    #[test]
+    fn test_redacts_object_field_prints() {
+        let input = r#"User { id: 42, email: "bob@corp.org", home: "/home/bob" }"#;
+        assert_eq!(
+            crash_redactor().redact_log(input),
+            r#"User { id: 42, email: "███@████.org", home: "/home/bob" }"#
+        );
+    }
+
+    #[test]
+    fn test_frames_and_symbols_pass_through() {
+        // A plain redactor would mask the dotted version as an IPv4 address
+        let input = "#3 0x00007f3a in worker::run () from /usr/lib/libapp.so.1.2.3.4\n\
+                     thread 'main' panicked at src/main.rs:10:5\n";
+        let redactor = crash_redactor();
+        assert_ne!(redactor.redactor.redact(input), input);
+        assert!(matches!(redactor.redact_log(input), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_scrub_paths_is_opt_in() {
+        let input = "config = /home/jane@example.com/app.toml";
+        assert_eq!(crash_redactor().redact_log(input), input);
+        assert_eq!(
+            crash_redactor().scrub_paths(true).redact_log(input),
+            "config = /home/████@███████.com/app.toml"
+        );
+    }
+
+    #[test]
+    fn test_numbers_claimed_by_detectors_are_redacted() {
+        let redactor = CrashLogRedactor::new(Redactor::new(
+            vec![
+                Box::new(CreditCardDetector::new()),
+                Box::new(SSNDetector::new()),
+            ],
+            RedactionPolicy::default(),
+        ));
+        let input = "card = 4111111111111111\nssn = 123456789\nattempts = 3\n";
+        let result = redactor.redact_log(input);
+        assert!(!result.contains("4111111111111111"), "{result}");
+        assert!(!result.contains("123456789"), "{result}");
+        assert!(result.contains("attempts = 3"));
+    }
+
+    #[test]
+    fn test_redacts_pii_outside_fields() {
+        let input = "thread 'main' panicked at 'no user jane@example.com', src/auth.rs:88:5\n\
+                     #2 0x00007f3a in auth::lookup () from /usr/lib/libauth.so.1.2.3.4\n";
+        assert_eq!(
+            crash_redactor().redact_log(input),
+            "thread 'main' panicked at 'no user ████@███████.com', src/auth.rs:88:5\n\
+             #2 0x00007f3a in auth::lookup () from /usr/lib/libauth.so.1.2.3.4\n"
+        );
+        // Addresses still count as PII in prose
+        assert_eq!(
+            crash_redactor().redact_log("connect failed to 10.0.0.1:8080"),
+            "connect failed to ████████:8080"
+        );
+    }
+
+    #[test]
+    fn test_comparisons_are_not_fields() {
+        let input = "assert failed: left == right (a@b.com)\nx >= y";
+        // `failed:` starts a value; `==` and `>=` never do
+        assert_eq!(
+            crash_redactor().redact_log(input),
+            "assert failed: left == right (█@█.com)\nx >= y"
+        );
+    }
+}
@@ -0,0 +1,261 @@
+use crate::redactor::Redactor;
+use crate::types::PiiType;
+use std::borrow::Cow;
+
+/// Header-aware redactor for CSV and TSV.
+///
+/// The first record is read as a header. Columns whose header names a PII
+/// type in the configured map (ASCII case-insensitive, surrounding
+/// whitespace ignored) are redacted wholesale as that type, with no
+/// detection: a column named `email` is masked whatever its cells contain.
+/// All other columns are scanned with the redactor's detectors, so PII in
+/// free-text columns like `notes` is still found. The header row itself is
+/// copied verbatim.
+///
+/// Quoted fields (RFC 4180) may contain delimiters, doubled quotes, and
+/// line breaks. Unchanged fields are copied verbatim, including their
+/// quoting; redacted fields are re-quoted when their new value needs it.
+/// Malformed input is redacted on a best-effort basis and never rejected.
+pub struct CsvRedactor {
+    redactor: Redactor,
+    delimiter: char,
+    columns: Vec<(String, PiiType)>,
+    scan_untyped: bool,
+}
+
+impl CsvRedactor {
+    /// Create a new `CsvRedactor` for comma-separated input that scans
+    /// every column.
+    pub fn new(redactor: Redactor) -> Self {
+        Self {
+            redactor,
+            delimiter: ',',
+            columns: Vec::new(),
+            scan_untyped: true,
+        }
+    }
+
+    /// Use a different field delimiter, e.g. `'\t'` for TSV.
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Redact columns whose header is `name` wholesale as `pii_type`.
+    pub fn with_column(mut self, name: &str, pii_type: PiiType) -> Self {
+        self.columns.push((name.trim().to_string(), pii_type));
+        self
+    }
+
+    /// Map several header names to types at once, e.g.
+    /// `[("email", PiiType::Email), ("ssn", PiiType::Ssn)]`.
+    pub fn with_columns(mut self, columns: &[(&str, PiiType)]) -> Self {
+        for &(name, pii_type) in columns {
+            self = self.with_column(name, pii_type);
+        }
+        self
+    }
+
+    /// Scan columns without a mapped type (default: true). When off, they
+    /// are copied verbatim and only typed columns are redacted.
+    pub fn scan_untyped(mut self, enabled: bool) -> Self {
+        self.scan_untyped = enabled;
+        self
+    }
+
+    /// Redact a CSV/TSV document, preserving its layout.
+    pub fn redact_csv<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        let mut out = String::with_capacity(input.len());
+        let mut changed = false;
+        let mut header = true;
+        let mut types: Vec<Option<PiiType>> = Vec::new();
+        let mut column = 0;
+        let mut pos = 0;
+
+        while pos < input.len() {
+            let end = pos + self.field_len(&input[pos..]);
+            let raw = &input[pos..end];
+            if header {
+                types.push(self.column_type(&unquote(raw)));
+                out.push_str(raw);
+            } else {
+                let pii_type = types.get(column).copied().flatten();
+                match self.redact_field(raw, pii_type) {
+                    Some(redacted) => {
+                        out.push_str(&redacted);
+                        changed = true;
+                    }
+                    None => out.push_str(raw),
+                }
+            }
+
+            // Field terminator: a delimiter, a line break, or the end
+            let rest = &input[end..];
+            if rest.starts_with(self.delimiter) {
+                out.push(self.delimiter);
+                pos = end + self.delimiter.len_utf8();
+                column += 1;
+                if pos == input.len() {
+                    // Trailing empty field
+                    break;
+                }
+            } else {
+                let newline = if rest.starts_with("\r\n") {
+                    2
+                } else {
+                    usize::from(rest.starts_with('\n'))
+                };
+                out.push_str(&rest[..newline]);
+                pos = end + newline;
+                column = 0;
+                header = false;
+                if newline == 0 {
+                    break;
+                }
+            }
+        }
+
+        if changed {
+            Cow::Owned(out)
+        } else {
+            Cow::Borrowed(input)
+        }
+    }
+
+    /// Consume this wrapper and return the inner `Redactor`.
+    pub fn into_inner(self) -> Redactor {
+        self.redactor
+    }
+
+    fn column_type(&self, header: &str) -> Option<PiiType> {
+        let header = header.trim();
+        self.columns
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(header))
+            .map(|&(_, pii_type)| pii_type)
+    }
+
+    /// Byte length of the raw field at the start of `s`, quotes included
+    fn field_len(&self, s: &str) -> usize {
+        let mut from = 0;
+        if let Some(quoted) = s.strip_prefix('"') {
+            // Skip to the closing quote; `""` is an escaped quote
+            let mut i = 0;
+            let bytes = quoted.as_bytes();
+            while i < bytes.len() {
+                if bytes[i] == b'"' {
+                    if bytes.get(i + 1) == Some(&b'"') {
+                        i += 2;
+                        continue;
+                    }
+                    from = i + 2;
+                    break;
+                }
+                i += 1;
+            }
+            if i == bytes.len() {
+                // Unterminated quote: the field runs to the end
+                return s.len();
+            }
+        }
+        s[from..]
+            .find([self.delimiter, '\n', '\r'])
+            .map_or(s.len(), |i| from + i)
+    }
+
+    /// Redacted replacement for a raw field, or `None` if it is unchanged
+    fn redact_field(&self, raw: &str, pii_type: Option<PiiType>) -> Option<String> {
+        let value = unquote(raw);
+        if value.is_empty() {
+            return None;
+        }
+        let redacted = match pii_type {
+            Some(pii_type) => self.redactor.redact_as(&value, pii_type),
+            None if self.scan_untyped => self.redactor.redact(&value).into_owned(),
+            None => return None,
+        };
+        if redacted == value {
+            return None;
+        }
+        let needs_quotes =
+            raw.starts_with('"') || redacted.contains([self.delimiter, '"', '\n', '\r']);
+        Some(if needs_quotes {
+            format!("\"{}\"", redacted.replace('"', "\"\""))
+        } else {
+            redacted
+        })
+    }
+}
+
+/// Decoded value of a raw field: surrounding quotes removed, `""` → `"`
+fn unquote(raw: &str) -> Cow<'_, str> {
+    match raw.strip_prefix('"') {
+        Some(inner) => {
+            let inner = inner.strip_suffix('"').unwrap_or(inner);
+            if inner.contains("\"\"") {
+                Cow::Owned(inner.replace("\"\"", "\""))
+            } else {
+                Cow::Borrowed(inner)
+            }
+        }
+        None => Cow::Borrowed(raw),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::{email::EmailDetector, phone_number::PhoneNumberDetector};
+    use crate::policy::RedactionPolicy;
+
+    fn csv_redactor() -> CsvRedactor {
+        let redactor = Redactor::new(
+            vec![
+                Box::new(EmailDetector::new()),
+                Box::new(PhoneNumberDetector::new()),
+            ],
+            RedactionPolicy::default(),
+        );
+        CsvRedactor::new(redactor).with_column("email", PiiType::Email)
+    }
+
+    #[test]
+    fn test_typed_column_redacted_by_name_and_notes_scanned() {
+        let input = "id,Email,notes,city\n\
+                     1,jane@example.com,call +1 202 555 0123 after 5,Paris\n\
+                     2,not-an-address,prefers mail,Lyon\n";
+        assert_eq!(
+            csv_redactor().redact_csv(input),
+            "id,Email,notes,city\n\
+             1,████@███████.com,call +█ ███ ███ ████ after 5,Paris\n\
+             2,██████████████,prefers mail,Lyon\n"
+        );
+    }
+
+    #[test]
+    fn test_quoted_fields_and_tsv() {
+        let input = "notes,email\r\n\"Hi, mail bob@corp.org \"\"now\"\"\",\"x@y.io\"\r\n";
+        assert_eq!(
+            csv_redactor().redact_csv(input),
+            "notes,email\r\n\"Hi, mail ███@████.org \"\"now\"\"\",\"█@█.io\"\r\n"
+        );
+
+        let tsv = csv_redactor().delimiter('\t');
+        assert_eq!(
+            tsv.redact_csv("email\tcity\njane@example.com\tRome"),
+            "email\tcity\n████@███████.com\tRome"
+        );
+    }
+
+    #[test]
+    fn test_untyped_columns_can_be_left_alone() {
+        let redactor = csv_redactor().scan_untyped(false);
+        assert_eq!(
+            redactor.redact_csv("email,notes\na@b.com,c@d.com\n"),
+            "email,notes\n█@█.com,c@d.com\n"
+        );
+        // Clean input is borrowed
+        let input = "email,notes\n,fine\n";
+        assert!(matches!(csv_redactor().redact_csv(input), Cow::Borrowed(_)));
+    }
+}
@@ -0,0 +1,150 @@
+//! Detection results: what a detector found and where.
+//!
+//! These types only need `alloc`, so they are available without the `std`
+//! feature, together with [`PiiType`] and the [`ascii`](crate::ascii)
+//! detectors. [`detector`](crate::detector) re-exports them.
+
+use crate::types::PiiType;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use zeroize::Zeroize;
+
+/// Confidence level for a PII detection
+///
+/// Determines how certain we are that the detected text is actually PII.
+/// Used for filtering and overlap resolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Confidence {
+    /// High confidence: regex pattern + checksum/validation (e.g., Luhn, mod-97)
+    /// Very few false positives
+    High,
+    /// Medium confidence: regex pattern without checksum validation
+    /// Some false positives possible (e.g., random digit sequences matching phone format)
+    #[default]
+    Medium,
+    /// Low confidence: heuristic or pattern matching
+    /// Higher false positive rate (e.g., addresses based on street type keywords)
+    Low,
+}
+
+impl Confidence {
+    /// Returns numeric value for comparison (higher = more confident)
+    fn value(&self) -> u8 {
+        match self {
+            Confidence::High => 3,
+            Confidence::Medium => 2,
+            Confidence::Low => 1,
+        }
+    }
+}
+
+impl PartialOrd for Confidence {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Confidence {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.value().cmp(&other.value())
+    }
+}
+
+/// Detection result with memory safety guarantees
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Detection {
+    pub pii_type: PiiType,
+    pub confidence: Confidence,
+    pub start: usize,     // UTF-8 byte offset (NOT char index)
+    pub end: usize,       // UTF-8 byte offset
+    pub original: String, // Original text – will be zeroized on drop
+    /// Optional detector-supplied tags (rule id, capture group, ...).
+    /// Carried into `RedactionReport` entries; apart from
+    /// [`OPAQUE_METADATA_KEY`], never used for redaction. Keys and values
+    /// are zeroized on drop like `original`; detectors must not copy raw
+    /// PII into them.
+    pub metadata: Option<DetectionMetadata>,
+}
+
+/// Free-form key/value tags attached to a detection (ordered for stable output)
+pub type DetectionMetadata = BTreeMap<String, String>;
+
+/// Metadata key (with value `"true"`) for a value whose format is unknown:
+/// it is masked whole, one `█` per character, instead of with its type's
+/// format-preserving mask, which could leave parts of it visible
+pub const OPAQUE_METADATA_KEY: &str = "opaque";
+
+impl Detection {
+    /// Whether the detector marked the value as opaque (see
+    /// [`OPAQUE_METADATA_KEY`])
+    pub fn is_opaque(&self) -> bool {
+        self.metadata
+            .as_ref()
+            .and_then(|m| m.get(OPAQUE_METADATA_KEY))
+            .is_some_and(|v| v == "true")
+    }
+
+    /// Type and offsets of this detection, without the value
+    pub fn span(&self) -> Span {
+        Span {
+            pii_type: self.pii_type,
+            start: self.start,
+            end: self.end,
+        }
+    }
+
+    /// `start..end` as char indices into `text`, the string the detection
+    /// was made in, for editors that address text by character rather than
+    /// byte. Chars are Unicode scalar values: an emoji built from several
+    /// code points (a flag, a ZWJ sequence) counts as several.
+    pub fn char_range(&self, text: &str) -> (usize, usize) {
+        let start = text[..self.start].chars().count();
+        (start, start + text[self.start..self.end].chars().count())
+    }
+}
+
+/// Where a detection is, without what it holds: a [`Detection`] minus the
+/// `original` copy, for callers that only need counts or positions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub pii_type: PiiType,
+    pub start: usize, // UTF-8 byte offset
+    pub end: usize,   // UTF-8 byte offset
+}
+
+impl Span {
+    /// The [`Detection`] for this span of `text`, copying out its value
+    pub fn to_detection(self, text: &str, confidence: Confidence) -> Detection {
+        Detection {
+            pii_type: self.pii_type,
+            confidence,
+            start: self.start,
+            end: self.end,
+            original: String::from(&text[self.start..self.end]),
+            metadata: None,
+        }
+    }
+}
+
+impl Zeroize for Detection {
+    fn zeroize(&mut self) {
+        self.original.zeroize();
+        if let Some(metadata) = self.metadata.take() {
+            for (mut key, mut value) in metadata {
+                key.zeroize();
+                value.zeroize();
+            }
+        }
+    }
+}
+
+impl Drop for Detection {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
@@ -1,74 +1,38 @@
+pub use crate::detection::{Confidence, Detection, DetectionMetadata, Span, OPAQUE_METADATA_KEY};
+use crate::detectors::is_bidi_control;
+use crate::redactor::RedactError;
 use crate::types::PiiType;
 use aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use zeroize::Zeroize;
 
-/// Confidence level for a PII detection
-///
-/// Determines how certain we are that the detected text is actually PII.
-/// Used for filtering and overlap resolution.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub enum Confidence {
-    /// High confidence: regex pattern + checksum/validation (e.g., Luhn, mod-97)
-    /// Very few false positives
-    High,
-    /// Medium confidence: regex pattern without checksum validation
-    /// Some false positives possible (e.g., random digit sequences matching phone format)
-    #[default]
-    Medium,
-    /// Low confidence: heuristic or pattern matching
-    /// Higher false positive rate (e.g., addresses based on street type keywords)
-    Low,
-}
-
-impl Confidence {
-    /// Returns numeric value for comparison (higher = more confident)
-    fn value(&self) -> u8 {
-        match self {
-            Confidence::High => 3,
-            Confidence::Medium => 2,
-            Confidence::Low => 1,
-        }
-    }
-}
-
-impl PartialOrd for Confidence {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for Confidence {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.value().cmp(&other.value())
-    }
-}
-
-/// Detection result with memory safety guarantees
+/// Time one detector spent on a document (see `Redactor::redact_profiled`)
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Detection {
-    pub pii_type: PiiType,
-    pub confidence: Confidence,
-    pub start: usize,     // UTF-8 byte offset (NOT char index)
-    pub end: usize,       // UTF-8 byte offset
-    pub original: String, // Original text – will be zeroized on drop
-}
-
-impl Zeroize for Detection {
-    fn zeroize(&mut self) {
-        self.original.zeroize();
-    }
-}
-
-impl Drop for Detection {
-    fn drop(&mut self) {
-        self.zeroize();
-    }
+pub struct DetectorProfile {
+    /// [`PiiDetector::name`] of the detector
+    pub name: String,
+    /// Wall-clock time spent in the detector's `detect` calls
+    pub elapsed: Duration,
+    /// Detections it reported, before overlap resolution
+    pub detections: usize,
 }
 
 /// Core detection trait – all detectors must implement this
 pub trait PiiDetector: Send + Sync {
     fn pii_type(&self) -> PiiType;
 
+    /// Human-readable name for profiling and diagnostics.
+    /// Default: the implementing type's name, e.g. `EmailDetector`.
+    fn name(&self) -> &str {
+        let path = std::any::type_name::<Self>();
+        // Strip the module path, but not inside generic parameters
+        let base = path.split('<').next().unwrap_or(path);
+        let from = base.rfind("::").map_or(0, |i| i + 2);
+        &path[from..]
+    }
+
     /// Returns the confidence level for this detector's matches.
     /// Override this to provide detector-specific confidence.
     /// Default: Medium (regex-based detection without validation)
@@ -84,6 +48,15 @@ pub trait PiiDetector: Send + Sync {
     /// - MUST handle UTF-8 boundaries correctly (never split grapheme clusters)
     fn detect(&self, text: &str) -> Vec<Detection>;
 
+    /// Spans of the detections `detect` returns, same order and guarantees.
+    ///
+    /// The default derives them from `detect`, so the values are still
+    /// copied and then zeroized; detectors that can locate matches without
+    /// building `original` should override this to skip that allocation.
+    fn detect_spans(&self, text: &str) -> Vec<Span> {
+        self.detect(text).iter().map(Detection::span).collect()
+    }
+
     /// Detect PII with optional validation bypass
     /// Called by redactor with policy's strict_validation setting
     fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
@@ -93,6 +66,14 @@ pub trait PiiDetector: Send + Sync {
         self.detect(text)
     }
 
+    /// Structural/checksum validation for a single matched candidate.
+    /// Detectors apply this in `detect_with_validation` when strict
+    /// validation is on. Default: every candidate is valid.
+    fn validate(&self, candidate: &str) -> bool {
+        let _ = candidate;
+        true
+    }
+
     /// Returns literal anchor patterns for Aho-Corasick pre-filtering.
     /// These are substrings that MUST be present in text containing this PII type.
     /// Default: empty (no optimization, full regex scan).
@@ -117,31 +98,187 @@ pub trait PiiDetector: Send + Sync {
     }
 }
 
+/// Detection closure for [`FnDetector`]
+pub type DetectFn = dyn Fn(&str) -> Vec<Detection> + Send + Sync;
+
+/// Candidate validation closure for [`FnDetector`]
+pub type ValidateFn = dyn Fn(&str) -> bool + Send + Sync;
+
+/// Adapter turning a closure into a `PiiDetector`, for one-off rules that
+/// don't warrant their own type.
+///
+/// ```rust
+/// use auvura_core::detector::{Confidence, Detection, FnDetector};
+/// use auvura_core::policy::RedactionPolicy;
+/// use auvura_core::redactor::Redactor;
+/// use auvura_core::types::PiiType;
+///
+/// const TICKET: PiiType = PiiType::Other("TICKET");
+/// let tickets = FnDetector::new(TICKET, |text| {
+///     let digits = |from: usize| text[from..].bytes().take_while(u8::is_ascii_digit).count();
+///     text.match_indices("TKT-")
+///         .map(|(start, _)| {
+///             let end = start + 4 + digits(start + 4);
+///             Detection {
+///                 pii_type: TICKET,
+///                 confidence: Confidence::Medium,
+///                 start,
+///                 end,
+///                 original: text[start..end].to_string(),
+///                 metadata: None,
+///             }
+///         })
+///         .collect()
+/// });
+/// let policy = RedactionPolicy::builder().enable(TICKET).build();
+/// let redactor = Redactor::new(vec![Box::new(tickets)], policy);
+/// assert_eq!(redactor.redact("see TKT-1234"), "see ████████");
+/// ```
+pub struct FnDetector {
+    pii_type: PiiType,
+    confidence: Confidence,
+    detect: Box<DetectFn>,
+    validator: Option<Box<ValidateFn>>,
+}
+
+impl FnDetector {
+    /// Report what `detect` finds as `pii_type`, at Medium confidence.
+    /// Detections may come back in any order; they are sorted by `start`.
+    pub fn new<F>(pii_type: PiiType, detect: F) -> Self
+    where
+        F: Fn(&str) -> Vec<Detection> + Send + Sync + 'static,
+    {
+        Self {
+            pii_type,
+            confidence: Confidence::Medium,
+            detect: Box::new(detect),
+            validator: None,
+        }
+    }
+
+    /// Drop matches `validate` rejects when strict validation is on
+    pub fn with_validator<F>(mut self, validate: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.validator = Some(Box::new(validate));
+        self
+    }
+
+    /// Report detections at `confidence` instead of Medium
+    pub fn with_confidence(mut self, confidence: Confidence) -> Self {
+        self.confidence = confidence;
+        self
+    }
+}
+
+impl PiiDetector for FnDetector {
+    fn pii_type(&self) -> PiiType {
+        self.pii_type
+    }
+
+    fn confidence(&self) -> Confidence {
+        self.confidence
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        self.detect_with_validation(text, true)
+    }
+
+    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
+        let mut detections = (self.detect)(text);
+        if validate {
+            detections.retain(|d| self.validate(&d.original));
+        }
+        detections.sort_by_key(|d| d.start);
+        detections
+    }
+
+    fn validate(&self, candidate: &str) -> bool {
+        self.validator.as_ref().is_none_or(|v| v(candidate))
+    }
+}
+
+/// Luhn (mod 10) check digit validation, as used by payment cards and
+/// many national and organizational IDs. Spaces and dashes between digits
+/// are ignored; any other character, or fewer than two digits, fails.
+pub fn luhn_valid(candidate: &str) -> bool {
+    let mut sum = 0;
+    let mut count = 0;
+    for c in candidate.chars().rev() {
+        if c == ' ' || c == '-' {
+            continue;
+        }
+        let Some(digit) = c.to_digit(10) else {
+            return false;
+        };
+        sum += match (count % 2, digit * 2) {
+            (1, doubled) if doubled > 9 => doubled - 9,
+            (1, doubled) => doubled,
+            _ => digit,
+        };
+        count += 1;
+    }
+    count >= 2 && sum % 10 == 0
+}
+
 /// Anchor-based candidate region for a detector
 struct AnchorRegion {
     start: usize,
     end: usize,
 }
 
+/// Candidate window around an anchor match: 64 bytes either side, widened
+/// to the nearest char boundaries so multi-byte text is never split
+fn anchor_window(text: &str, start: usize, end: usize) -> (usize, usize) {
+    let mut win_start = start.saturating_sub(64);
+    while !text.is_char_boundary(win_start) {
+        win_start -= 1;
+    }
+    let mut win_end = std::cmp::min(text.len(), end + 64);
+    while !text.is_char_boundary(win_end) {
+        win_end += 1;
+    }
+    (win_start, win_end)
+}
+
+/// Callback told about each detection whose span can't be sliced from the
+/// text; see [`MultiDetector::set_invalid_span_handler`]
+pub type InvalidSpanHandler = Arc<dyn Fn(&RedactError) + Send + Sync>;
+
 /// Composite detector for single-pass scanning
 pub struct MultiDetector {
     detectors: Vec<Box<dyn PiiDetector>>,
     /// Pre-built Aho-Corasick automaton for anchor patterns.
-    /// `anchor_detector_idx[i]` maps automaton pattern index `i` to detector index.
+    /// `anchor_detector_idx[i]` maps automaton pattern index `i` to every
+    /// detector sharing that anchor (patterns are deduplicated, since a
+    /// leftmost-first automaton only ever reports the first of two equal
+    /// patterns).
     ac: Option<AhoCorasick>,
-    anchor_detector_idx: Vec<usize>,
+    anchor_detector_idx: Vec<Vec<usize>>,
+    /// Whether each detector has anchor patterns
+    anchored: Vec<bool>,
+    on_invalid_span: Option<InvalidSpanHandler>,
 }
 
 impl MultiDetector {
     pub fn new(detectors: Vec<Box<dyn PiiDetector>>) -> Self {
         // Build Aho-Corasick automaton from all detector anchor patterns
         let mut patterns: Vec<&str> = Vec::new();
-        let mut detector_idx: Vec<usize> = Vec::new();
+        let mut detector_idx: Vec<Vec<usize>> = Vec::new();
 
+        let mut anchored = Vec::with_capacity(detectors.len());
         for (i, det) in detectors.iter().enumerate() {
-            for pattern in det.anchor_patterns() {
-                patterns.push(pattern);
-                detector_idx.push(i);
+            let anchors = det.anchor_patterns();
+            anchored.push(!anchors.is_empty());
+            for pattern in anchors {
+                match patterns.iter().position(|&p| p == pattern) {
+                    Some(existing) => detector_idx[existing].push(i),
+                    None => {
+                        patterns.push(pattern);
+                        detector_idx.push(vec![i]);
+                    }
+                }
             }
         }
 
@@ -160,9 +297,19 @@ impl MultiDetector {
             detectors,
             ac,
             anchor_detector_idx: detector_idx,
+            anchored,
+            on_invalid_span: None,
         }
     }
 
+    /// Call `handler` for every detection dropped because its span is
+    /// reversed, out of bounds, or splits a multi-byte character, e.g. to
+    /// log a detector that counts chars instead of bytes. The error names
+    /// the detector, type, and offsets, never the text.
+    pub fn set_invalid_span_handler(&mut self, handler: InvalidSpanHandler) {
+        self.on_invalid_span = Some(handler);
+    }
+
     /// Single-pass detection using Aho-Corasick for efficiency.
     ///
     /// Strategy:
@@ -184,18 +331,22 @@ impl MultiDetector {
         // Single Aho-Corasick pass to find all anchor matches
         let anchor_matches: Vec<(usize, usize, usize)> = ac
             .find_iter(text)
-            .map(|m| (self.anchor_detector_idx[m.pattern()], m.start(), m.end()))
+            .flat_map(|m| {
+                self.anchor_detector_idx[m.pattern()]
+                    .iter()
+                    .map(move |&idx| (idx, m.start(), m.end()))
+            })
             .collect();
 
         // If no anchors matched, still run detectors that have no anchor patterns
         if anchor_matches.is_empty() {
             let mut detections = Vec::new();
-            for det in &self.detectors {
-                if det.anchor_patterns().is_empty() {
+            for (det_idx, det) in self.detectors.iter().enumerate() {
+                if !self.anchored[det_idx] {
                     detections.extend(det.detect(text));
                 }
             }
-            return Self::resolve_overlaps(detections);
+            return Self::finalize(text, detections);
         }
 
         // Group anchor positions by detector index, expand to candidate windows
@@ -204,8 +355,7 @@ impl MultiDetector {
         for &(det_idx, start, end) in &anchor_matches {
             // Expand window: 64 bytes before (for local part, area code, etc.)
             // and 64 bytes after (for domain, remaining digits, etc.)
-            let win_start = start.saturating_sub(64);
-            let win_end = std::cmp::min(text.len(), end + 64);
+            let (win_start, win_end) = anchor_window(text, start, end);
 
             // Merge overlapping windows for the same detector
             if let Some(last) = candidate_regions[det_idx].last_mut() {
@@ -226,7 +376,7 @@ impl MultiDetector {
             if candidate_regions[det_idx].is_empty() {
                 // If the detector has anchor patterns but none were found, skip it.
                 // If the detector has NO anchor patterns, run on full text as fallback.
-                if !det.anchor_patterns().is_empty() {
+                if self.anchored[det_idx] {
                     continue;
                 }
                 detections.extend(det.detect(text));
@@ -242,7 +392,7 @@ impl MultiDetector {
             }
         }
 
-        Self::resolve_overlaps(detections)
+        Self::finalize(text, detections)
     }
 
     /// Detect with optional validation bypass
@@ -251,70 +401,268 @@ impl MultiDetector {
             return Vec::new();
         }
 
-        let Some(ref ac) = self.ac else {
-            return self.detect_naive_with_validation(text, validate);
-        };
+        let detections = self.with_candidate_regions(text, |regions| {
+            let mut detections: Vec<Detection> = Vec::new();
+            for det_idx in 0..self.detectors.len() {
+                detections.extend(self.run_detector(det_idx, text, regions, validate));
+            }
+            detections
+        });
+        Self::finalize(text, detections)
+    }
 
-        let anchor_matches: Vec<(usize, usize, usize)> = ac
-            .find_iter(text)
-            .map(|m| (self.anchor_detector_idx[m.pattern()], m.start(), m.end()))
-            .collect();
+    /// As [`detect_with_validation`](Self::detect_with_validation), but fail
+    /// on the first detection whose span is reversed, out of bounds, or not
+    /// on char boundaries, naming its detector, instead of dropping it
+    pub fn try_detect_with_validation(
+        &self,
+        text: &str,
+        validate: bool,
+    ) -> Result<Vec<Detection>, RedactError> {
+        if text.is_empty() {
+            return Ok(Vec::new());
+        }
 
-        // If no anchors matched, still run detectors that have no anchor patterns
-        // (e.g. PhoneNumberDetector which falls back to full-text regex scan)
-        if anchor_matches.is_empty() {
-            let mut detections = Vec::new();
-            for det in &self.detectors {
-                if det.anchor_patterns().is_empty() {
-                    detections.extend(det.detect_with_validation(text, validate));
+        let detections = self.with_candidate_regions(text, |regions| {
+            let mut detections: Vec<Detection> = Vec::new();
+            for det_idx in 0..self.detectors.len() {
+                let found = self.run_detector(det_idx, text, regions, validate);
+                if let Some(bad) = found.iter().find(|d| text.get(d.start..d.end).is_none()) {
+                    let name = self.detectors[det_idx].name();
+                    return Err(RedactError::invalid_span(name, bad, text));
                 }
+                detections.extend(found);
             }
-            return Self::resolve_overlaps(detections);
+            Ok(detections)
+        })?;
+        Ok(Self::finalize(text, detections))
+    }
+
+    /// As [`detect_with_validation`](Self::detect_with_validation), also
+    /// timing each detector. Time spent on the shared anchor scan and on
+    /// overlap resolution is not attributed to any detector.
+    pub fn detect_profiled(
+        &self,
+        text: &str,
+        validate: bool,
+    ) -> (Vec<Detection>, Vec<DetectorProfile>) {
+        let (detections, profile) = self.with_candidate_regions(text, |regions| {
+            let mut detections: Vec<Detection> = Vec::new();
+            let mut profile = Vec::with_capacity(self.detectors.len());
+            for (det_idx, det) in self.detectors.iter().enumerate() {
+                let started = Instant::now();
+                let found = if text.is_empty() {
+                    Vec::new()
+                } else {
+                    self.run_detector(det_idx, text, regions, validate)
+                };
+                profile.push(DetectorProfile {
+                    name: det.name().to_string(),
+                    elapsed: started.elapsed(),
+                    detections: found.len(),
+                });
+                detections.extend(found);
+            }
+            (detections, profile)
+        });
+        (Self::finalize(text, detections), profile)
+    }
+
+    /// Whether any detector finds a span `accept` returns true for, stopping
+    /// at the first one: later detectors are not run.
+    ///
+    /// Spans are checked, trimmed, and `original` re-sliced as in `detect`, but
+    /// overlaps are not resolved; `accept` sees every raw detection.
+    pub fn detect_any(
+        &self,
+        text: &str,
+        validate: bool,
+        mut accept: impl FnMut(&Detection) -> bool,
+    ) -> bool {
+        if text.is_empty() {
+            return false;
         }
 
-        let mut candidate_regions: Vec<Vec<AnchorRegion>> =
-            (0..self.detectors.len()).map(|_| Vec::new()).collect();
-        for &(det_idx, start, end) in &anchor_matches {
-            let win_start = start.saturating_sub(64);
-            let win_end = std::cmp::min(text.len(), end + 64);
+        self.with_candidate_regions(text, |regions| {
+            (0..self.detectors.len()).any(|det_idx| {
+                let mut found = self.run_detector(det_idx, text, regions, validate);
+                found
+                    .iter_mut()
+                    .any(|d| Self::check_span(text, d) && accept(d))
+            })
+        })
+    }
 
-            if let Some(last) = candidate_regions[det_idx].last_mut() {
-                if win_start <= last.end {
-                    last.end = std::cmp::max(last.end, win_end);
-                    continue;
+    /// Run `f` on the candidate windows per detector from one Aho-Corasick
+    /// pass, or on `None` if no detector has anchors (every detector scans
+    /// the full text).
+    ///
+    /// The windows live in a per-thread buffer that keeps its capacity
+    /// between calls, so scanning text without PII allocates nothing once
+    /// warm. A detector that runs a `MultiDetector` itself gets a fresh
+    /// buffer.
+    fn with_candidate_regions<R>(
+        &self,
+        text: &str,
+        f: impl FnOnce(Option<&[Vec<AnchorRegion>]>) -> R,
+    ) -> R {
+        thread_local! {
+            static SCRATCH: RefCell<Vec<Vec<AnchorRegion>>> = const { RefCell::new(Vec::new()) };
+        }
+
+        let Some(ac) = self.ac.as_ref() else {
+            return f(None);
+        };
+        SCRATCH.with(|scratch| match scratch.try_borrow_mut() {
+            Ok(mut regions) => {
+                self.fill_candidate_regions(ac, text, &mut regions);
+                f(Some(&regions))
+            }
+            Err(_) => {
+                let mut regions = Vec::new();
+                self.fill_candidate_regions(ac, text, &mut regions);
+                f(Some(&regions))
+            }
+        })
+    }
+
+    /// Refill `candidate_regions` with one list of windows per detector
+    fn fill_candidate_regions(
+        &self,
+        ac: &AhoCorasick,
+        text: &str,
+        candidate_regions: &mut Vec<Vec<AnchorRegion>>,
+    ) {
+        candidate_regions.truncate(self.detectors.len());
+        for regions in candidate_regions.iter_mut() {
+            regions.clear();
+        }
+        candidate_regions.resize_with(self.detectors.len(), Vec::new);
+        for m in ac.find_iter(text) {
+            let (win_start, win_end) = anchor_window(text, m.start(), m.end());
+            for &det_idx in &self.anchor_detector_idx[m.pattern()] {
+                if let Some(last) = candidate_regions[det_idx].last_mut() {
+                    if win_start <= last.end {
+                        last.end = std::cmp::max(last.end, win_end);
+                        continue;
+                    }
                 }
+                candidate_regions[det_idx].push(AnchorRegion {
+                    start: win_start,
+                    end: win_end,
+                });
             }
-            candidate_regions[det_idx].push(AnchorRegion {
-                start: win_start,
-                end: win_end,
-            });
         }
+    }
 
-        let mut detections: Vec<Detection> = Vec::new();
-        for (det_idx, det) in self.detectors.iter().enumerate() {
-            if candidate_regions[det_idx].is_empty() {
-                if !det.anchor_patterns().is_empty() {
-                    continue;
+    /// Run one detector on its candidate windows. Detectors without anchor
+    /// patterns (e.g. PhoneNumberDetector) scan the full text; anchored
+    /// detectors whose anchors never matched are skipped.
+    fn run_detector(
+        &self,
+        det_idx: usize,
+        text: &str,
+        regions: Option<&[Vec<AnchorRegion>]>,
+        validate: bool,
+    ) -> Vec<Detection> {
+        let det = &self.detectors[det_idx];
+        let mut detections = match regions {
+            Some(regions) if !regions[det_idx].is_empty() => {
+                self.run_in_regions(det_idx, text, &regions[det_idx], validate)
+            }
+            Some(_) if self.anchored[det_idx] => return Vec::new(),
+            _ => det.detect_with_validation(text, validate),
+        };
+        if let Some(handler) = &self.on_invalid_span {
+            for d in &detections {
+                if text.get(d.start..d.end).is_none() {
+                    handler(&RedactError::invalid_span(det.name(), d, text));
                 }
-                detections.extend(det.detect_with_validation(text, validate));
-                continue;
             }
+        }
+        if validate {
+            // Enforce checksums for types that need them, even when a
+            // detector's own matching doesn't apply `validate`
+            detections.retain(|d| !d.pii_type.requires_validation() || det.validate(&d.original));
+        }
+        detections
+    }
 
-            for region in &candidate_regions[det_idx] {
-                if region.start >= region.end || region.end > text.len() {
-                    continue;
-                }
-                let window = &text[region.start..region.end];
-                // Use detect_with_validation on the window, adjusting offsets
-                let window_detections = det.detect_with_validation(window, validate);
-                detections.extend(window_detections.into_iter().map(|mut d| {
-                    d.start += region.start;
-                    d.end += region.start;
-                    d
-                }));
+    fn run_in_regions(
+        &self,
+        det_idx: usize,
+        text: &str,
+        regions: &[AnchorRegion],
+        validate: bool,
+    ) -> Vec<Detection> {
+        let det = &self.detectors[det_idx];
+        let mut detections = Vec::new();
+        for region in regions {
+            if region.start >= region.end || region.end > text.len() {
+                continue;
             }
+            let window = &text[region.start..region.end];
+            // Use detect_with_validation on the window, adjusting offsets
+            let window_detections = det.detect_with_validation(window, validate);
+            detections.extend(window_detections.into_iter().map(|mut d| {
+                // Saturate: a bogus span must be dropped, not overflow
+                d.start = d.start.saturating_add(region.start);
+                d.end = d.end.saturating_add(region.start);
+                d
+            }));
+        }
+        detections
+    }
+
+    /// As `detect_with_validation(text, true)`, but built from each
+    /// detector's [`detect_spans`](PiiDetector::detect_spans), so detectors
+    /// that override it copy no values. The detections carry their
+    /// detector's [`confidence`](PiiDetector::confidence) and an empty
+    /// `original`; callers slice `text` for the value.
+    pub(crate) fn detect_span_candidates(&self, text: &str) -> Vec<Detection> {
+        if text.is_empty() {
+            return Vec::new();
         }
 
+        let detections = self.with_candidate_regions(text, |regions| {
+            let mut detections: Vec<Detection> = Vec::new();
+            for (det_idx, det) in self.detectors.iter().enumerate() {
+                let spans = match regions {
+                    Some(regions) if !regions[det_idx].is_empty() => regions[det_idx]
+                        .iter()
+                        .filter(|r| r.start < r.end && r.end <= text.len())
+                        .flat_map(|r| {
+                            det.detect_spans(&text[r.start..r.end])
+                                .into_iter()
+                                .map(move |mut s| {
+                                    s.start = s.start.saturating_add(r.start);
+                                    s.end = s.end.saturating_add(r.start);
+                                    s
+                                })
+                        })
+                        .collect(),
+                    Some(_) if self.anchored[det_idx] => continue,
+                    _ => det.detect_spans(text),
+                };
+                for span in spans {
+                    let Some((start, end)) = Self::trim_span(text, span.start, span.end) else {
+                        continue;
+                    };
+                    if span.pii_type.requires_validation() && !det.validate(&text[start..end]) {
+                        continue;
+                    }
+                    detections.push(Detection {
+                        pii_type: span.pii_type,
+                        confidence: det.confidence(),
+                        start,
+                        end,
+                        original: String::new(),
+                        metadata: None,
+                    });
+                }
+            }
+            detections
+        });
         Self::resolve_overlaps(detections)
     }
 
@@ -324,20 +672,50 @@ impl MultiDetector {
         for detector in &self.detectors {
             detections.extend(detector.detect(text));
         }
+        Self::finalize(text, detections)
+    }
+
+    /// Check detector output against `text`, then resolve overlaps.
+    ///
+    /// Detectors are trusted for spans, not for `original`: detections with
+    /// an empty, out-of-bounds, or non-char-boundary span are dropped, and
+    /// `original` is re-sliced from `text` when it doesn't match the span,
+    /// so masks are always built from the bytes they replace. Bidi controls
+    /// at either edge of a span are trimmed off, so redaction never removes
+    /// the isolates or marks around PII embedded in right-to-left text.
+    fn finalize(text: &str, mut detections: Vec<Detection>) -> Vec<Detection> {
+        detections.retain_mut(|d| Self::check_span(text, d));
         Self::resolve_overlaps(detections)
     }
 
-    /// Fallback: naive loop with validation bypass
-    fn detect_naive_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
-        let mut detections: Vec<Detection> = Vec::new();
-        for detector in &self.detectors {
-            detections.extend(detector.detect_with_validation(text, validate));
+    /// Trim and re-slice one detection for `finalize`; false to drop it
+    fn check_span(text: &str, d: &mut Detection) -> bool {
+        let Some((start, end)) = Self::trim_span(text, d.start, d.end) else {
+            return false;
+        };
+        (d.start, d.end) = (start, end);
+        let trimmed = &text[start..end];
+        if d.original != trimmed {
+            d.original.zeroize();
+            d.original = trimmed.to_string();
         }
-        Self::resolve_overlaps(detections)
+        true
+    }
+
+    /// `start..end` with bidi controls trimmed off both edges, or `None`
+    /// if it is out of bounds, splits a char, or is empty once trimmed
+    fn trim_span(text: &str, start: usize, end: usize) -> Option<(usize, usize)> {
+        let span = text.get(start..end)?;
+        let trimmed = span.trim_matches(is_bidi_control);
+        if trimmed.is_empty() {
+            return None;
+        }
+        let start = start + span.len() - span.trim_start_matches(is_bidi_control).len();
+        Some((start, start + trimmed.len()))
     }
 
     /// Resolve overlapping detections – keep highest priority PII type
-    /// Priority (higher = more specific): SSN(5) > CreditCard(4) > IBAN/Passport/NatID(3) > PhoneNumber(2) > Email/IP/Address(1) > Other(0)
+    /// (see `PiiType::priority`): SSN(5) > CreditCard(4) > IBAN/Passport/NatID(3) > PhoneNumber(2) > Email/IP/Address(1) > Other(0)
     /// Tiebreakers: confidence (higher wins), then longer span
     fn resolve_overlaps(detections: Vec<Detection>) -> Vec<Detection> {
         if detections.is_empty() {
@@ -348,7 +726,7 @@ impl MultiDetector {
         sorted.sort_by(|a, b| {
             a.start
                 .cmp(&b.start)
-                .then_with(|| pii_priority(b.pii_type).cmp(&pii_priority(a.pii_type)))
+                .then_with(|| b.pii_type.priority().cmp(&a.pii_type.priority()))
                 .then_with(|| b.confidence.cmp(&a.confidence))
                 .then_with(|| (b.end - b.start).cmp(&(a.end - a.start)))
         });
@@ -361,8 +739,8 @@ impl MultiDetector {
         for i in 1..sorted.len() {
             if sorted[i].start < sorted[current_idx].end {
                 // Overlap detected – keep higher priority (or higher confidence, or longer span)
-                let cur_p = pii_priority(sorted[current_idx].pii_type);
-                let new_p = pii_priority(sorted[i].pii_type);
+                let cur_p = sorted[current_idx].pii_type.priority();
+                let new_p = sorted[i].pii_type.priority();
                 let cur_c = sorted[current_idx].confidence;
                 let new_c = sorted[i].confidence;
                 let cur_len = sorted[current_idx].end - sorted[current_idx].start;
@@ -392,21 +770,14 @@ impl MultiDetector {
     }
 }
 
-/// Returns priority for PII type (higher = more specific)
-/// SSN > CreditCard > IBAN > PhoneNumber > Email > IpAddress > Other
-fn pii_priority(pii_type: PiiType) -> u8 {
-    match pii_type {
-        PiiType::Ssn => 5,
-        PiiType::CreditCard => 4,
-        PiiType::Iban => 3,
-        PiiType::PassportNumber => 3,
-        PiiType::NationalId => 3,
-        PiiType::PhoneNumber => 2,
-        PiiType::Email => 1,
-        PiiType::IpAddressV4 | PiiType::IpAddressV6 => 1,
-        PiiType::PhysicalAddress => 1,
-        PiiType::Other(_) => 0,
-    }
+/// Merge detections produced by several independent detector passes.
+///
+/// All inputs must carry offsets into the same original text. The combined set
+/// is resolved with the same priority/confidence/length rules `MultiDetector`
+/// uses, so the result is sorted by `start` and free of overlaps.
+pub fn merge_detections(sets: Vec<Vec<Detection>>) -> Vec<Detection> {
+    let combined: Vec<Detection> = sets.into_iter().flatten().collect();
+    MultiDetector::resolve_overlaps(combined)
 }
 
 #[cfg(test)]
@@ -433,6 +804,7 @@ mod tests {
                     start,
                     end,
                     original: text[start..end].to_string(),
+                    metadata: None,
                 }]
             } else {
                 vec![]
@@ -458,26 +830,137 @@ mod tests {
         // but trait impl guarantees it happens. Fuzz tests will validate.
     }
 
+    #[test]
+    fn test_default_detect_spans_follow_detect() {
+        let detector = TestEmailDetector;
+        let text = "contact john@example.com";
+        assert_eq!(
+            detector.detect_spans(text),
+            vec![Span {
+                pii_type: PiiType::Email,
+                start: 8,
+                end: 24,
+            }]
+        );
+        assert!(detector.detect_spans("no email").is_empty());
+    }
+
+    #[test]
+    fn test_fn_detector_builds_a_redactor_from_closures() {
+        use crate::policy::RedactionPolicy;
+        use crate::redactor::Redactor;
+
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<FnDetector>();
+
+        // Order numbers "#" + 6 digits, valid when the digit sum is even
+        let orders = FnDetector::new(PiiType::Other("ORDER"), |text| {
+            let mut found: Vec<Detection> = text
+                .match_indices('#')
+                .filter(|(i, _)| {
+                    text.len() >= i + 7 && text[i + 1..i + 7].bytes().all(|b| b.is_ascii_digit())
+                })
+                .map(|(i, _)| Detection {
+                    pii_type: PiiType::Other("ORDER"),
+                    confidence: Confidence::High,
+                    start: i,
+                    end: i + 7,
+                    original: text[i..i + 7].to_string(),
+                    metadata: None,
+                })
+                .collect();
+            // Out of order on purpose: the adapter sorts
+            found.reverse();
+            found
+        })
+        .with_validator(|order| {
+            order[1..].bytes().map(|b| u32::from(b - b'0')).sum::<u32>() % 2 == 0
+        })
+        .with_confidence(Confidence::High);
+        assert_eq!(orders.name(), "FnDetector");
+        assert_eq!(orders.confidence(), Confidence::High);
+        assert!(orders.validate("#123453") && !orders.validate("#123456"));
+
+        let text = "orders #123453 and #123456";
+        let starts: Vec<usize> = orders
+            .detect_with_validation(text, false)
+            .iter()
+            .map(|d| d.start)
+            .collect();
+        assert_eq!(starts, vec![7, 19]);
+
+        let policy = RedactionPolicy::builder()
+            .enable(PiiType::Other("ORDER"))
+            .build();
+        let redactor = Redactor::new(vec![Box::new(orders)], policy);
+        assert_eq!(redactor.redact(text), "orders ███████ and #123456");
+    }
+
     #[test]
     fn test_resolve_overlaps_prefers_longer_match() {
+        // Only once type priority and confidence tie does span length decide
         let short = Detection {
             pii_type: PiiType::PhoneNumber,
             confidence: Confidence::Medium,
             start: 10,
             end: 20,
             original: "1234567890".to_string(),
+            metadata: None,
         };
         let long = Detection {
-            pii_type: PiiType::Ssn,
-            confidence: Confidence::High,
+            pii_type: PiiType::PhoneNumber,
+            confidence: Confidence::Medium,
             start: 12,
             end: 25,
-            original: "456789012".to_string(),
+            original: "4567890123456".to_string(),
+            metadata: None,
         };
 
         let resolved = MultiDetector::resolve_overlaps(vec![short.clone(), long.clone()]);
-        assert_eq!(resolved.len(), 1);
-        assert_eq!(resolved[0].pii_type, PiiType::Ssn); // Longer match wins
+        assert_eq!(resolved, vec![long]); // Longer match wins the tie
+
+        // A more specific type beats a longer span
+        let ssn = Detection {
+            pii_type: PiiType::Ssn,
+            confidence: Confidence::High,
+            start: 12,
+            end: 19,
+            original: "4567890".to_string(),
+            metadata: None,
+        };
+        let resolved = MultiDetector::resolve_overlaps(vec![short, ssn.clone()]);
+        assert_eq!(resolved, vec![ssn]);
+    }
+
+    #[test]
+    fn test_short_ssn_beats_long_phone_run() {
+        let text = "call +1 (555) 123-45-6789 now";
+        let phone = Detection {
+            pii_type: PiiType::PhoneNumber,
+            confidence: Confidence::High,
+            start: 5,
+            end: 25,
+            original: text[5..25].to_string(),
+            metadata: None,
+        };
+        let ssn = Detection {
+            pii_type: PiiType::Ssn,
+            confidence: Confidence::Medium,
+            start: 14,
+            end: 25,
+            original: text[14..25].to_string(),
+            metadata: None,
+        };
+        assert_eq!(ssn.original, "123-45-6789");
+        assert!(PiiType::Ssn.priority() > PiiType::PhoneNumber.priority());
+
+        // Even against a higher-confidence phone match, in either input order
+        for detections in [
+            vec![phone.clone(), ssn.clone()],
+            vec![ssn.clone(), phone.clone()],
+        ] {
+            assert_eq!(merge_detections(vec![detections]), vec![ssn.clone()]);
+        }
     }
 
     #[test]
@@ -490,6 +973,7 @@ mod tests {
             start: 10,
             end: 25, // longer span
             original: "123-456-7890".to_string(),
+            metadata: None,
         };
         let ssn = Detection {
             pii_type: PiiType::Ssn,
@@ -497,6 +981,7 @@ mod tests {
             start: 12,
             end: 23, // shorter span but higher priority
             original: "123-45-6789".to_string(),
+            metadata: None,
         };
 
         let resolved = MultiDetector::resolve_overlaps(vec![phone, ssn]);
@@ -513,6 +998,7 @@ mod tests {
             start: 10,
             end: 25,
             original: "user@example.com".to_string(),
+            metadata: None,
         };
         let high = Detection {
             pii_type: PiiType::Email,
@@ -520,6 +1006,7 @@ mod tests {
             start: 12,
             end: 23,
             original: "er@example".to_string(),
+            metadata: None,
         };
 
         let resolved = MultiDetector::resolve_overlaps(vec![low, high]);
@@ -527,11 +1014,92 @@ mod tests {
         assert_eq!(resolved[0].confidence, Confidence::High);
     }
 
+    #[test]
+    fn test_resolve_overlaps_equal_length_higher_confidence_wins() {
+        let detection = |confidence, original: &str| Detection {
+            pii_type: PiiType::PhoneNumber,
+            confidence,
+            start: 4,
+            end: 16,
+            original: original.to_string(),
+            metadata: None,
+        };
+        let strict = detection(Confidence::High, "555-123-4567");
+        let loose = detection(Confidence::Low, "555 123 4567");
+
+        // Regardless of detector order
+        for candidates in [
+            vec![strict.clone(), loose.clone()],
+            vec![loose.clone(), strict.clone()],
+        ] {
+            let resolved = MultiDetector::resolve_overlaps(candidates);
+            assert_eq!(resolved, vec![strict.clone()]);
+        }
+    }
+
+    #[test]
+    fn test_merge_detections_resolves_cross_pass_overlap() {
+        let phone_pass = vec![
+            Detection {
+                pii_type: PiiType::PhoneNumber,
+                confidence: Confidence::Medium,
+                start: 5,
+                end: 17,
+                original: "123-456-7890".to_string(),
+                metadata: None,
+            },
+            Detection {
+                pii_type: PiiType::PhoneNumber,
+                confidence: Confidence::Medium,
+                start: 40,
+                end: 52,
+                original: "555-123-4567".to_string(),
+                metadata: None,
+            },
+        ];
+        let ssn_pass = vec![Detection {
+            pii_type: PiiType::Ssn,
+            confidence: Confidence::High,
+            start: 6,
+            end: 17,
+            original: "23-456-7890".to_string(),
+            metadata: None,
+        }];
+        let email_pass = vec![Detection {
+            pii_type: PiiType::Email,
+            confidence: Confidence::Medium,
+            start: 20,
+            end: 36,
+            original: "john@example.com".to_string(),
+            metadata: None,
+        }];
+
+        let merged = merge_detections(vec![phone_pass, email_pass, ssn_pass]);
+        let spans: Vec<(PiiType, usize, usize)> = merged
+            .iter()
+            .map(|d| (d.pii_type, d.start, d.end))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                (PiiType::Ssn, 6, 17),
+                (PiiType::Email, 20, 36),
+                (PiiType::PhoneNumber, 40, 52),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_detections_empty_inputs() {
+        assert!(merge_detections(Vec::new()).is_empty());
+        assert!(merge_detections(vec![Vec::new(), Vec::new()]).is_empty());
+    }
+
     #[test]
     fn test_pii_priority_ordering() {
-        assert!(pii_priority(PiiType::Ssn) > pii_priority(PiiType::CreditCard));
-        assert!(pii_priority(PiiType::CreditCard) > pii_priority(PiiType::PhoneNumber));
-        assert!(pii_priority(PiiType::PhoneNumber) > pii_priority(PiiType::Email));
+        assert!(PiiType::Ssn.priority() > PiiType::CreditCard.priority());
+        assert!(PiiType::CreditCard.priority() > PiiType::PhoneNumber.priority());
+        assert!(PiiType::PhoneNumber.priority() > PiiType::Email.priority());
     }
 
     #[test]
@@ -546,6 +1114,104 @@ mod tests {
         assert_eq!(detections[1].original, "jane@test.org");
     }
 
+    #[test]
+    fn test_aho_corasick_shared_anchor_reaches_all_detectors() {
+        use crate::detectors::{email::EmailDetector, social_handle::SocialHandleDetector};
+        let detectors: Vec<Box<dyn PiiDetector>> = vec![
+            Box::new(EmailDetector::new()),
+            Box::new(SocialHandleDetector::new()),
+        ];
+        let multi = MultiDetector::new(detectors);
+
+        let detections = multi.detect("cc @jane_doe or john@example.com");
+        let types: Vec<PiiType> = detections.iter().map(|d| d.pii_type).collect();
+        assert_eq!(types, vec![PiiType::SocialHandle, PiiType::Email]);
+        assert_eq!(multi.detect_with_validation("cc @jane_doe", true).len(), 1);
+    }
+
+    #[test]
+    fn test_aho_corasick_matches_naive_path() {
+        use crate::detectors::default_detector;
+        let detectors: Vec<Box<dyn PiiDetector>> = [
+            PiiType::Email,
+            PiiType::PhoneNumber,
+            PiiType::Ssn,
+            PiiType::CreditCard,
+            PiiType::IpAddressV4,
+            PiiType::IpAddressV6,
+            PiiType::Iban,
+            PiiType::PassportNumber,
+            PiiType::NationalId,
+            PiiType::PhysicalAddress,
+            PiiType::Timestamp,
+            PiiType::MedicareBeneficiaryId,
+            PiiType::SocialHandle,
+            PiiType::AustralianAbn,
+            PiiType::AustralianAcn,
+            PiiType::Secret,
+            PiiType::PartiallyMasked,
+            PiiType::VatNumber,
+            PiiType::GpsCoordinate,
+            PiiType::DateOfBirth,
+            PiiType::MacAddress,
+            PiiType::UsPassport,
+            PiiType::DriversLicense,
+            PiiType::BankAccountRouting,
+        ]
+        .into_iter()
+        .filter_map(default_detector)
+        .collect();
+        let multi = MultiDetector::new(detectors);
+
+        let filler = " lorem ipsum dolor sit amet, █ consectetur adipiscing elit ".repeat(3);
+        let text = [
+            "mail jane@example.com",
+            "SSN 123-45-6789 or 123456789",
+            "card 4111111111111111",
+            "host 192.168.1.7 and 2001:db8::1",
+            "call +1 202-555-0143",
+            "IBAN DE89 3704 0044 0532 0130 00",
+            "follow @jane_doe",
+        ]
+        .join(&filler);
+        let spans = |detections: Vec<Detection>| -> Vec<(usize, usize, PiiType)> {
+            detections
+                .iter()
+                .map(|d| (d.start, d.end, d.pii_type))
+                .collect()
+        };
+        let naive = spans(multi.detect_naive(&text));
+        assert!(naive.len() >= 9, "{naive:?}");
+        assert_eq!(spans(multi.detect(&text)), naive);
+        assert_eq!(spans(multi.detect_with_validation(&text, true)), naive);
+    }
+
+    #[test]
+    fn test_anchor_window_respects_char_boundaries() {
+        use crate::detectors::email::EmailDetector;
+        let detectors: Vec<Box<dyn PiiDetector>> = vec![Box::new(EmailDetector::new())];
+        let multi = MultiDetector::new(detectors);
+
+        // 64 bytes before/after the anchor land inside multi-byte chars
+        let text = format!("{}x john@example.com {}", "█".repeat(30), "█".repeat(30));
+        let detections = multi.detect_with_validation(&text, true);
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].original, "john@example.com");
+        assert_eq!(multi.detect(&text).len(), 1);
+    }
+
+    #[test]
+    fn test_luhn_valid() {
+        assert!(luhn_valid("4111111111111111"));
+        assert!(luhn_valid("4111 1111 1111 1111"));
+        assert!(luhn_valid("4111-1111-1111-1111"));
+        assert!(luhn_valid("18"));
+        assert!(!luhn_valid("4111 1111 1111 1112"));
+        assert!(!luhn_valid("4111.1111.1111.1111"));
+        assert!(!luhn_valid("0"));
+        assert!(!luhn_valid(""));
+    }
+
     #[test]
     fn test_aho_corasick_no_anchors_no_work() {
         // Detector with no anchor patterns falls back to naive
@@ -147,6 +147,7 @@ impl PiiDetector for AddressDetector {
                     start,
                     end,
                     original: candidate.to_string(),
+                    metadata: None,
                 })
             })
             .collect()
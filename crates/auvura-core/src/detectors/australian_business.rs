@@ -0,0 +1,257 @@
+//! Australian business identifiers: ABN and ACN
+//!
+//! - **ABN** (Australian Business Number): 11 digits, usually printed as
+//!   `51 824 753 556`. Valid when, after subtracting 1 from the first digit,
+//!   the weighted sum with weights `10, 1, 3, 5, ..., 19` is divisible by 89.
+//! - **ACN** (Australian Company Number): 9 digits, usually printed as
+//!   `004 085 616`. The last digit is `(10 - (Σ dᵢ·(8-i)) mod 10) mod 10`
+//!   over the first eight digits.
+//!
+//! Both are plain digit runs, so detection is gated on context: the
+//! keyword `ABN` / `ACN` (case-insensitive) must appear shortly before the
+//! number. An ABN embeds its company's ACN as its last nine digits; gating
+//! on the keyword keeps the two types apart. `with_keywords` replaces the
+//! keyword list, e.g. to add spelled-out or translated forms.
+
+use super::has_context;
+use crate::{
+    detector::{Confidence, Detection, PiiDetector},
+    types::PiiType,
+};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const ABN_WEIGHTS: [u32; 11] = [10, 1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+const ACN_WEIGHTS: [u32; 8] = [8, 7, 6, 5, 4, 3, 2, 1];
+
+fn digits(candidate: &str) -> Vec<u32> {
+    candidate.chars().filter_map(|c| c.to_digit(10)).collect()
+}
+
+/// Find context-gated candidates for `pattern` and emit detections
+fn detect_gated(
+    detector: &dyn PiiDetector,
+    pattern: &Regex,
+    keywords: &[String],
+    text: &str,
+    validate: bool,
+) -> Vec<Detection> {
+    pattern
+        .find_iter(text)
+        .filter(|m| has_context(text, m.start(), keywords))
+        .filter(|m| !validate || detector.validate(m.as_str()))
+        .map(|m| Detection {
+            pii_type: detector.pii_type(),
+            confidence: detector.confidence(),
+            start: m.start(),
+            end: m.end(),
+            original: m.as_str().to_string(),
+            metadata: None,
+        })
+        .collect()
+}
+
+/// AbnDetector - detects and validates Australian Business Numbers
+pub struct AbnDetector {
+    pattern: &'static Regex,
+    keywords: Vec<String>,
+}
+
+impl Default for AbnDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AbnDetector {
+    /// Create a new AbnDetector
+    pub fn new() -> Self {
+        static PATTERN: OnceLock<Regex> = OnceLock::new();
+        Self {
+            pattern: PATTERN.get_or_init(|| {
+                Regex::new(r"\b\d{2} ?\d{3} ?\d{3} ?\d{3}\b").expect("ABN pattern is valid")
+            }),
+            keywords: vec!["ABN".to_string()],
+        }
+    }
+
+    /// Gate on these keywords instead of `ABN`
+    pub fn with_keywords(mut self, keywords: &[&str]) -> Self {
+        self.keywords = keywords.iter().map(|k| k.to_string()).collect();
+        self
+    }
+}
+
+impl PiiDetector for AbnDetector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::AustralianAbn
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        self.detect_with_validation(text, true)
+    }
+
+    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
+        detect_gated(self, self.pattern, &self.keywords, text, validate)
+    }
+
+    fn validate(&self, candidate: &str) -> bool {
+        let mut digits = digits(candidate);
+        if digits.len() != ABN_WEIGHTS.len() || digits[0] == 0 {
+            return false;
+        }
+        digits[0] -= 1;
+        let sum: u32 = digits.iter().zip(ABN_WEIGHTS).map(|(d, w)| d * w).sum();
+        sum.is_multiple_of(89)
+    }
+}
+
+/// AcnDetector - detects and validates Australian Company Numbers
+pub struct AcnDetector {
+    pattern: &'static Regex,
+    keywords: Vec<String>,
+}
+
+impl Default for AcnDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AcnDetector {
+    /// Create a new AcnDetector
+    pub fn new() -> Self {
+        static PATTERN: OnceLock<Regex> = OnceLock::new();
+        Self {
+            pattern: PATTERN.get_or_init(|| {
+                Regex::new(r"\b\d{3} ?\d{3} ?\d{3}\b").expect("ACN pattern is valid")
+            }),
+            keywords: vec!["ACN".to_string()],
+        }
+    }
+
+    /// Gate on these keywords instead of `ACN`
+    pub fn with_keywords(mut self, keywords: &[&str]) -> Self {
+        self.keywords = keywords.iter().map(|k| k.to_string()).collect();
+        self
+    }
+}
+
+impl PiiDetector for AcnDetector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::AustralianAcn
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        self.detect_with_validation(text, true)
+    }
+
+    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
+        detect_gated(self, self.pattern, &self.keywords, text, validate)
+    }
+
+    fn validate(&self, candidate: &str) -> bool {
+        let digits = digits(candidate);
+        if digits.len() != ACN_WEIGHTS.len() + 1 {
+            return false;
+        }
+        let sum: u32 = digits.iter().zip(ACN_WEIGHTS).map(|(d, w)| d * w).sum();
+        (10 - sum % 10) % 10 == digits[8]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{policy::RedactionPolicy, redactor::Redactor};
+
+    fn au_redactor(strict: bool) -> Redactor {
+        let policy = RedactionPolicy::builder()
+            .enable(PiiType::AustralianAbn)
+            .enable(PiiType::AustralianAcn)
+            .strict_validation(strict)
+            .build();
+        Redactor::new(
+            vec![Box::new(AbnDetector::new()), Box::new(AcnDetector::new())],
+            policy,
+        )
+    }
+
+    #[test]
+    fn test_valid_abn_passes_checksum() {
+        let detector = AbnDetector::new();
+        assert!(detector.validate("51 824 753 556"));
+        assert!(detector.validate("53004085616"));
+        assert!(!detector.validate("51 824 753 557"));
+
+        let detections = detector.detect("Supplier ABN: 51 824 753 556.");
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].pii_type, PiiType::AustralianAbn);
+        assert_eq!(detections[0].original, "51 824 753 556");
+    }
+
+    #[test]
+    fn test_acn_checksum() {
+        let detector = AcnDetector::new();
+        assert!(detector.validate("004 085 616"));
+        assert!(detector.validate("000 000 019"));
+        assert!(!detector.validate("004 085 617"));
+    }
+
+    #[test]
+    fn test_invalid_acn_rejected_under_strict_mode() {
+        let input = "Company ACN 004 085 617";
+        assert_eq!(au_redactor(true).redact(input), input);
+        assert_eq!(au_redactor(false).redact(input), "Company ACN ███ ███ ███");
+    }
+
+    #[test]
+    fn test_requires_context_keyword() {
+        assert!(AbnDetector::new().detect("ref 51 824 753 556").is_empty());
+        assert!(AcnDetector::new().detect("ref 004 085 616").is_empty());
+        // Keyword must be a whole word
+        assert!(AcnDetector::new().detect("BACNET 004 085 616").is_empty());
+        assert_eq!(AcnDetector::new().detect("acn: 004085616").len(), 1);
+    }
+
+    #[test]
+    fn test_custom_keywords() {
+        let detector = AbnDetector::new().with_keywords(&["ABN", "business number"]);
+        assert_eq!(detector.detect("Business Number 51 824 753 556").len(), 1);
+        assert_eq!(detector.detect("ABN 51 824 753 556").len(), 1);
+        assert!(detector.detect("ref 51 824 753 556").is_empty());
+    }
+
+    #[test]
+    fn test_type_disambiguation() {
+        let redactor = au_redactor(true);
+        // The ABN embeds the ACN as its last nine digits
+        let input = "ABN 53 004 085 616, ACN 004 085 616";
+        assert_eq!(
+            redactor.redaction_ranges(input),
+            vec![
+                (4, 18, PiiType::AustralianAbn),
+                (24, 35, PiiType::AustralianAcn),
+            ]
+        );
+        assert_eq!(
+            redactor.redact(input),
+            "ABN ██ ███ ███ ███, ACN ███ ███ ███"
+        );
+    }
+
+    #[test]
+    fn test_zeroizes_on_drop() {
+        let detections = AbnDetector::new().detect("ABN 51824753556");
+        assert_eq!(detections.len(), 1);
+        std::mem::drop(detections);
+    }
+}
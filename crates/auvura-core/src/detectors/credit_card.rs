@@ -10,7 +10,7 @@
 //! https://docs.paymentcardindustry.com/virtual-terminal/test-card-numbers/
 
 use crate::{
-    detector::{Confidence, Detection, PiiDetector},
+    detector::{luhn_valid, Confidence, Detection, PiiDetector, Span},
     types::PiiType,
 };
 use regex::Regex;
@@ -44,34 +44,6 @@ impl CreditCardDetector {
         })
     }
 
-    /// Luhn algorithm validation (mod 10 check)
-    fn passes_luhn(s: &str) -> bool {
-        if s.len() < 2 {
-            return false;
-        }
-
-        let mut sum = 0;
-        let mut should_double = false;
-
-        for ch in s.chars().rev() {
-            if let Some(digit) = ch.to_digit(10) {
-                let mut d = digit;
-                if should_double {
-                    d *= 2;
-                    if d > 9 {
-                        d -= 9;
-                    }
-                }
-                sum += d;
-                should_double = !should_double;
-            } else {
-                return false;
-            }
-        }
-
-        sum % 10 == 0
-    }
-
     /// BIN validation + length checks to prevent false positives
     fn is_valid_card_number(s: &str) -> bool {
         let len = s.len();
@@ -166,23 +138,10 @@ impl CreditCardDetector {
 
         false
     }
-}
-
-impl PiiDetector for CreditCardDetector {
-    fn pii_type(&self) -> PiiType {
-        PiiType::CreditCard
-    }
-
-    fn confidence(&self) -> Confidence {
-        Confidence::High
-    }
 
-    fn detect(&self, text: &str) -> Vec<Detection> {
-        self.detect_with_validation(text, true)
-    }
-
-    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
-        let mut detections = Vec::new();
+    /// Matched spans, checked with Luhn and BIN ranges when `validate` is set
+    fn spans(&self, text: &str, validate: bool) -> Vec<Span> {
+        let mut spans = Vec::new();
 
         for m in self.pattern.find_iter(text) {
             let candidate = m.as_str();
@@ -190,20 +149,16 @@ impl PiiDetector for CreditCardDetector {
             let end = m.end();
 
             // CRITICAL: Boundary validation in Rust code (avoids regex look-around)
-            // Reject if preceded by digit (prevents matching substrings of longer numbers)
-            if start > 0 {
-                let prev_char = text[start - 1..start].chars().next().unwrap_or('\0');
-                if prev_char.is_ascii_digit() {
-                    continue;
-                }
+            // Reject if preceded by digit (prevents matching substrings of longer numbers).
+            // Byte checks: neighbors may be multi-byte (e.g. bidi controls),
+            // and no byte of a multi-byte char is an ASCII digit
+            if start > 0 && text.as_bytes()[start - 1].is_ascii_digit() {
+                continue;
             }
 
             // Reject if followed by digit
-            if end < text.len() {
-                let next_char = text[end..end + 1].chars().next().unwrap_or('\0');
-                if next_char.is_ascii_digit() {
-                    continue;
-                }
+            if end < text.len() && text.as_bytes()[end].is_ascii_digit() {
+                continue;
             }
 
             // Clean separators to get raw digits
@@ -215,37 +170,49 @@ impl PiiDetector for CreditCardDetector {
             }
 
             // Validation chain: Luhn + BIN patterns (skip if validation disabled)
-            if !validate || (Self::passes_luhn(&cleaned) && Self::is_valid_card_number(&cleaned)) {
-                detections.push(Detection {
+            if !validate || (luhn_valid(&cleaned) && Self::is_valid_card_number(&cleaned)) {
+                spans.push(Span {
                     pii_type: PiiType::CreditCard,
-                    confidence: self.confidence(),
                     start,
                     end,
-                    original: candidate.to_string(),
                 });
             }
         }
 
         // Sort by start position (required by trait contract)
-        detections.sort_by_key(|d| d.start);
-        detections
+        spans.sort_by_key(|s| s.start);
+        spans
     }
+}
 
-    /// Space or dash between digits is the anchor for credit card patterns
-    fn anchor_patterns(&self) -> Vec<&'static str> {
-        vec![" -", " "]
+impl PiiDetector for CreditCardDetector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::CreditCard
     }
 
-    fn detect_in_window(&self, window: &str, window_start: usize) -> Vec<Detection> {
-        self.detect_with_validation(window, true)
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        self.detect_with_validation(text, true)
+    }
+
+    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
+        self.spans(text, validate)
             .into_iter()
-            .map(|mut d| {
-                d.start += window_start;
-                d.end += window_start;
-                d
-            })
+            .map(|span| span.to_detection(text, self.confidence()))
             .collect()
     }
+
+    fn detect_spans(&self, text: &str) -> Vec<Span> {
+        self.spans(text, true)
+    }
+
+    fn validate(&self, candidate: &str) -> bool {
+        let cleaned: String = candidate.chars().filter(|c| c.is_ascii_digit()).collect();
+        luhn_valid(candidate) && Self::is_valid_card_number(&cleaned)
+    }
 }
 
 #[cfg(test)]
@@ -27,6 +27,15 @@ pub struct CustomRegexConfig {
     /// Optional regex flags (e.g., "i" for case-insensitive)
     #[serde(default)]
     pub flags: Option<String>,
+    /// Capture group whose span is the detection (default: the whole
+    /// match). Matches where the group doesn't participate are skipped.
+    #[serde(default)]
+    pub group: Option<usize>,
+    /// Only report matches with one of these keywords shortly before them
+    /// (case-insensitive, any language, e.g. `["DOB", "Geburtsdatum"]`).
+    /// Empty (the default) reports every match.
+    #[serde(default)]
+    pub context_keywords: Vec<String>,
 }
 
 fn default_placeholder() -> String {
@@ -44,6 +53,10 @@ pub struct CustomRegexDetector {
     pattern: Arc<Regex>,
     placeholder: String,
     confidence_level: crate::detector::Confidence,
+    /// Capture group to report instead of the whole match
+    group: Option<usize>,
+    /// Keywords gating matches; empty for no gating
+    context_keywords: Vec<String>,
 }
 
 impl CustomRegexDetector {
@@ -58,6 +71,16 @@ impl CustomRegexDetector {
 
         let pattern = Regex::new(&pattern_str)
             .map_err(|e| format!("Invalid regex pattern '{}': {}", config.pattern, e))?;
+        if let Some(group) = config.group {
+            if group >= pattern.captures_len() {
+                return Err(format!(
+                    "Capture group {} out of range for pattern '{}' ({} groups)",
+                    group,
+                    config.pattern,
+                    pattern.captures_len() - 1
+                ));
+            }
+        }
 
         let confidence_level = match config.confidence.to_lowercase().as_str() {
             "high" => crate::detector::Confidence::High,
@@ -74,6 +97,8 @@ impl CustomRegexDetector {
             pattern: Arc::new(pattern),
             placeholder: config.placeholder.clone(),
             confidence_level,
+            group: config.group,
+            context_keywords: config.context_keywords.clone(),
         })
     }
 
@@ -93,19 +118,37 @@ impl PiiDetector for CustomRegexDetector {
         PiiType::Other(self.name_static)
     }
 
+    fn name(&self) -> &str {
+        self.name_static
+    }
+
     fn confidence(&self) -> crate::detector::Confidence {
         self.confidence_level
     }
 
     fn detect(&self, text: &str) -> Vec<Detection> {
-        self.pattern
-            .find_iter(text)
+        let matches: Vec<regex::Match<'_>> = match self.group {
+            None => self.pattern.find_iter(text).collect(),
+            Some(group) => self
+                .pattern
+                .captures_iter(text)
+                .filter_map(|caps| caps.get(group))
+                .filter(|m| !m.is_empty())
+                .collect(),
+        };
+        matches
+            .into_iter()
+            .filter(|m| {
+                self.context_keywords.is_empty()
+                    || super::has_context(text, m.start(), &self.context_keywords)
+            })
             .map(|m| Detection {
                 pii_type: self.pii_type(),
                 confidence: self.confidence_level,
                 start: m.start(),
                 end: m.end(),
                 original: m.as_str().to_string(),
+                metadata: None,
             })
             .collect()
     }
@@ -149,6 +192,8 @@ mod tests {
             placeholder: "[EMP_ID]".to_string(),
             confidence: "high".to_string(),
             flags: None,
+            group: None,
+            context_keywords: Vec::new(),
         };
 
         let detector = CustomRegexDetector::from_config(&config).unwrap();
@@ -169,6 +214,8 @@ mod tests {
             placeholder: "[CASE]".to_string(),
             confidence: "medium".to_string(),
             flags: Some("i".to_string()),
+            group: None,
+            context_keywords: Vec::new(),
         };
 
         let detector = CustomRegexDetector::from_config(&config).unwrap();
@@ -188,12 +235,82 @@ mod tests {
             placeholder: "[REDacted]".to_string(),
             confidence: "medium".to_string(),
             flags: None,
+            group: None,
+            context_keywords: Vec::new(),
         };
 
         let result = CustomRegexDetector::from_config(&config);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_custom_regex_capture_group() {
+        use crate::{policy::RedactionPolicy, redactor::Redactor, types::PiiType};
+
+        let config = CustomRegexConfig {
+            name: "order_user".to_string(),
+            pattern: r"order:(\d+):user:(\w+@\w+\.\w+)".to_string(),
+            placeholder: "[USER]".to_string(),
+            confidence: "high".to_string(),
+            flags: None,
+            group: Some(2),
+            context_keywords: Vec::new(),
+        };
+        let detector = CustomRegexDetector::from_config(&config).unwrap();
+
+        let text = "GET order:4411:user:jane@example.com ok";
+        let detections = detector.detect(text);
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].original, "jane@example.com");
+        assert_eq!(
+            &text[detections[0].start..detections[0].end],
+            "jane@example.com"
+        );
+
+        let policy = RedactionPolicy::builder()
+            .enable(PiiType::Other("order_user"))
+            .build();
+        let redactor = Redactor::new(vec![Box::new(detector)], policy);
+        assert_eq!(
+            redactor.redact(text),
+            "GET order:4411:user:████████████████ ok"
+        );
+    }
+
+    #[test]
+    fn test_custom_regex_capture_group_validation() {
+        let config = |pattern: &str, group| CustomRegexConfig {
+            name: "grouped".to_string(),
+            pattern: pattern.to_string(),
+            placeholder: "[X]".to_string(),
+            confidence: "medium".to_string(),
+            flags: None,
+            group: Some(group),
+            context_keywords: Vec::new(),
+        };
+
+        let err = CustomRegexDetector::from_config(&config(r"id=(\d+)", 2))
+            .err()
+            .unwrap();
+        assert!(err.contains("Capture group 2 out of range"), "{err}");
+
+        // Group 0 is the whole match; optional groups that don't take part
+        // in a match produce no detection
+        let whole = CustomRegexDetector::from_config(&config(r"id=(\d+)", 0)).unwrap();
+        assert_eq!(whole.detect("id=42")[0].original, "id=42");
+        let optional = CustomRegexDetector::from_config(&config(r"id(?:=(\d+))?", 1)).unwrap();
+        let detections = optional.detect("id id=7");
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].original, "7");
+
+        let parsed: CustomRegexConfig =
+            serde_json::from_str(r#"{"name": "n", "pattern": "a(b)", "group": 1}"#).unwrap();
+        assert_eq!(parsed.group, Some(1));
+        let parsed: CustomRegexConfig =
+            serde_json::from_str(r#"{"name": "n", "pattern": "ab"}"#).unwrap();
+        assert_eq!(parsed.group, None);
+    }
+
     #[test]
     fn test_build_custom_detectors_batch() {
         let configs = vec![
@@ -203,6 +320,8 @@ mod tests {
                 placeholder: "[EMP]".to_string(),
                 confidence: "high".to_string(),
                 flags: None,
+                group: None,
+                context_keywords: Vec::new(),
             },
             CustomRegexConfig {
                 name: "invalid".to_string(),
@@ -210,6 +329,8 @@ mod tests {
                 placeholder: "[BAD]".to_string(),
                 confidence: "medium".to_string(),
                 flags: None,
+                group: None,
+                context_keywords: Vec::new(),
             },
         ];
 
@@ -227,6 +348,8 @@ mod tests {
             placeholder: "[PROJECT]".to_string(),
             confidence: "low".to_string(),
             flags: None,
+            group: None,
+            context_keywords: Vec::new(),
         };
 
         let detector = CustomRegexDetector::from_config(&config).unwrap();
@@ -244,6 +367,8 @@ mod tests {
             placeholder: "[SSN]".to_string(),
             confidence: "high".to_string(),
             flags: None,
+            group: None,
+            context_keywords: Vec::new(),
         };
 
         let detector = CustomRegexDetector::from_config(&config).unwrap();
@@ -265,4 +390,31 @@ mod tests {
         let result = redactor.redact(input);
         assert_eq!(result, "SSN: [SSN]");
     }
+
+    #[test]
+    fn test_context_keywords_gate_date_of_birth() {
+        let config = CustomRegexConfig {
+            name: "date_of_birth".to_string(),
+            pattern: r"\b\d{2}\.\d{2}\.\d{4}\b".to_string(),
+            placeholder: "[DOB]".to_string(),
+            confidence: "medium".to_string(),
+            flags: None,
+            group: None,
+            context_keywords: vec![
+                "DOB".to_string(),
+                "Geburtsdatum".to_string(),
+                "date de naissance".to_string(),
+            ],
+        };
+        let detector = CustomRegexDetector::from_config(&config).unwrap();
+
+        let detections = detector.detect("Name: Max Muster, Geburtsdatum: 01.02.1990");
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].original, "01.02.1990");
+        assert_eq!(detector.detect("Date de naissance 03.04.1985").len(), 1);
+
+        // No configured keyword nearby: an ordinary date isn't a birth date
+        assert!(detector.detect("Rechnungsdatum: 01.02.2024").is_empty());
+        assert!(detector.detect("Lieferung am 01.02.2024").is_empty());
+    }
 }
@@ -0,0 +1,210 @@
+//! DateOfBirthDetector - Detects calendar dates that may be dates of birth
+//!
+//! Dates related to an individual are HIPAA Safe Harbor identifiers. The
+//! detector is opt-in (DateOfBirth is enabled by the `hipaa()` profile, not
+//! the default policy) and treats every supported date as a candidate, since
+//! the text rarely says whose date it is.
+//!
+//! Supported formats:
+//! - `03/14/1990` (US `MM/DD/YYYY`; one-digit month or day allowed)
+//! - `1990-03-14` (ISO `YYYY-MM-DD`, date only)
+//! - `14 Mar 1990` (`DD Mon YYYY`; full month names and a trailing `.` allowed)
+//!
+//! `validate` checks month 1–12 and day 1–31. Dates embedded in longer
+//! separator runs (`1990-03-14-2`, `1/2/2020/3`) and ISO timestamps are not
+//! matched, and version strings like `1.2.3` never have the shape. In `Mask`
+//! mode digits and month names are masked and separators kept:
+//! `03/14/1990` → `██/██/████`.
+
+use crate::{
+    detector::{Confidence, Detection, PiiDetector},
+    types::PiiType,
+};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const MONTHS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+/// DateOfBirthDetector - detects US, ISO, and day-month-name dates
+pub struct DateOfBirthDetector {
+    pattern: &'static Regex,
+}
+
+impl Default for DateOfBirthDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DateOfBirthDetector {
+    /// Create a new DateOfBirthDetector
+    pub fn new() -> Self {
+        Self {
+            pattern: Self::get_pattern(),
+        }
+    }
+
+    fn get_pattern() -> &'static Regex {
+        static PATTERN: OnceLock<Regex> = OnceLock::new();
+        PATTERN.get_or_init(|| {
+            Regex::new(concat!(
+                r"\b(?:\d{1,2}/\d{1,2}/\d{4}",
+                r"|\d{4}-\d{2}-\d{2}",
+                r"|(?i:\d{1,2} (?:jan(?:uary)?|feb(?:ruary)?|mar(?:ch)?|apr(?:il)?|may|june?",
+                r"|july?|aug(?:ust)?|sep(?:t(?:ember)?)?|oct(?:ober)?|nov(?:ember)?",
+                r"|dec(?:ember)?)\.? \d{4}))\b",
+            ))
+            .expect("Date of birth pattern is valid")
+        })
+    }
+
+    /// Month and day of a candidate in any supported format
+    fn month_day(candidate: &str) -> Option<(u32, u32)> {
+        let num = |s: &str| s.parse::<u32>().ok();
+        if let Some((month, rest)) = candidate.split_once('/') {
+            let (day, _year) = rest.split_once('/')?;
+            return Some((num(month)?, num(day)?));
+        }
+        let mut parts = candidate.split([' ', '-']);
+        let (first, second) = (parts.next()?, parts.next()?);
+        if candidate.contains('-') {
+            // YYYY-MM-DD
+            return Some((num(second)?, num(parts.next()?)?));
+        }
+        // DD Mon YYYY
+        let name = second.trim_end_matches('.').to_ascii_lowercase();
+        let month = MONTHS.iter().position(|m| name.starts_with(m))? as u32 + 1;
+        Some((month, num(first)?))
+    }
+
+    /// Whether the date continues into a longer separator run or timestamp
+    fn is_embedded(text: &str, start: usize, end: usize) -> bool {
+        let bytes = text.as_bytes();
+        let before = start >= 2
+            && matches!(bytes[start - 1], b'/' | b'-' | b'.')
+            && bytes[start - 2].is_ascii_digit();
+        let after = end + 1 < bytes.len()
+            && matches!(bytes[end], b'/' | b'-' | b'.' | b':')
+            && bytes[end + 1].is_ascii_digit();
+        before || after
+    }
+}
+
+impl PiiDetector for DateOfBirthDetector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::DateOfBirth
+    }
+
+    fn confidence(&self) -> Confidence {
+        // Range-checked, but any date matches
+        Confidence::Medium
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        self.detect_with_validation(text, true)
+    }
+
+    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
+        self.pattern
+            .find_iter(text)
+            .filter(|m| !Self::is_embedded(text, m.start(), m.end()))
+            .filter(|m| !validate || self.validate(m.as_str()))
+            .map(|m| Detection {
+                pii_type: PiiType::DateOfBirth,
+                confidence: self.confidence(),
+                start: m.start(),
+                end: m.end(),
+                original: m.as_str().to_string(),
+                metadata: None,
+            })
+            .collect()
+    }
+
+    /// Month 1–12 and day 1–31
+    fn validate(&self, candidate: &str) -> bool {
+        Self::month_day(candidate)
+            .is_some_and(|(month, day)| (1..=12).contains(&month) && (1..=31).contains(&day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{policy::RedactionPolicy, redactor::Redactor};
+
+    fn found(text: &str) -> Vec<&str> {
+        DateOfBirthDetector::new()
+            .detect(text)
+            .iter()
+            .map(|d| &text[d.start..d.end])
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_us_format() {
+        assert_eq!(found("DOB: 03/14/1990."), vec!["03/14/1990"]);
+        assert_eq!(found("born 3/4/1990"), vec!["3/4/1990"]);
+    }
+
+    #[test]
+    fn test_detects_iso_format() {
+        let detections = DateOfBirthDetector::new().detect("dob 1990-03-14, admitted");
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].pii_type, PiiType::DateOfBirth);
+        assert_eq!(detections[0].original, "1990-03-14");
+        assert_eq!(detections[0].start, 4);
+    }
+
+    #[test]
+    fn test_detects_day_month_name_format() {
+        assert_eq!(
+            found("born 14 Mar 1990 and 2 september 1985 or 9 Sept. 2001"),
+            vec!["14 Mar 1990", "2 september 1985", "9 Sept. 2001"]
+        );
+    }
+
+    #[test]
+    fn test_rejects_out_of_range_dates() {
+        assert!(found("13/40/2020").is_empty());
+        assert!(found("00/10/2020").is_empty());
+        assert!(found("1990-02-32").is_empty());
+        assert!(found("32 Jan 1990").is_empty());
+
+        let detector = DateOfBirthDetector::new();
+        assert!(!detector.validate("13/40/2020"));
+        assert!(detector.validate("12/31/2020"));
+        // Candidates still match with validation off
+        assert_eq!(
+            detector.detect_with_validation("13/40/2020", false).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_rejects_versions_and_embedded_runs() {
+        assert!(found("version 1.2.3 and build 10.11.2020").is_empty());
+        assert!(found("range 1990-03-14-2 and 1/2/2020/3").is_empty());
+        assert!(found("at 2024-01-15T13:42:07Z").is_empty());
+        assert_eq!(found("at 2024-01-15 13:42"), vec!["2024-01-15"]);
+        assert!(found("ticket 12 Mayhem 2020").is_empty());
+    }
+
+    #[test]
+    fn test_masks_digits_and_keeps_separators() {
+        let redactor = Redactor::new(
+            vec![Box::new(DateOfBirthDetector::new())],
+            RedactionPolicy::hipaa(),
+        );
+        assert_eq!(
+            redactor.redact("DOB 03/14/1990, 1990-03-14, 14 Mar 1990"),
+            "DOB ██/██/████, ████-██-██, ██ ███ ████"
+        );
+        let default = Redactor::new(
+            vec![Box::new(DateOfBirthDetector::new())],
+            RedactionPolicy::default(),
+        );
+        assert_eq!(default.redact("DOB 03/14/1990"), "DOB 03/14/1990");
+    }
+}
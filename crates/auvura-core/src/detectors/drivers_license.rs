@@ -0,0 +1,227 @@
+//! DriversLicenseDetector - Detects US driver's license numbers
+//!
+//! Every state issues its own format, so the detector holds a map of state
+//! code to pattern. The defaults cover a few common shapes:
+//!
+//! | State | Format |
+//! |-------|--------|
+//! | CA | 1 letter + 7 digits (`A1234567`) |
+//! | FL | 1 letter + 12 digits |
+//! | IL | 1 letter + 11 digits |
+//! | NY | 9 digits |
+//! | TX | 8 digits |
+//!
+//! `with_state_patterns` replaces the map. Patterns need no anchors or `\b`:
+//! a match touching a letter or digit on either side is skipped. When two
+//! states match the same span, the detection is tagged with the first state
+//! code in alphabetical order (metadata key `state`).
+//!
+//! These are short digit runs, so detection is gated on context: a keyword
+//! such as `driver's license` or `DL` must appear shortly before the number.
+//! `with_keywords` replaces the keyword list.
+
+use super::has_context;
+use crate::{
+    detector::{Confidence, Detection, DetectionMetadata, PiiDetector},
+    types::PiiType,
+};
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Default context keywords for driver's license numbers
+pub const DL_KEYWORDS: &[&str] = &[
+    "driver's license",
+    "drivers license",
+    "driver license",
+    "driver's licence",
+    "driving licence",
+    "license no",
+    "license number",
+    "DL",
+    "DLN",
+];
+
+const DEFAULT_STATE_PATTERNS: [(&str, &str); 5] = [
+    ("CA", r"[A-Z]\d{7}"),
+    ("FL", r"[A-Z]\d{12}"),
+    ("IL", r"[A-Z]\d{11}"),
+    ("NY", r"\d{9}"),
+    ("TX", r"\d{8}"),
+];
+
+/// DriversLicenseDetector - detects keyword-gated, state-specific license numbers
+pub struct DriversLicenseDetector {
+    /// Sorted by state code
+    state_patterns: Vec<(String, Regex)>,
+    keywords: Vec<String>,
+}
+
+impl Default for DriversLicenseDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DriversLicenseDetector {
+    /// Create a new DriversLicenseDetector with the default state patterns
+    pub fn new() -> Self {
+        static PATTERNS: OnceLock<Vec<(String, Regex)>> = OnceLock::new();
+        let state_patterns = PATTERNS.get_or_init(|| {
+            DEFAULT_STATE_PATTERNS
+                .iter()
+                .map(|(state, pattern)| {
+                    let regex = Regex::new(pattern).expect("Driver's license pattern is valid");
+                    (state.to_string(), regex)
+                })
+                .collect()
+        });
+        Self {
+            state_patterns: state_patterns.clone(),
+            keywords: DL_KEYWORDS.iter().map(|k| k.to_string()).collect(),
+        }
+    }
+
+    /// Match these state formats instead of the defaults, keyed by state code
+    pub fn with_state_patterns(mut self, patterns: HashMap<String, Regex>) -> Self {
+        let mut state_patterns: Vec<(String, Regex)> = patterns.into_iter().collect();
+        state_patterns.sort_by(|a, b| a.0.cmp(&b.0));
+        self.state_patterns = state_patterns;
+        self
+    }
+
+    /// Gate on these keywords instead of [`DL_KEYWORDS`]
+    pub fn with_keywords(mut self, keywords: &[&str]) -> Self {
+        self.keywords = keywords.iter().map(|k| k.to_string()).collect();
+        self
+    }
+
+    /// First state whose pattern matches all of `candidate`
+    fn state_of(&self, candidate: &str) -> Option<&str> {
+        self.state_patterns
+            .iter()
+            .find(|(_, regex)| {
+                regex
+                    .find_iter(candidate)
+                    .any(|m| m.start() == 0 && m.end() == candidate.len())
+            })
+            .map(|(state, _)| state.as_str())
+    }
+}
+
+impl PiiDetector for DriversLicenseDetector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::DriversLicense
+    }
+
+    fn confidence(&self) -> Confidence {
+        // Keyword-gated, but formats carry no checksum
+        Confidence::Medium
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        self.detect_with_validation(text, true)
+    }
+
+    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
+        let bytes = text.as_bytes();
+        let mut spans: Vec<(usize, usize, &str)> = Vec::new();
+        for (state, regex) in &self.state_patterns {
+            for m in regex.find_iter(text) {
+                let embedded = (m.start() > 0 && bytes[m.start() - 1].is_ascii_alphanumeric())
+                    || bytes.get(m.end()).is_some_and(u8::is_ascii_alphanumeric);
+                if m.is_empty() || embedded || !has_context(text, m.start(), &self.keywords) {
+                    continue;
+                }
+                if !spans
+                    .iter()
+                    .any(|&(s, e, _)| (s, e) == (m.start(), m.end()))
+                {
+                    spans.push((m.start(), m.end(), state));
+                }
+            }
+        }
+        spans.sort_by_key(|&(start, end, _)| (start, end));
+
+        spans
+            .into_iter()
+            .filter(|&(start, end, _)| !validate || self.validate(&text[start..end]))
+            .map(|(start, end, state)| {
+                let metadata: DetectionMetadata = [("state".to_string(), state.to_string())].into();
+                Detection {
+                    pii_type: PiiType::DriversLicense,
+                    confidence: self.confidence(),
+                    start,
+                    end,
+                    original: text[start..end].to_string(),
+                    metadata: Some(metadata),
+                }
+            })
+            .collect()
+    }
+
+    /// Matches one of the state formats exactly
+    fn validate(&self, candidate: &str) -> bool {
+        self.state_of(candidate).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{policy::RedactionPolicy, redactor::Redactor};
+
+    fn found(text: &str) -> Vec<&str> {
+        DriversLicenseDetector::new()
+            .detect(text)
+            .iter()
+            .map(|d| &text[d.start..d.end])
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_california_license() {
+        let detections = DriversLicenseDetector::new().detect("California DL: A1234567");
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].pii_type, PiiType::DriversLicense);
+        assert_eq!(detections[0].original, "A1234567");
+        assert_eq!(detections[0].start, 15);
+        let state = detections[0].metadata.as_ref().unwrap().get("state");
+        assert_eq!(state.map(String::as_str), Some("CA"));
+        assert_eq!(found("Driver's License Number B7654321."), vec!["B7654321"]);
+    }
+
+    #[test]
+    fn test_requires_keyword() {
+        assert!(found("Order A1234567 shipped").is_empty());
+        assert!(found("invoice 12345678").is_empty());
+        assert_eq!(found("TX driver license 12345678"), vec!["12345678"]);
+    }
+
+    #[test]
+    fn test_skips_embedded_runs() {
+        assert!(found("DL: A12345678").is_empty());
+        assert!(found("DL: XA1234567").is_empty());
+        assert!(found("DL: 1234567890").is_empty());
+    }
+
+    #[test]
+    fn test_with_state_patterns_replaces_defaults() {
+        let patterns = HashMap::from([("WA".to_string(), Regex::new(r"WDL[A-Z0-9]{9}").unwrap())]);
+        let detector = DriversLicenseDetector::new().with_state_patterns(patterns);
+        let detections = detector.detect("WA DL WDLABC12345D, CA DL A1234567");
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].original, "WDLABC12345D");
+        assert!(detector.validate("WDLABC12345D"));
+        assert!(!detector.validate("A1234567"));
+    }
+
+    #[test]
+    fn test_masks_digits_and_keeps_leading_letters() {
+        let policy = RedactionPolicy::builder()
+            .enable(PiiType::DriversLicense)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(DriversLicenseDetector::new())], policy);
+        assert_eq!(redactor.redact("DL A1234567"), "DL A███████");
+    }
+}
@@ -5,14 +5,44 @@
 //! - Word boundary enforcement
 //! - Domain validation to reduce false positives
 //! - UTF-8 safe byte offsets
+//! - Path/URL-aware spans: `/`, `\`, `?`, `#` and their percent-encoded
+//!   forms (`%2F`, `%5C`, `%3F`, `%23`) delimit the address
 
 use crate::{
-    detector::{Detection, PiiDetector},
+    detector::{Detection, PiiDetector, Span},
     types::PiiType,
 };
 use regex::Regex;
 use std::sync::OnceLock;
 
+/// Percent-encoded URL/path separators that cannot be part of a local part
+/// in practice, even though `%` and hex digits are allowed characters
+const ENCODED_SEPARATORS: [&str; 7] = ["%2F", "%5C", "%3F", "%23", "%26", "%3D", "%3A"];
+
+/// Start of the local part for a regex match at `start..at` (`at` is the
+/// offset of `@`), cutting after the last percent-encoded separator.
+///
+/// The regex's `\b` may start a match just after a `%`, splitting an escape
+/// such as `%2F`; the preceding `%` is taken into account for that case.
+fn local_part_start(text: &str, start: usize, at: usize) -> usize {
+    let from = if text[..start].ends_with('%') {
+        start - 1
+    } else {
+        start
+    };
+    let local = &text[from..at];
+    ENCODED_SEPARATORS
+        .iter()
+        .filter_map(|sep| {
+            local
+                .as_bytes()
+                .windows(3)
+                .rposition(|w| w.eq_ignore_ascii_case(sep.as_bytes()))
+        })
+        .max()
+        .map_or(start, |pos| from + pos + 3)
+}
+
 /// EmailDetector - detects email addresses using RFC 5322 patterns
 pub struct EmailDetector {
     pattern: &'static Regex,
@@ -50,21 +80,30 @@ impl PiiDetector for EmailDetector {
     }
 
     fn detect(&self, text: &str) -> Vec<Detection> {
+        self.detect_spans(text)
+            .into_iter()
+            .map(|span| span.to_detection(text, self.confidence()))
+            .collect()
+    }
+
+    fn detect_spans(&self, text: &str) -> Vec<Span> {
         self.pattern
             .find_iter(text)
             .filter_map(|m| {
-                let email = m.as_str();
-                let local_part = email.split('@').next().unwrap_or("");
+                let at = m.start() + m.as_str().find('@')?;
+                let start = local_part_start(text, m.start(), at);
+                let local_part = &text[start..at];
+                if local_part.is_empty() || local_part.starts_with('.') {
+                    return None;
+                }
                 // Reject emails with consecutive dots in local part (invalid per RFC 5322)
                 if local_part.contains("..") {
                     return None;
                 }
-                Some(Detection {
+                Some(Span {
                     pii_type: PiiType::Email,
-                    confidence: self.confidence(),
-                    start: m.start(),
+                    start,
                     end: m.end(),
-                    original: email.to_string(),
                 })
             })
             .collect()
@@ -166,6 +205,24 @@ mod tests {
         assert!(!detections[0].original.ends_with(' '));
     }
 
+    #[test]
+    fn test_angle_brackets_and_incomplete_addresses() {
+        let detector = EmailDetector::new();
+        let text = "From: Jane <jane@example.com>, cc <a+tag@b.com>.";
+        let detections = detector.detect(text);
+        assert_eq!(detections.len(), 2);
+        assert_eq!(detections[0].original, "jane@example.com");
+        assert_eq!(
+            &text[detections[0].start..detections[0].end],
+            "jane@example.com"
+        );
+        assert_eq!(detections[1].original, "a+tag@b.com");
+
+        assert!(detector.detect("reply @ noon").is_empty());
+        assert!(detector.detect("send to user@ later").is_empty());
+        assert!(detector.detect("@example.com").is_empty());
+    }
+
     #[test]
     fn test_detects_emails_in_sentence() {
         let detector = EmailDetector::new();
@@ -260,6 +317,66 @@ mod tests {
         assert_eq!(detections.len(), 0);
     }
 
+    #[test]
+    fn test_email_in_url_path() {
+        let detector = EmailDetector::new();
+        let text = "GET /users/john@x.com/settings?ref=jane@y.org#top";
+        let detections = detector.detect(text);
+
+        assert_eq!(detections.len(), 2);
+        assert_eq!(detections[0].original, "john@x.com");
+        assert_eq!(&text[detections[0].start..detections[0].end], "john@x.com");
+        assert_eq!(detections[1].original, "jane@y.org");
+    }
+
+    #[test]
+    fn test_email_in_windows_path() {
+        let detector = EmailDetector::new();
+        let text = r"C:\Users\john@x.com\Desktop";
+        let detections = detector.detect(text);
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].start, 9);
+        assert_eq!(detections[0].original, "john@x.com");
+    }
+
+    #[test]
+    fn test_email_after_percent_encoded_separator() {
+        let detector = EmailDetector::new();
+        for text in [
+            "/api?path=%2Fusers%2Fjohn@x.com%2Fsettings",
+            "/api/%2fjohn@x.com",
+            "share%5Cjohn@x.com",
+        ] {
+            let detections = detector.detect(text);
+            assert_eq!(detections.len(), 1, "{text}");
+            assert_eq!(detections[0].original, "john@x.com", "{text}");
+        }
+        // A separator escape with nothing after it leaves no local part
+        assert!(detector.detect("/a%2F@x.com").is_empty());
+        // Other escapes are valid local-part characters
+        let detections = detector.detect("/u/a%41b@x.com");
+        assert_eq!(detections[0].original, "a%41b@x.com");
+    }
+
+    #[test]
+    fn test_path_email_redacts_only_the_address() {
+        use crate::{policy::RedactionPolicy, redactor::Redactor};
+
+        let redactor = Redactor::new(
+            vec![Box::new(EmailDetector::new())],
+            RedactionPolicy::default(),
+        );
+        assert_eq!(
+            redactor.redact("/users/john@x.com/settings"),
+            "/users/████@█.com/settings"
+        );
+        assert_eq!(
+            redactor.redact(r"C:\Users\john@x.com\Desktop"),
+            r"C:\Users\████@█.com\Desktop"
+        );
+    }
+
     // V2 ENHANCEMENT TRACKING
     // ========================
     // Quoted local parts (e.g., "john.doe"@example.com) are intentionally excluded from V1:
@@ -0,0 +1,150 @@
+//! EmployeeIdDetector - organization-specific prefixed employee IDs
+//!
+//! Many organizations issue IDs as a fixed prefix followed by a fixed number
+//! of digits (`E1234567`, `EMP123456`), often with an internal check digit.
+//! This detector is configured with the prefix, the digit count, and an
+//! optional checksum closure, so an organization can redact its own IDs
+//! deterministically without writing a full detector:
+//!
+//! ```
+//! use auvura_core::detectors::employee_id::EmployeeIdDetector;
+//!
+//! let detector = EmployeeIdDetector::new(
+//!     "EMP",
+//!     6,
+//!     Some(Box::new(|digits: &str| digits.ends_with('7'))),
+//! );
+//! ```
+//!
+//! The prefix is matched case-sensitively and must not touch other letters
+//! or digits. The checksum receives only the digits and is applied when
+//! strict validation is on. Detections are reported as
+//! `PiiType::Other("EMPLOYEE_ID")`, which must be enabled in the policy.
+
+use crate::{
+    detector::{Confidence, Detection, PiiDetector},
+    types::PiiType,
+};
+use regex::Regex;
+
+/// Org checksum over an ID's digits: return `true` if they are valid
+pub type IdChecksum = dyn Fn(&str) -> bool + Send + Sync;
+
+/// The type employee IDs are reported as
+pub const EMPLOYEE_ID: PiiType = PiiType::Other("EMPLOYEE_ID");
+
+/// EmployeeIdDetector - detects `<prefix><digits>` IDs with an optional checksum
+pub struct EmployeeIdDetector {
+    prefix_len: usize,
+    pattern: Regex,
+    validator: Option<Box<IdChecksum>>,
+}
+
+impl EmployeeIdDetector {
+    /// Detect `prefix` followed by exactly `digit_len` ASCII digits.
+    /// `validator`, if any, is the org's checksum over the digits.
+    pub fn new(prefix: &str, digit_len: usize, validator: Option<Box<IdChecksum>>) -> Self {
+        let pattern = format!("{}[0-9]{{{}}}", regex::escape(prefix), digit_len);
+        Self {
+            prefix_len: prefix.len(),
+            pattern: Regex::new(&pattern).expect("employee ID pattern is valid"),
+            validator,
+        }
+    }
+}
+
+impl PiiDetector for EmployeeIdDetector {
+    fn pii_type(&self) -> PiiType {
+        EMPLOYEE_ID
+    }
+
+    fn confidence(&self) -> Confidence {
+        // A passing checksum makes a false positive unlikely
+        if self.validator.is_some() {
+            Confidence::High
+        } else {
+            Confidence::Medium
+        }
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        self.detect_with_validation(text, true)
+    }
+
+    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
+        let touches_word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+        self.pattern
+            .find_iter(text)
+            .filter(|m| {
+                !touches_word(text[..m.start()].chars().next_back())
+                    && !touches_word(text[m.end()..].chars().next())
+            })
+            .filter(|m| !validate || self.validate(m.as_str()))
+            .map(|m| Detection {
+                pii_type: EMPLOYEE_ID,
+                confidence: self.confidence(),
+                start: m.start(),
+                end: m.end(),
+                original: m.as_str().to_string(),
+                metadata: None,
+            })
+            .collect()
+    }
+
+    fn validate(&self, candidate: &str) -> bool {
+        match (&self.validator, candidate.get(self.prefix_len..)) {
+            (Some(validator), Some(digits)) => validator(digits),
+            (None, _) => true,
+            (Some(_), None) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{detector::luhn_valid, policy::RedactionPolicy, redactor::Redactor};
+
+    fn emp_detector() -> EmployeeIdDetector {
+        EmployeeIdDetector::new("EMP", 6, Some(Box::new(luhn_valid)))
+    }
+
+    #[test]
+    fn test_checksum_accepts_valid_and_rejects_invalid() {
+        let detector = emp_detector();
+        let text = "Badge EMP123455 and EMP123456";
+        let found: Vec<String> = detector
+            .detect_with_validation(text, true)
+            .into_iter()
+            .map(|d| d.original.clone())
+            .collect();
+        assert_eq!(found, vec!["EMP123455"]);
+        assert_eq!(detector.confidence(), Confidence::High);
+
+        // Without strict validation the checksum is skipped
+        assert_eq!(detector.detect_with_validation(text, false).len(), 2);
+    }
+
+    #[test]
+    fn test_redacts_under_strict_mode() {
+        let policy = RedactionPolicy::builder()
+            .enable(EMPLOYEE_ID)
+            .strict_validation(true)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(emp_detector())], policy);
+        assert_eq!(
+            redactor.redact("EMP123455 approved EMP123456"),
+            "█████████ approved EMP123456"
+        );
+    }
+
+    #[test]
+    fn test_length_and_boundaries() {
+        let detector = EmployeeIdDetector::new("EMP", 6, None);
+        assert_eq!(detector.confidence(), Confidence::Medium);
+        assert_eq!(detector.detect("id: EMP004217.").len(), 1);
+        // Wrong digit count, embedded, or wrong case
+        assert!(detector.detect("EMP12345 EMP1234567").is_empty());
+        assert!(detector.detect("XEMP123456 emp123456").is_empty());
+    }
+}
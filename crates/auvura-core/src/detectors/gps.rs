@@ -0,0 +1,245 @@
+//! GpsCoordinateDetector - Detects GPS positions in degrees/minutes/seconds
+//!
+//! Image metadata dumped to text (ExifTool, `exif`, camera logs) writes
+//! positions as DMS strings:
+//!
+//! - `GPSLatitude: 37 deg 46' 29.64"`
+//! - `GPS Latitude                    : 37 deg 46' 29.64" N`
+//! - `Longitude: 122° 25′ 9.85″ W`
+//!
+//! A precise position is location data that can single out a person's
+//! home or workplace. The DMS shape alone also fits angles in engineering
+//! notes, so detection is gated on context: `GPS`, `Latitude`, or
+//! `Longitude` (or an EXIF tag such as `GPSLatitude`, case-insensitive)
+//! must appear earlier on the same line. The second value of a pair like
+//! `GPS Position : 37 deg 46' 29.64" N, 122 deg 25' 9.85" W` is accepted
+//! through the first. `with_keywords` replaces the keyword list.
+//!
+//! Validation checks the ranges: degrees up to 180 (90 with an `N`/`S`
+//! hemisphere), minutes and seconds below 60.
+//!
+//! In `Mask` mode the numbers are masked and units and hemisphere kept:
+//! `37 deg 46' 29.64" N` → `██ deg ██' █████" N`.
+
+use super::has_context_within;
+use crate::{
+    detector::{Detection, PiiDetector},
+    types::PiiType,
+};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Default context keywords for GPS coordinates
+pub const GPS_KEYWORDS: &[&str] = &[
+    "GPS",
+    "Latitude",
+    "Longitude",
+    "GPSLatitude",
+    "GPSLongitude",
+    "GPSDestLatitude",
+    "GPSDestLongitude",
+];
+
+/// How far back on the line keywords are looked for. ExifTool pads tag
+/// names to 32 columns, so a value sits about 35 bytes after its tag.
+const LOOKBACK: usize = 48;
+
+/// GpsCoordinateDetector - detects context-gated DMS coordinates
+pub struct GpsCoordinateDetector {
+    pattern: &'static Regex,
+    keywords: Vec<String>,
+}
+
+impl Default for GpsCoordinateDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GpsCoordinateDetector {
+    /// Create a new GpsCoordinateDetector
+    pub fn new() -> Self {
+        Self {
+            pattern: Self::get_pattern(),
+            keywords: GPS_KEYWORDS.iter().map(|k| k.to_string()).collect(),
+        }
+    }
+
+    /// Gate on these keywords instead of [`GPS_KEYWORDS`]
+    pub fn with_keywords(mut self, keywords: &[&str]) -> Self {
+        self.keywords = keywords.iter().map(|k| k.to_string()).collect();
+        self
+    }
+
+    fn get_pattern() -> &'static Regex {
+        static PATTERN: OnceLock<Regex> = OnceLock::new();
+        PATTERN.get_or_init(|| {
+            // degrees, minutes, seconds, optional hemisphere
+            Regex::new(
+                r#"\b(\d{1,3})[ \t]*(?:deg|°)[ \t]*(\d{1,2})[ \t]*['′][ \t]*(\d{1,2}(?:\.\d+)?)[ \t]*(?:''|"|″)(?:[ \t]*([NSEW])\b)?"#,
+            )
+            .expect("GPS coordinate pattern is valid")
+        })
+    }
+
+    /// Whether a keyword appears before `start` on the same line
+    fn has_line_context(&self, text: &str, start: usize) -> bool {
+        let line_start = text[..start].rfind('\n').map_or(0, |i| i + 1);
+        has_context_within(
+            &text[line_start..],
+            start - line_start,
+            &self.keywords,
+            LOOKBACK,
+        )
+    }
+}
+
+impl PiiDetector for GpsCoordinateDetector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::GpsCoordinate
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        self.detect_with_validation(text, true)
+    }
+
+    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
+        let mut detections: Vec<Detection> = Vec::new();
+        for m in self.pattern.find_iter(text) {
+            // The longitude of a `lat, lon` pair shares the latitude's context
+            let follows_pair = detections.last().is_some_and(|prev| {
+                text[prev.end..m.start()]
+                    .bytes()
+                    .all(|b| matches!(b, b' ' | b'\t' | b',' | b';' | b'/'))
+            });
+            if !follows_pair && !self.has_line_context(text, m.start()) {
+                continue;
+            }
+            if validate && !self.validate(m.as_str()) {
+                continue;
+            }
+            detections.push(Detection {
+                pii_type: PiiType::GpsCoordinate,
+                confidence: self.confidence(),
+                start: m.start(),
+                end: m.end(),
+                original: m.as_str().to_string(),
+                metadata: None,
+            });
+        }
+        detections
+    }
+
+    /// Degrees up to 180 (90 north or south), minutes and seconds below 60
+    fn validate(&self, candidate: &str) -> bool {
+        let Some(caps) = self.pattern.captures(candidate) else {
+            return false;
+        };
+        let (Ok(degrees), Ok(minutes), Ok(seconds)) = (
+            caps[1].parse::<u32>(),
+            caps[2].parse::<u32>(),
+            caps[3].parse::<f64>(),
+        ) else {
+            return false;
+        };
+        let max_degrees = match caps.get(4).map(|h| h.as_str()) {
+            Some("N" | "S") => 90,
+            _ => 180,
+        };
+        minutes < 60
+            && seconds < 60.0
+            && (degrees < max_degrees || (degrees == max_degrees && minutes == 0 && seconds == 0.0))
+    }
+
+    /// Every DMS value has a degree marker
+    fn anchor_patterns(&self) -> Vec<&'static str> {
+        vec!["deg", "°"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{policy::RedactionPolicy, redactor::Redactor};
+
+    fn found(text: &str) -> Vec<&str> {
+        GpsCoordinateDetector::new()
+            .detect(text)
+            .iter()
+            .map(|d| &text[d.start..d.end])
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_exif_latitude() {
+        let detections = GpsCoordinateDetector::new().detect("GPSLatitude: 37 deg 46' 29.64\"");
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].pii_type, PiiType::GpsCoordinate);
+        assert_eq!(detections[0].original, "37 deg 46' 29.64\"");
+        assert_eq!(detections[0].start, 13);
+        assert_eq!(
+            found("GPS Latitude: 37 deg 46' 29.64\" N"),
+            vec!["37 deg 46' 29.64\" N"]
+        );
+        assert_eq!(
+            found("Longitude: 122° 25′ 9.85″ W"),
+            vec!["122° 25′ 9.85″ W"]
+        );
+    }
+
+    #[test]
+    fn test_exiftool_position_pair() {
+        let dump = "Make                            : Canon\n\
+                    GPS Position                    : 37 deg 46' 29.64\" N, 122 deg 25' 9.85\" W\n";
+        assert_eq!(
+            found(dump),
+            vec!["37 deg 46' 29.64\" N", "122 deg 25' 9.85\" W"]
+        );
+    }
+
+    #[test]
+    fn test_requires_context() {
+        assert!(found("Bend the pipe 37 deg 46' 29.64\" past the joint").is_empty());
+        // Context on another line doesn't count
+        assert!(found("GPS: off\nBend 37 deg 46' 29.64\"").is_empty());
+        let detector = GpsCoordinateDetector::new().with_keywords(&["Breite"]);
+        assert!(detector.detect("Latitude: 37 deg 46' 29.64\"").is_empty());
+        assert_eq!(detector.detect("Breite: 37 deg 46' 29.64\"").len(), 1);
+    }
+
+    #[test]
+    fn test_validates_ranges() {
+        assert!(found("GPS Latitude: 97 deg 46' 29.64\" N").is_empty());
+        assert!(found("GPS Latitude: 37 deg 61' 29.64\" N").is_empty());
+        assert!(found("GPS Latitude: 37 deg 46' 60.5\" N").is_empty());
+        assert!(found("GPS Longitude: 180 deg 1' 0\" W").is_empty());
+        assert_eq!(found("GPS Longitude: 180 deg 0' 0\" W").len(), 1);
+        assert_eq!(found("GPS Longitude: 97 deg 46' 29.64\" W").len(), 1);
+
+        let detector = GpsCoordinateDetector::new();
+        assert!(!detector.validate("37 deg 61' 29.64\""));
+        assert!(detector.validate("90 deg 0' 0\" S"));
+        assert_eq!(
+            detector
+                .detect_with_validation("GPS Latitude: 97 deg 46' 29.64\" N", false)
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_mask_redacts_numbers() {
+        let policy = RedactionPolicy::builder()
+            .enable(PiiType::GpsCoordinate)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(GpsCoordinateDetector::new())], policy);
+        assert_eq!(
+            redactor.redact("GPS Latitude: 37 deg 46' 29.64\" N"),
+            "GPS Latitude: ██ deg ██' █████\" N"
+        );
+        assert_eq!(
+            redactor.redact("Rotate 37 deg 46' 29.64\" clockwise"),
+            "Rotate 37 deg 46' 29.64\" clockwise"
+        );
+    }
+}
@@ -5,6 +5,9 @@
 //!   - 2-letter country code (ISO 3166-1 alpha-2)
 //!   - 2 check digits (mod-97)
 //!   - BBAN (Basic Bank Account Number, length varies by country)
+//!
+//! Candidates must also have the exact length registered for their country
+//! (e.g. 22 for DE, 27 for FR); unregistered country codes are rejected.
 
 use crate::{
     detector::{Confidence, Detection, PiiDetector},
@@ -13,6 +16,106 @@ use crate::{
 use regex::Regex;
 use std::sync::OnceLock;
 
+/// Total IBAN length per country, from the SWIFT IBAN registry
+const IBAN_LENGTHS: &[(&str, usize)] = &[
+    ("AD", 24),
+    ("AE", 23),
+    ("AL", 28),
+    ("AT", 20),
+    ("AZ", 28),
+    ("BA", 20),
+    ("BE", 16),
+    ("BG", 22),
+    ("BH", 22),
+    ("BI", 27),
+    ("BR", 29),
+    ("BY", 28),
+    ("CH", 21),
+    ("CR", 22),
+    ("CY", 28),
+    ("CZ", 24),
+    ("DE", 22),
+    ("DJ", 27),
+    ("DK", 18),
+    ("DO", 28),
+    ("EE", 20),
+    ("EG", 29),
+    ("ES", 24),
+    ("FI", 18),
+    ("FK", 18),
+    ("FO", 18),
+    ("FR", 27),
+    ("GB", 22),
+    ("GE", 22),
+    ("GI", 23),
+    ("GL", 18),
+    ("GR", 27),
+    ("GT", 28),
+    ("HR", 21),
+    ("HU", 28),
+    ("IE", 22),
+    ("IL", 23),
+    ("IQ", 23),
+    ("IS", 26),
+    ("IT", 27),
+    ("JO", 30),
+    ("KW", 30),
+    ("KZ", 20),
+    ("LB", 28),
+    ("LC", 32),
+    ("LI", 21),
+    ("LT", 20),
+    ("LU", 20),
+    ("LV", 21),
+    ("LY", 25),
+    ("MC", 27),
+    ("MD", 24),
+    ("ME", 22),
+    ("MK", 19),
+    ("MN", 20),
+    ("MR", 27),
+    ("MT", 31),
+    ("MU", 30),
+    ("NI", 28),
+    ("NL", 18),
+    ("NO", 15),
+    ("OM", 23),
+    ("PK", 24),
+    ("PL", 28),
+    ("PS", 29),
+    ("PT", 25),
+    ("QA", 29),
+    ("RO", 24),
+    ("RS", 22),
+    ("RU", 33),
+    ("SA", 24),
+    ("SC", 31),
+    ("SD", 18),
+    ("SE", 24),
+    ("SI", 19),
+    ("SK", 24),
+    ("SM", 27),
+    ("SO", 23),
+    ("ST", 25),
+    ("SV", 28),
+    ("TL", 23),
+    ("TN", 24),
+    ("TR", 26),
+    ("UA", 29),
+    ("VA", 22),
+    ("VG", 24),
+    ("XK", 20),
+    ("YE", 30),
+];
+
+/// Registered IBAN length for a country code
+fn iban_length(country: &str) -> Option<usize> {
+    IBAN_LENGTHS
+        .binary_search_by_key(&country, |&(code, _)| code)
+        .ok()
+        .map(|i| IBAN_LENGTHS[i].1)
+}
+
 /// IBAN detector with mod-97 checksum validation
 pub struct IbanDetector {
     pattern: &'static Regex,
@@ -53,8 +156,12 @@ impl IbanDetector {
             .filter(|c| !c.is_whitespace() && *c != '-')
             .collect();
 
-        // Must be 15-34 characters
-        if cleaned.len() < 15 || cleaned.len() > 34 {
+        // Must be 15-34 characters, and exactly the country's length
+        if cleaned.len() < 15
+            || cleaned.len() > 34
+            || !cleaned.is_char_boundary(2)
+            || iban_length(&cleaned[..2]) != Some(cleaned.len())
+        {
             return false;
         }
 
@@ -131,8 +238,8 @@ impl PiiDetector for IbanDetector {
                     return None;
                 }
 
-                // Validate with mod-97 checksum
-                if !Self::validate_iban(candidate) {
+                // Validate country length and mod-97 checksum
+                if !self.validate(candidate) {
                     return None;
                 }
 
@@ -151,11 +258,16 @@ impl PiiDetector for IbanDetector {
                     start,
                     end,
                     original: candidate.to_string(),
+                    metadata: None,
                 })
             })
             .collect()
     }
 
+    fn validate(&self, candidate: &str) -> bool {
+        Self::validate_iban(candidate)
+    }
+
     fn anchor_patterns(&self) -> Vec<&'static str> {
         // No reliable single anchor for IBANs — fall back to full regex scan
         Vec::new()
@@ -257,4 +369,20 @@ mod tests {
         let detections = detector.detect("iban: de89370400440532013000");
         assert_eq!(detections.len(), 0);
     }
+
+    #[test]
+    fn test_country_length_table() {
+        let detector = IbanDetector::new();
+        // Sorted for binary search
+        assert!(IBAN_LENGTHS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(iban_length("DE"), Some(22));
+        assert_eq!(iban_length("ZZ"), None);
+
+        assert!(detector.validate("DE89 3704 0044 0532 0130 00"));
+        assert!(!detector.validate("DE89 3704 0044 0532 0130 01"));
+        // Norway's 15-character IBAN
+        assert!(detector.validate("NO9386011117947"));
+        // A valid checksum with a digit appended no longer fits DE's length
+        assert!(detector.detect("DE8937040044053201300012").is_empty());
+    }
 }
@@ -4,13 +4,18 @@
 //! - Regex-based candidate detection
 //! - `std::net::IpAddr` validation (RFC-compliant)
 //! - Word boundary enforcement for IPv4, manual boundaries for IPv6
+//!
+//! `IpDetector` runs both and tags each match with its own `PiiType`.
 
 use crate::{
-    detector::{Confidence, Detection, PiiDetector},
+    detector::{Confidence, Detection, PiiDetector, Span},
     types::PiiType,
 };
 use regex::Regex;
-use std::{net::IpAddr, sync::OnceLock};
+use std::{
+    net::{Ipv4Addr, Ipv6Addr},
+    sync::OnceLock,
+};
 
 /// Detects IPv4 addresses (dotted-decimal notation)
 pub struct Ipv4Detector {
@@ -37,6 +42,40 @@ impl Ipv4Detector {
             Regex::new(r"\b(?:\d{1,3}\.){3}\d{1,3}\b").expect("IPv4 regex pattern is valid")
         })
     }
+
+    /// Reject four groups cut out of a longer dotted run, like the
+    /// `2.3.4.5` in build string `1.2.3.4.5`
+    fn is_bounded(text: &str, start: usize, end: usize) -> bool {
+        let bytes = text.as_bytes();
+        let dotted_digit_before =
+            start >= 2 && bytes[start - 1] == b'.' && bytes[start - 2].is_ascii_digit();
+        let dotted_digit_after =
+            end + 1 < bytes.len() && bytes[end] == b'.' && bytes[end + 1].is_ascii_digit();
+        !dotted_digit_before && !dotted_digit_after
+    }
+
+    /// Matched spans, checked with [`PiiDetector::validate`] when `validate`
+    /// is set
+    fn spans(&self, text: &str, validate: bool) -> Vec<Span> {
+        self.pattern
+            .find_iter(text)
+            .filter_map(|m| {
+                let addr = m.as_str();
+                if !Self::is_bounded(text, m.start(), m.end()) {
+                    return None;
+                }
+                if !validate || self.validate(addr) {
+                    Some(Span {
+                        pii_type: PiiType::IpAddressV4,
+                        start: m.start(),
+                        end: m.end(),
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
 }
 
 impl PiiDetector for Ipv4Detector {
@@ -53,25 +92,21 @@ impl PiiDetector for Ipv4Detector {
     }
 
     fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
-        self.pattern
-            .find_iter(text)
-            .filter_map(|m| {
-                let addr = m.as_str();
-                if !validate || addr.parse::<IpAddr>().is_ok() {
-                    Some(Detection {
-                        pii_type: PiiType::IpAddressV4,
-                        confidence: self.confidence(),
-                        start: m.start(),
-                        end: m.end(),
-                        original: addr.to_string(),
-                    })
-                } else {
-                    None
-                }
-            })
+        self.spans(text, validate)
+            .into_iter()
+            .map(|span| span.to_detection(text, self.confidence()))
             .collect()
     }
 
+    fn detect_spans(&self, text: &str) -> Vec<Span> {
+        self.spans(text, true)
+    }
+
+    /// Four octets of 0–255 without leading zeros
+    fn validate(&self, candidate: &str) -> bool {
+        candidate.parse::<Ipv4Addr>().is_ok()
+    }
+
     /// Dot-separated digits is the anchor for IPv4 (e.g., `192.168.`)
     fn anchor_patterns(&self) -> Vec<&'static str> {
         vec!["."]
@@ -137,22 +172,10 @@ impl Ipv6Detector {
         }
         true
     }
-}
-
-impl PiiDetector for Ipv6Detector {
-    fn pii_type(&self) -> PiiType {
-        PiiType::IpAddressV6
-    }
 
-    fn confidence(&self) -> Confidence {
-        Confidence::High
-    }
-
-    fn detect(&self, text: &str) -> Vec<Detection> {
-        self.detect_with_validation(text, true)
-    }
-
-    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
+    /// Matched spans, checked with [`PiiDetector::validate`] when `validate`
+    /// is set
+    fn spans(&self, text: &str, validate: bool) -> Vec<Span> {
         self.pattern
             .find_iter(text)
             .filter_map(|m| {
@@ -165,13 +188,11 @@ impl PiiDetector for Ipv6Detector {
                 if !Self::is_bounded(text, m.start(), m.end()) {
                     return None;
                 }
-                if !validate || candidate.parse::<IpAddr>().is_ok() {
-                    Some(Detection {
+                if !validate || self.validate(candidate) {
+                    Some(Span {
                         pii_type: PiiType::IpAddressV6,
-                        confidence: self.confidence(),
                         start: m.start(),
                         end: m.end(),
-                        original: candidate.to_string(),
                     })
                 } else {
                     None
@@ -179,6 +200,36 @@ impl PiiDetector for Ipv6Detector {
             })
             .collect()
     }
+}
+
+impl PiiDetector for Ipv6Detector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::IpAddressV6
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        self.detect_with_validation(text, true)
+    }
+
+    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
+        self.spans(text, validate)
+            .into_iter()
+            .map(|span| span.to_detection(text, self.confidence()))
+            .collect()
+    }
+
+    fn detect_spans(&self, text: &str) -> Vec<Span> {
+        self.spans(text, true)
+    }
+
+    /// Any RFC 4291 text form, including `::` compression and an IPv4 suffix
+    fn validate(&self, candidate: &str) -> bool {
+        candidate.parse::<Ipv6Addr>().is_ok()
+    }
 
     /// Colon is the anchor for IPv6 addresses
     fn anchor_patterns(&self) -> Vec<&'static str> {
@@ -197,6 +248,86 @@ impl PiiDetector for Ipv6Detector {
     }
 }
 
+/// Detects IPv4 and IPv6 addresses in one pass, emitting `IpAddressV4` or
+/// `IpAddressV6` per match.
+///
+/// `pii_type()` reports `IpAddressV4`; enabling only one IP type in the
+/// policy still drops the other's matches.
+#[derive(Default)]
+pub struct IpDetector {
+    v4: Ipv4Detector,
+    v6: Ipv6Detector,
+}
+
+impl IpDetector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Spans from both detectors, sorted by start
+    fn spans(&self, text: &str, validate: bool) -> Vec<Span> {
+        // An IPv4-suffixed IPv6 address also contains an IPv4 match
+        let v6 = self.v6.spans(text, validate);
+        let mut spans: Vec<Span> = self
+            .v4
+            .spans(text, validate)
+            .into_iter()
+            .filter(|s| !v6.iter().any(|v| s.start < v.end && s.end > v.start))
+            .collect();
+        spans.extend(v6);
+        spans.sort_by_key(|s| s.start);
+        spans
+    }
+}
+
+impl PiiDetector for IpDetector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::IpAddressV4
+    }
+
+    fn name(&self) -> &str {
+        "IpDetector"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        self.detect_with_validation(text, true)
+    }
+
+    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
+        self.spans(text, validate)
+            .into_iter()
+            .map(|span| span.to_detection(text, self.confidence()))
+            .collect()
+    }
+
+    fn detect_spans(&self, text: &str) -> Vec<Span> {
+        self.spans(text, true)
+    }
+
+    fn validate(&self, candidate: &str) -> bool {
+        self.v4.validate(candidate) || self.v6.validate(candidate)
+    }
+
+    fn anchor_patterns(&self) -> Vec<&'static str> {
+        vec![".", ":"]
+    }
+
+    fn detect_in_window(&self, window: &str, window_start: usize) -> Vec<Detection> {
+        self.detect_with_validation(window, true)
+            .into_iter()
+            .map(|mut d| {
+                d.start += window_start;
+                d.end += window_start;
+                d
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -363,6 +494,54 @@ mod tests {
         assert_eq!(detections[1].original, "::1");
     }
 
+    #[test]
+    fn test_rejects_version_and_build_strings() {
+        let detector = Ipv4Detector::new();
+        assert!(detector.detect("release 1.2.3 is out").is_empty());
+        assert!(detector.detect("build 1.2.3.4.5").is_empty());
+        assert!(detector
+            .detect_with_validation("build 10.0.19041.1.2", false)
+            .is_empty());
+        // A trailing full stop is punctuation, not another group
+        assert_eq!(detector.detect("Reach 10.0.0.1.").len(), 1);
+    }
+
+    #[test]
+    fn test_validate_parses_each_family() {
+        let (v4, v6, ip) = (Ipv4Detector::new(), Ipv6Detector::new(), IpDetector::new());
+        assert!(v4.validate("192.168.1.1"));
+        assert!(!v4.validate("999.999.999.999"));
+        assert!(v6.validate("::1"));
+        assert!(!v6.validate("1::2::3"));
+        assert!(ip.validate("10.0.0.1") && ip.validate("fe80::1"));
+        assert!(!ip.validate("1.2.3"));
+    }
+
+    #[test]
+    fn test_ip_detector_tags_each_family() {
+        let detector = IpDetector::new();
+        let text = "v4 192.168.1.1, v6 ::1, mapped ::ffff:10.0.0.1, version 1.2.3";
+        let found: Vec<(PiiType, &str)> = detector
+            .detect(text)
+            .iter()
+            .map(|d| (d.pii_type, &text[d.start..d.end]))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (PiiType::IpAddressV4, "192.168.1.1"),
+                (PiiType::IpAddressV6, "::1"),
+                (PiiType::IpAddressV6, "::ffff:10.0.0.1"),
+            ]
+        );
+
+        let redactor = Redactor::new(vec![Box::new(detector)], RedactionPolicy::default());
+        assert_eq!(
+            redactor.redact("at 999.999.999.999 or ::1"),
+            "at 999.999.999.999 or ███"
+        );
+    }
+
     #[test]
     fn test_zeroizes_on_drop() {
         let detector = Ipv4Detector::new();
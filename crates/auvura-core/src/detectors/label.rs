@@ -0,0 +1,173 @@
+//! FieldLabelDetector - label-driven detection in forms-as-text
+//!
+//! In text like `Name: John Smith\nSSN: 123-45-XXXX` the label says more
+//! than the value: a partially masked or oddly formatted SSN won't match the
+//! SSN pattern, but it is still sensitive. This detector reports whatever
+//! follows a configured label on the same line, regardless of content:
+//!
+//! - The label must be a whole word (`SSN:` matches, `XSSN:` does not) and is
+//!   matched ASCII case-insensitively
+//! - The label is followed by optional spaces/tabs and a `:`
+//! - The value runs to the end of the line, with surrounding whitespace
+//!   trimmed; empty values are not reported
+//!
+//! Each detector reports a single PII type. Since a labelled value can have
+//! any format, detections are marked opaque ([`OPAQUE_METADATA_KEY`]): `Mask`
+//! mode blanks every character rather than applying the type's structured
+//! mask (which for `Ssn` would only mask digits), while modes like `Replace`
+//! render as for any match of the type.
+
+use crate::{
+    detector::{Confidence, Detection, PiiDetector, OPAQUE_METADATA_KEY},
+    types::PiiType,
+};
+use regex::Regex;
+
+/// FieldLabelDetector - detects values that follow configured field labels
+pub struct FieldLabelDetector {
+    pii_type: PiiType,
+    pattern: Regex,
+}
+
+impl FieldLabelDetector {
+    /// Report values after any of `labels` (e.g. `["SSN", "Social Security
+    /// Number"]`) as `pii_type`
+    pub fn new(pii_type: PiiType, labels: &[&str]) -> Self {
+        let mut labels: Vec<&str> = labels
+            .iter()
+            .map(|l| l.trim())
+            .filter(|l| !l.is_empty())
+            .collect();
+        // Longest first, so `SSN Number` wins over `SSN`
+        labels.sort_unstable_by_key(|l| std::cmp::Reverse(l.len()));
+        labels.dedup();
+        let alternation = if labels.is_empty() {
+            // Matches nothing: a label cannot be empty
+            r"[^\s\S]".to_string()
+        } else {
+            labels
+                .iter()
+                .map(|l| regex::escape(l))
+                .collect::<Vec<_>>()
+                .join("|")
+        };
+        let pattern = format!(r"(?im)(?:^|[^A-Za-z0-9_])(?:{alternation})[ \t]*:([^\r\n]*)");
+        Self {
+            pii_type,
+            pattern: Regex::new(&pattern).expect("field label pattern is valid"),
+        }
+    }
+}
+
+impl PiiDetector for FieldLabelDetector {
+    fn pii_type(&self) -> PiiType {
+        self.pii_type
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        self.pattern
+            .captures_iter(text)
+            .filter_map(|caps| {
+                let value = caps.get(1)?;
+                let raw = value.as_str();
+                let trimmed = raw.trim_start();
+                let start = value.start() + (raw.len() - trimmed.len());
+                let end = start + trimmed.trim_end().len();
+                (end > start).then(|| Detection {
+                    pii_type: self.pii_type,
+                    confidence: self.confidence(),
+                    start,
+                    end,
+                    original: text[start..end].to_string(),
+                    metadata: Some([(OPAQUE_METADATA_KEY.to_string(), "true".to_string())].into()),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        detectors::ssn::SSNDetector,
+        policy::{RedactionMode, RedactionPolicy},
+        redactor::Redactor,
+    };
+
+    fn ssn_label() -> FieldLabelDetector {
+        FieldLabelDetector::new(PiiType::Ssn, &["SSN", "Social Security Number"])
+    }
+
+    #[test]
+    fn test_redacts_partial_ssn_after_label() {
+        let input = "Name: John Smith\nSSN: 123-45-XXXX\nPlan: gold";
+        let detections = ssn_label().detect(input);
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].original, "123-45-XXXX");
+
+        let redactor = Redactor::new(
+            vec![Box::new(ssn_label()), Box::new(SSNDetector::new())],
+            RedactionPolicy::default(),
+        );
+        assert_eq!(
+            redactor.redact(input),
+            "Name: John Smith\nSSN: ███████████\nPlan: gold"
+        );
+    }
+
+    #[test]
+    fn test_masks_non_digit_value_whole() {
+        let redactor = Redactor::new(vec![Box::new(ssn_label())], RedactionPolicy::default());
+        assert_eq!(redactor.redact("SSN: on file ABCD"), "SSN: ████████████");
+
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::FormatPreserving { mask_char: '*' })
+            .build();
+        let redactor = Redactor::new(vec![Box::new(ssn_label())], policy);
+        assert_eq!(redactor.redact("SSN: on file ABCD"), "SSN: ** **** ****");
+
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::Replace)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(ssn_label())], policy);
+        assert_eq!(redactor.redact("SSN: on file ABCD"), "SSN: [REDACTED_SSN]");
+    }
+
+    #[test]
+    fn test_value_is_rest_of_line_trimmed() {
+        let detector = FieldLabelDetector::new(PiiType::Other("NAME"), &["name"]);
+        let input = "  NAME :\t John Smith  \r\nnext line";
+        let detections = detector.detect(input);
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].original, "John Smith");
+        assert_eq!(&input[detections[0].start..detections[0].end], "John Smith");
+    }
+
+    #[test]
+    fn test_label_must_be_whole_word_with_value() {
+        let detector = ssn_label();
+        assert!(detector.detect("XSSN: 123").is_empty());
+        assert!(detector.detect("SSN:\nnext: 1").is_empty());
+        assert!(detector.detect("SSN 123-45-6789").is_empty());
+        assert_eq!(
+            detector.detect("Social Security Number: 987")[0].original,
+            "987"
+        );
+        assert!(FieldLabelDetector::new(PiiType::Ssn, &[" "])
+            .detect("SSN: 1")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_multiple_labels_in_document() {
+        let detector = ssn_label();
+        let detections = detector.detect("ssn: 1\nSSN: 2");
+        let values: Vec<&str> = detections.iter().map(|d| d.original.as_str()).collect();
+        assert_eq!(values, vec!["1", "2"]);
+    }
+}
@@ -0,0 +1,169 @@
+//! MacAddressDetector - Detects hardware (MAC-48) addresses
+//!
+//! Device MAC addresses are online identifiers under GDPR Recital 30: they
+//! persist across networks and single out a device, and with it its owner.
+//!
+//! Supported formats (hex digits in either case):
+//! - `00:1A:2B:3C:4D:5E` (colon-separated)
+//! - `00-1A-2B-3C-4D-5E` (hyphen-separated)
+//!
+//! Exactly six two-digit octets with one separator style are required, so
+//! time-like `00:1A:2B`, longer colon runs such as IPv6 addresses, and mixed
+//! `00:1A-2B:...` forms are not matched. In `Mask` mode hex digits are
+//! masked and separators kept: `00:1A:2B:3C:4D:5E` → `██:██:██:██:██:██`.
+
+use crate::{
+    detector::{Confidence, Detection, PiiDetector},
+    types::PiiType,
+};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// MacAddressDetector - detects colon- and hyphen-separated MAC addresses
+pub struct MacAddressDetector {
+    pattern: &'static Regex,
+}
+
+impl Default for MacAddressDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MacAddressDetector {
+    /// Create a new MacAddressDetector
+    pub fn new() -> Self {
+        Self {
+            pattern: Self::get_pattern(),
+        }
+    }
+
+    fn get_pattern() -> &'static Regex {
+        static PATTERN: OnceLock<Regex> = OnceLock::new();
+        PATTERN.get_or_init(|| {
+            Regex::new(r"\b(?:[0-9A-Fa-f]{2}(?::[0-9A-Fa-f]{2}){5}|[0-9A-Fa-f]{2}(?:-[0-9A-Fa-f]{2}){5})\b")
+                .expect("MAC address pattern is valid")
+        })
+    }
+
+    /// Whether the match continues into a longer run of hex groups, like
+    /// six groups cut out of an IPv6 address or a seven-octet EUI string
+    fn is_embedded(text: &str, start: usize, end: usize) -> bool {
+        let bytes = text.as_bytes();
+        let before = start >= 2
+            && matches!(bytes[start - 1], b':' | b'-')
+            && bytes[start - 2].is_ascii_hexdigit();
+        let after = end + 1 < bytes.len()
+            && matches!(bytes[end], b':' | b'-')
+            && bytes[end + 1].is_ascii_hexdigit();
+        before || after
+    }
+}
+
+impl PiiDetector for MacAddressDetector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::MacAddress
+    }
+
+    fn confidence(&self) -> Confidence {
+        // The six-octet shape is distinctive, but carries no checksum
+        Confidence::Medium
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        self.detect_with_validation(text, true)
+    }
+
+    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
+        self.pattern
+            .find_iter(text)
+            .filter(|m| !Self::is_embedded(text, m.start(), m.end()))
+            .filter(|m| !validate || self.validate(m.as_str()))
+            .map(|m| Detection {
+                pii_type: PiiType::MacAddress,
+                confidence: self.confidence(),
+                start: m.start(),
+                end: m.end(),
+                original: m.as_str().to_string(),
+                metadata: None,
+            })
+            .collect()
+    }
+
+    /// Six hex octets joined by a single separator style
+    fn validate(&self, candidate: &str) -> bool {
+        let Some(separator) = candidate.chars().find(|c| matches!(c, ':' | '-')) else {
+            return false;
+        };
+        let octets: Vec<&str> = candidate.split(separator).collect();
+        octets.len() == 6
+            && octets
+                .iter()
+                .all(|o| o.len() == 2 && o.bytes().all(|b| b.is_ascii_hexdigit()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{policy::RedactionPolicy, redactor::Redactor};
+
+    fn found(text: &str) -> Vec<&str> {
+        MacAddressDetector::new()
+            .detect(text)
+            .iter()
+            .map(|d| &text[d.start..d.end])
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_colon_separated() {
+        let detections = MacAddressDetector::new().detect("device 00:1A:2B:3C:4D:5E joined");
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].pii_type, PiiType::MacAddress);
+        assert_eq!(detections[0].original, "00:1A:2B:3C:4D:5E");
+        assert_eq!(detections[0].start, 7);
+    }
+
+    #[test]
+    fn test_detects_hyphen_separated() {
+        assert_eq!(
+            found("nic 00-1a-2b-3c-4d-5e, then ab-CD-ef-01-23-45."),
+            vec!["00-1a-2b-3c-4d-5e", "ab-CD-ef-01-23-45"]
+        );
+    }
+
+    #[test]
+    fn test_rejects_short_long_and_mixed_candidates() {
+        assert!(found("at 00:1A:2B").is_empty());
+        assert!(found("00:1A:2B:3C:4D").is_empty());
+        assert!(found("00:1A:2B:3C:4D:5E:6F").is_empty());
+        assert!(found("fe80:0:0:0:00:1a:2b:3c:4d:5e").is_empty());
+        assert!(found("00:1A-2B:3C-4D:5E").is_empty());
+        assert!(found("00:1G:2B:3C:4D:5E").is_empty());
+
+        let detector = MacAddressDetector::new();
+        assert!(detector.validate("00:1A:2B:3C:4D:5E"));
+        assert!(!detector.validate("00:1A:2B"));
+    }
+
+    #[test]
+    fn test_masks_hex_and_keeps_separators() {
+        let redactor = Redactor::new(
+            vec![Box::new(MacAddressDetector::new())],
+            RedactionPolicy::gdpr(),
+        );
+        assert_eq!(
+            redactor.redact("mac 00:1A:2B:3C:4D:5E / 00-1A-2B-3C-4D-5E"),
+            "mac ██:██:██:██:██:██ / ██-██-██-██-██-██"
+        );
+        let default = Redactor::new(
+            vec![Box::new(MacAddressDetector::new())],
+            RedactionPolicy::default(),
+        );
+        assert_eq!(
+            default.redact("mac 00:1A:2B:3C:4D:5E"),
+            "mac 00:1A:2B:3C:4D:5E"
+        );
+    }
+}
@@ -0,0 +1,201 @@
+//! MedicareDetector - Detects Medicare Beneficiary Identifiers (MBI)
+//!
+//! The MBI replaced the SSN-based HICN on Medicare cards. It is 11
+//! characters, optionally printed with dashes as `1EG4-TE5-MK73`, with a
+//! fixed character class per position:
+//!
+//! | Pos | 1   | 2 | 3  | 4 | 5 | 6  | 7 | 8 | 9 | 10 | 11 |
+//! |-----|-----|---|----|---|---|----|---|---|---|----|----|
+//! |     | 1-9 | A | AN | N | A | AN | N | A | A | N  | N  |
+//!
+//! `N` is a digit 0-9, `A` is an uppercase letter excluding S, L, O, I, B
+//! and Z, and `AN` is either. The regex only checks the digit/letter shape;
+//! `validate` enforces the full positional rules including the excluded
+//! letters.
+
+use crate::{
+    detector::{Confidence, Detection, PiiDetector},
+    types::PiiType,
+};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Position classes for the 11 MBI characters
+#[derive(Clone, Copy)]
+enum MbiClass {
+    NonZeroDigit,
+    Digit,
+    Alpha,
+    AlphaNumeric,
+}
+
+const MBI_LAYOUT: [MbiClass; 11] = [
+    MbiClass::NonZeroDigit,
+    MbiClass::Alpha,
+    MbiClass::AlphaNumeric,
+    MbiClass::Digit,
+    MbiClass::Alpha,
+    MbiClass::AlphaNumeric,
+    MbiClass::Digit,
+    MbiClass::Alpha,
+    MbiClass::Alpha,
+    MbiClass::Digit,
+    MbiClass::Digit,
+];
+
+/// Letters never used in an MBI (easily confused with digits)
+fn is_mbi_letter(c: u8) -> bool {
+    c.is_ascii_uppercase() && !matches!(c, b'S' | b'L' | b'O' | b'I' | b'B' | b'Z')
+}
+
+/// MedicareDetector - detects and validates Medicare Beneficiary Identifiers
+pub struct MedicareDetector {
+    pattern: &'static Regex,
+}
+
+impl Default for MedicareDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MedicareDetector {
+    /// Create a new MedicareDetector
+    pub fn new() -> Self {
+        Self {
+            pattern: Self::get_pattern(),
+        }
+    }
+
+    fn get_pattern() -> &'static Regex {
+        static PATTERN: OnceLock<Regex> = OnceLock::new();
+        PATTERN.get_or_init(|| {
+            Regex::new(r"\b[1-9][A-Z][0-9A-Z][0-9]-?[A-Z][0-9A-Z][0-9]-?[A-Z]{2}[0-9]{2}\b")
+                .expect("MBI pattern is valid")
+        })
+    }
+}
+
+impl PiiDetector for MedicareDetector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::MedicareBeneficiaryId
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        self.detect_with_validation(text, true)
+    }
+
+    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
+        self.pattern
+            .find_iter(text)
+            .filter(|m| {
+                // Dashes are all-or-nothing: 1EG4-TE5-MK73 or 1EG4TE5MK73
+                let dashes = m.as_str().matches('-').count();
+                dashes == 0 || dashes == 2
+            })
+            .filter(|m| !validate || self.validate(m.as_str()))
+            .map(|m| Detection {
+                pii_type: PiiType::MedicareBeneficiaryId,
+                confidence: self.confidence(),
+                start: m.start(),
+                end: m.end(),
+                original: m.as_str().to_string(),
+                metadata: None,
+            })
+            .collect()
+    }
+
+    fn validate(&self, candidate: &str) -> bool {
+        let chars: Vec<u8> = candidate.bytes().filter(|&b| b != b'-').collect();
+        if chars.len() != MBI_LAYOUT.len() {
+            return false;
+        }
+
+        chars.iter().zip(MBI_LAYOUT).all(|(&c, class)| match class {
+            MbiClass::NonZeroDigit => (b'1'..=b'9').contains(&c),
+            MbiClass::Digit => c.is_ascii_digit(),
+            MbiClass::Alpha => is_mbi_letter(c),
+            MbiClass::AlphaNumeric => c.is_ascii_digit() || is_mbi_letter(c),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_valid_mbi() {
+        let detector = MedicareDetector::new();
+        let detections = detector.detect("Medicare ID: 1EG4TE5MK73 on file");
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].pii_type, PiiType::MedicareBeneficiaryId);
+        assert_eq!(detections[0].original, "1EG4TE5MK73");
+        assert_eq!(detections[0].start, 13);
+    }
+
+    #[test]
+    fn test_detects_dashed_mbi() {
+        let detector = MedicareDetector::new();
+        let detections = detector.detect("MBI 1EG4-TE5-MK73");
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].original, "1EG4-TE5-MK73");
+    }
+
+    #[test]
+    fn test_rejects_excluded_letter() {
+        let detector = MedicareDetector::new();
+        // 'S' in position 2 is never issued
+        assert!(detector.detect("MBI 1SG4TE5MK73").is_empty());
+        // 'O' in position 9
+        assert!(detector.detect("MBI 1EG4TE5MO73").is_empty());
+        assert!(!detector.validate("1EG4TE5MZ73"));
+    }
+
+    #[test]
+    fn test_rejects_wrong_positions() {
+        let detector = MedicareDetector::new();
+        // Leading zero
+        assert!(detector.detect("0EG4TE5MK73").is_empty());
+        // Mixed dash usage
+        assert!(detector.detect("1EG4-TE5MK73").is_empty());
+        assert!(!detector.validate("1EG4TE5MK7"));
+    }
+
+    #[test]
+    fn test_validation_bypass() {
+        let detector = MedicareDetector::new();
+        assert!(detector
+            .detect_with_validation("1SG4TE5MK73", true)
+            .is_empty());
+        assert_eq!(
+            detector.detect_with_validation("1SG4TE5MK73", false).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_integrates_with_redactor() {
+        use crate::{policy::RedactionPolicy, redactor::Redactor};
+
+        let redactor = Redactor::new(
+            vec![Box::new(MedicareDetector::new())],
+            RedactionPolicy::hipaa(),
+        );
+        assert_eq!(redactor.redact("MBI: 1EG4-TE5-MK73"), "MBI: 1███-███-████");
+    }
+
+    #[test]
+    fn test_zeroizes_on_drop() {
+        let detector = MedicareDetector::new();
+        let detections = detector.detect("1EG4TE5MK73");
+        assert_eq!(detections.len(), 1);
+        std::mem::drop(detections);
+    }
+}
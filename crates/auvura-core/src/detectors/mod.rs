@@ -1,11 +1,260 @@
 pub mod address;
+pub mod api_token;
+pub mod australian_business;
+pub mod credential;
 pub mod credit_card;
 pub mod custom_regex;
+pub mod date_of_birth;
+pub mod drivers_license;
 pub mod email;
+pub mod employee_id;
+pub mod gps;
 pub mod iban;
 pub mod ip;
+pub mod label;
+pub mod mac_address;
+pub mod medicare;
 pub mod national_id;
 pub mod ner;
+pub mod partial_mask;
 pub mod passport;
 pub mod phone_number;
+pub mod routing_number;
+pub mod secret;
+pub mod social_handle;
 pub mod ssn;
+pub mod timestamp;
+pub mod us_passport;
+pub mod vat;
+
+use crate::{detector::PiiDetector, types::PiiType};
+use std::collections::HashMap;
+
+/// Unicode bidirectional formatting characters: ALM, LRM, RLM, the
+/// embeddings and overrides (U+202A..U+202E), and the isolates
+/// (U+2066..U+2069).
+///
+/// They are invisible but structural in right-to-left text, so they are
+/// never part of a detection: `MultiDetector` trims them from span edges.
+pub fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+/// How far before a match (in bytes) context-gating detectors look for
+/// their keywords
+pub const CONTEXT_WINDOW: usize = 32;
+
+/// Whether one of `keywords` appears shortly before `start` in `text`.
+///
+/// Context-gating detectors use this to accept ambiguous matches (bare
+/// digit runs, short prefixed codes) only near a keyword. Matching is
+/// case-insensitive and keywords may span several words, so lists can mix
+/// languages: `["ABN", "date of birth", "Geburtsdatum", "date de
+/// naissance"]`. A keyword must not be part of a longer word.
+pub fn has_context<S: AsRef<str>>(text: &str, start: usize, keywords: &[S]) -> bool {
+    has_context_within(text, start, keywords, CONTEXT_WINDOW)
+}
+
+/// As [`has_context`], looking back `lookback` bytes instead of
+/// [`CONTEXT_WINDOW`]
+pub fn has_context_within<S: AsRef<str>>(
+    text: &str,
+    start: usize,
+    keywords: &[S],
+    lookback: usize,
+) -> bool {
+    let mut from = start.saturating_sub(lookback);
+    while !text.is_char_boundary(from) {
+        from -= 1;
+    }
+    let window = text[from..start].to_lowercase();
+    keywords.iter().any(|keyword| {
+        let keyword = keyword.as_ref().to_lowercase();
+        !keyword.is_empty()
+            && window.match_indices(&keyword).any(|(i, _)| {
+                let before = window[..i].chars().next_back();
+                let after = window[i + keyword.len()..].chars().next();
+                !before.is_some_and(char::is_alphanumeric)
+                    && !after.is_some_and(char::is_alphanumeric)
+            })
+    })
+}
+
+/// The built-in detector for a PII type, with default settings.
+///
+/// Returns `None` for `Other(..)` types, which only have custom detectors.
+pub fn default_detector(pii_type: PiiType) -> Option<Box<dyn PiiDetector>> {
+    let detector: Box<dyn PiiDetector> = match pii_type {
+        PiiType::Email => Box::new(email::EmailDetector::new()),
+        PiiType::PhoneNumber => Box::new(phone_number::PhoneNumberDetector::new()),
+        PiiType::Ssn => Box::new(ssn::SSNDetector::new()),
+        PiiType::CreditCard => Box::new(credit_card::CreditCardDetector::new()),
+        PiiType::IpAddressV4 => Box::new(ip::Ipv4Detector::new()),
+        PiiType::IpAddressV6 => Box::new(ip::Ipv6Detector::new()),
+        PiiType::Iban => Box::new(iban::IbanDetector::new()),
+        PiiType::PassportNumber => Box::new(passport::PassportDetector::new()),
+        PiiType::NationalId => Box::new(national_id::NationalIdDetector::new()),
+        PiiType::PhysicalAddress => Box::new(address::AddressDetector::new()),
+        PiiType::Timestamp => Box::new(timestamp::TimestampDetector::new()),
+        PiiType::MedicareBeneficiaryId => Box::new(medicare::MedicareDetector::new()),
+        PiiType::SocialHandle => Box::new(social_handle::SocialHandleDetector::new()),
+        PiiType::AustralianAbn => Box::new(australian_business::AbnDetector::new()),
+        PiiType::AustralianAcn => Box::new(australian_business::AcnDetector::new()),
+        PiiType::Secret => Box::new(secret::SecretKeyDetector::new()),
+        PiiType::VatNumber => Box::new(vat::VatDetector::new()),
+        PiiType::DateOfBirth => Box::new(date_of_birth::DateOfBirthDetector::new()),
+        PiiType::MacAddress => Box::new(mac_address::MacAddressDetector::new()),
+        PiiType::UsPassport => Box::new(us_passport::UsPassportDetector::new()),
+        PiiType::DriversLicense => Box::new(drivers_license::DriversLicenseDetector::new()),
+        PiiType::BankAccountRouting => Box::new(routing_number::RoutingNumberDetector::new()),
+        PiiType::PartiallyMasked => Box::new(partial_mask::PartialMaskDetector::new()),
+        PiiType::GpsCoordinate => Box::new(gps::GpsCoordinateDetector::new()),
+        PiiType::Other(_) => return None,
+    };
+    Some(detector)
+}
+
+/// Detectors keyed by the PII type they report, one per type.
+///
+/// Pass to [`Redactor::from_registry`](crate::redactor::Redactor::from_registry),
+/// which runs only the detectors of enabled types and fails if an enabled
+/// type has none.
+#[derive(Default)]
+pub struct DetectorRegistry {
+    detectors: HashMap<PiiType, Box<dyn PiiDetector>>,
+}
+
+impl DetectorRegistry {
+    /// An empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A registry holding the built-in detector of each of `types`
+    /// (`Other(..)` types have none and are skipped)
+    pub fn with_defaults(types: impl IntoIterator<Item = PiiType>) -> Self {
+        let mut registry = Self::new();
+        for detector in types.into_iter().filter_map(default_detector) {
+            registry.insert(detector);
+        }
+        registry
+    }
+
+    /// Register `detector` under its `pii_type()`, replacing any detector
+    /// already registered for that type
+    pub fn register(mut self, detector: impl PiiDetector + 'static) -> Self {
+        self.insert(Box::new(detector));
+        self
+    }
+
+    /// As [`register`](Self::register), for an already boxed detector;
+    /// returns the detector it replaced
+    pub fn insert(&mut self, detector: Box<dyn PiiDetector>) -> Option<Box<dyn PiiDetector>> {
+        self.detectors.insert(detector.pii_type(), detector)
+    }
+
+    /// The detector registered for `pii_type`
+    pub fn get(&self, pii_type: PiiType) -> Option<&dyn PiiDetector> {
+        self.detectors.get(&pii_type).map(Box::as_ref)
+    }
+
+    /// Whether a detector is registered for `pii_type`
+    pub fn contains(&self, pii_type: PiiType) -> bool {
+        self.detectors.contains_key(&pii_type)
+    }
+
+    /// Number of registered types
+    pub fn len(&self) -> usize {
+        self.detectors.len()
+    }
+
+    /// Whether no detector is registered
+    pub fn is_empty(&self) -> bool {
+        self.detectors.is_empty()
+    }
+
+    /// Take the detector registered for `pii_type` out of the registry
+    pub fn remove(&mut self, pii_type: PiiType) -> Option<Box<dyn PiiDetector>> {
+        self.detectors.remove(&pii_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PiiType;
+
+    #[test]
+    fn test_has_context_multilingual_keywords() {
+        let keywords = ["DOB", "Geburtsdatum", "date de naissance"];
+        let text = "Geburtsdatum: 01.02.1990";
+        assert!(has_context(text, 14, &keywords));
+        let text = "Date de Naissance : 01/02/1990";
+        assert!(has_context(text, 20, &keywords));
+        // Whole words only, and only shortly before the match
+        assert!(!has_context("DOBSON 01.02.1990", 7, &keywords));
+        let far = format!("DOB {} 01.02.1990", "x".repeat(CONTEXT_WINDOW));
+        assert!(!has_context(&far, far.len() - 10, &keywords));
+        assert!(!has_context("Geburtsdatum: 01.02.1990", 14, &[] as &[&str]));
+    }
+
+    #[test]
+    fn test_default_detector_matches_type() {
+        for pii_type in [
+            PiiType::Email,
+            PiiType::PhoneNumber,
+            PiiType::Ssn,
+            PiiType::CreditCard,
+            PiiType::IpAddressV4,
+            PiiType::IpAddressV6,
+            PiiType::Iban,
+            PiiType::PassportNumber,
+            PiiType::NationalId,
+            PiiType::PhysicalAddress,
+            PiiType::Timestamp,
+            PiiType::MedicareBeneficiaryId,
+            PiiType::SocialHandle,
+            PiiType::AustralianAbn,
+            PiiType::AustralianAcn,
+            PiiType::Secret,
+            PiiType::PartiallyMasked,
+            PiiType::VatNumber,
+            PiiType::GpsCoordinate,
+            PiiType::DateOfBirth,
+            PiiType::MacAddress,
+            PiiType::UsPassport,
+            PiiType::DriversLicense,
+            PiiType::BankAccountRouting,
+        ] {
+            let detector = default_detector(pii_type).expect("built-in type has a detector");
+            assert_eq!(detector.pii_type(), pii_type);
+        }
+        assert!(default_detector(PiiType::Other("custom")).is_none());
+    }
+
+    #[test]
+    fn test_registry_keeps_one_detector_per_type() {
+        let mut registry = DetectorRegistry::with_defaults([
+            PiiType::Email,
+            PiiType::Ssn,
+            PiiType::Other("PERSON"),
+        ]);
+        assert_eq!(registry.len(), 2);
+        assert!(registry.contains(PiiType::Email));
+        assert!(!registry.contains(PiiType::Other("PERSON")));
+
+        let replaced = registry.insert(Box::new(email::EmailDetector::new()));
+        assert!(replaced.is_some());
+        assert_eq!(registry.len(), 2);
+        assert_eq!(
+            registry.get(PiiType::Ssn).map(|d| d.pii_type()),
+            Some(PiiType::Ssn)
+        );
+        assert!(registry.remove(PiiType::Ssn).is_some());
+        assert!(registry.get(PiiType::Ssn).is_none());
+        assert!(DetectorRegistry::new().is_empty());
+    }
+}
@@ -120,6 +120,7 @@ impl PiiDetector for NationalIdDetector {
                     start,
                     end,
                     original: candidate.to_string(),
+                    metadata: None,
                 })
             })
             .collect()
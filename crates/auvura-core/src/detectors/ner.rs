@@ -87,6 +87,7 @@ impl PiiDetector for SimpleNameDetector {
                         start: abs_start,
                         end: abs_start + trimmed.len(),
                         original: trimmed.to_string(),
+                        metadata: None,
                     });
                 }
             }
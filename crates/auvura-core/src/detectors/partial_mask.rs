@@ -0,0 +1,138 @@
+//! PartialMaskDetector - partially masked values that still leak digits
+//!
+//! Third-party redaction is often incomplete: `XXX-XX-6789` still reveals an
+//! SSN's last four digits and its structure, and `****-****-****-1111` a
+//! card's. This detector finds such values so they can be fully redacted
+//! when re-processing already-redacted data:
+//!
+//! - SSN-shaped: `XXX-XX-6789`
+//! - Card-shaped: `****-****-****-1111`, `**** **** **** 1111`,
+//!   `************1111`, and `4111-XXXX-XXXX-1111` (first and last four
+//!   visible)
+//! - Generic: four or more mask characters followed by four or more digits,
+//!   e.g. `****1234` or `XXXX-1234` for account numbers
+//!
+//! Mask characters are `X`, `x`, `*`, `#`, and `•`. Matches must not touch
+//! other letters or digits. Detection is opt-in: `PartiallyMasked` is not
+//! enabled in the default policy. In `Mask` mode the residual digits are
+//! masked along with the mask characters, so `XXX-XX-6789` → `███-██-████`.
+
+use crate::{
+    detector::{Detection, PiiDetector},
+    types::PiiType,
+};
+use regex::Regex;
+
+/// Characters third-party redaction commonly masks with
+pub(crate) fn is_mask_char(c: char) -> bool {
+    matches!(c, 'X' | 'x' | '*' | '#' | '•')
+}
+
+/// PartialMaskDetector - detects partially masked SSNs, cards, and accounts
+pub struct PartialMaskDetector {
+    pattern: Regex,
+}
+
+impl Default for PartialMaskDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartialMaskDetector {
+    pub fn new() -> Self {
+        const M: &str = "[Xx*#•]";
+        let card = format!(
+            r"{M}{{4}}-{M}{{4}}-{M}{{4}}-\d{{4}}|{M}{{4}} {M}{{4}} {M}{{4}} \d{{4}}|{M}{{12}}\d{{4}}|\d{{4}}[- ]{M}{{4}}[- ]{M}{{4}}[- ]\d{{4}}"
+        );
+        let ssn = format!(r"{M}{{3}}-{M}{{2}}-\d{{4}}");
+        let generic = format!(r"{M}{{4,}}[- ]?\d{{4,}}");
+        // Leftmost-first: the structured shapes win over the generic one
+        let pattern = format!("{card}|{ssn}|{generic}");
+        Self {
+            pattern: Regex::new(&pattern).expect("partial mask pattern is valid"),
+        }
+    }
+}
+
+impl PiiDetector for PartialMaskDetector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::PartiallyMasked
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        let touches_word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+        self.pattern
+            .find_iter(text)
+            .filter(|m| {
+                !touches_word(text[..m.start()].chars().next_back())
+                    && !touches_word(text[m.end()..].chars().next())
+            })
+            .map(|m| Detection {
+                pii_type: PiiType::PartiallyMasked,
+                confidence: self.confidence(),
+                start: m.start(),
+                end: m.end(),
+                original: m.as_str().to_string(),
+                metadata: None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{policy::RedactionPolicy, redactor::Redactor};
+
+    fn matches(text: &str) -> Vec<String> {
+        PartialMaskDetector::new()
+            .detect(text)
+            .iter()
+            .map(|d| d.original.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_masks_residual_ssn_digits() {
+        let input = "SSN on file: XXX-XX-6789.";
+        assert_eq!(matches(input), vec!["XXX-XX-6789"]);
+
+        let policy = RedactionPolicy::builder()
+            .enable(PiiType::PartiallyMasked)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(PartialMaskDetector::new())], policy);
+        assert_eq!(redactor.redact(input), "SSN on file: ███-██-████.");
+        // Off by default
+        let redactor = Redactor::new(
+            vec![Box::new(PartialMaskDetector::new())],
+            RedactionPolicy::default(),
+        );
+        assert_eq!(redactor.redact(input), input);
+    }
+
+    #[test]
+    fn test_card_and_account_shapes() {
+        assert_eq!(
+            matches("card ****-****-****-1111, alt **** **** **** 2222"),
+            vec!["****-****-****-1111", "**** **** **** 2222"]
+        );
+        assert_eq!(matches("************4242"), vec!["************4242"]);
+        assert_eq!(matches("4111-XXXX-XXXX-1111"), vec!["4111-XXXX-XXXX-1111"]);
+        assert_eq!(
+            matches("acct ••••5678 / #####-90123"),
+            vec!["••••5678", "#####-90123"]
+        );
+    }
+
+    #[test]
+    fn test_ignores_unmasked_and_embedded_text() {
+        assert!(matches("123-45-6789").is_empty());
+        assert!(matches("XXX-XX-XXXX").is_empty());
+        // Too little masking or digits
+        assert!(matches("XX-1234 and ****12").is_empty());
+        // Must not touch other word characters
+        assert!(matches("AXXX-XX-6789").is_empty());
+        assert!(matches("XXX-XX-67890").is_empty());
+    }
+}
@@ -133,6 +133,7 @@ impl PiiDetector for PassportDetector {
                     start,
                     end,
                     original: candidate.to_string(),
+                    metadata: None,
                 })
             })
             .collect()
@@ -4,6 +4,12 @@
 //! 1. Regex pre-filter finds candidate digit sequences
 //! 2. phonelib validates candidates as actual phone numbers
 //! 3. Boundary checks prevent false positives (timestamps/SKUs)
+//!
+//! `validate` also enforces digit counts: at most 15 for `+CC` (E.164)
+//! forms, and exactly 10 for North American numbers, i.e. `+1` numbers and
+//! local numbers grouped `(xxx) xxx-xxxx` or `xxx-xxx-xxxx`. A valid SSN
+//! like `123-45-6789` is left to the SSN detector; SSN-shaped numbers it
+//! rejects, e.g. ITINs in the 9xx area, still fall through to this one.
 
 use crate::{
     detector::{Detection, PiiDetector},
@@ -56,10 +62,48 @@ impl PhoneNumberDetector {
             // - 7-15 digits (covers most international formats)
             // - Optional separators: spaces, dashes, dots, parentheses
             // - Optional leading +
-            Regex::new(r"\+?[\d\s\-\.\(\)]{7,25}").expect("Phone candidate pattern is valid")
+            // Line breaks are excluded so a number never runs into the next line
+            Regex::new(r"\+?[\d\s\-\.\(\)&&[^\r\n]]{7,25}")
+                .expect("Phone candidate pattern is valid")
         })
     }
 
+    /// Whether the digit groups fit the number's numbering plan
+    fn has_valid_digit_count(candidate: &str) -> bool {
+        let groups: Vec<usize> = candidate
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|g| !g.is_empty())
+            .map(str::len)
+            .collect();
+        let digits: usize = groups.iter().sum();
+        if let Some(international) = candidate.strip_prefix('+') {
+            // NANP: country code 1 plus a 10-digit national number
+            return if international.starts_with('1') {
+                digits == 11
+            } else {
+                digits <= 15
+            };
+        }
+        // SSN shape, unless the SSN detector would reject it
+        if groups == [3, 2, 4] && candidate.len() == 11 && candidate.matches('-').count() == 2 {
+            let part = |range: core::ops::Range<usize>| candidate[range].parse().unwrap_or(0);
+            let (area, group, serial) = (part(0..3), part(4..6) as u8, part(7..11));
+            if crate::ascii::is_valid_ssn_parts(area, group, serial) {
+                return false;
+            }
+        }
+        // NANP punctuation: (xxx) xxx-… or xxx-xxx-…, optionally after a 1
+        let national = candidate
+            .strip_prefix('1')
+            .map(|rest| rest.trim_start_matches([' ', '-', '.']))
+            .filter(|rest| rest.len() < candidate.len() - 1)
+            .unwrap_or(candidate);
+        let bytes = national.as_bytes();
+        let nanp_grouped = (national.starts_with('(') && bytes.get(4) == Some(&b')'))
+            || (bytes.get(3) == Some(&b'-') && bytes.get(7) == Some(&b'-'));
+        !nanp_grouped || national.chars().filter(char::is_ascii_digit).count() == 10
+    }
+
     /// Validate candidate as actual phone number using phonelib
     fn is_valid_phone(&self, candidate: &str) -> bool {
         // Basic digit filtering
@@ -102,34 +146,30 @@ impl PiiDetector for PhoneNumberDetector {
             let start = m.start();
             let candidate = m.as_str();
 
-            // Trim only leading/trailing whitespace
-            let trimmed = candidate.trim_matches(|c: char| c.is_whitespace());
+            // Trim whitespace and separators that can't start or end a
+            // number, e.g. a sentence's full stop or an opening parenthesis
+            let lead =
+                candidate.trim_start_matches(|c: char| c.is_whitespace() || ").-".contains(c));
+            let trimmed = lead.trim_end_matches(|c: char| c.is_whitespace() || "(.-".contains(c));
             if trimmed.is_empty() {
                 continue;
             }
 
             // Compute new start/end positions after trimming
-            let offset = candidate.find(trimmed).unwrap();
+            let offset = candidate.len() - lead.len();
             let new_start = start + offset;
             let new_end = new_start + trimmed.len();
 
             // --- CRITICAL: Check boundaries in original text at trimmed positions ---
-            if new_start > 0 {
-                let prev = text[new_start - 1..new_start]
-                    .chars()
-                    .next()
-                    .unwrap_or('\0');
-                // Reject if previous character is alphanumeric (digit or letter)
-                if prev.is_ascii_alphanumeric() {
-                    continue;
-                }
+            // Byte checks: neighbors may be multi-byte (e.g. bidi controls),
+            // and no byte of a multi-byte char is ASCII
+            // Reject if previous character is alphanumeric (digit or letter)
+            if new_start > 0 && text.as_bytes()[new_start - 1].is_ascii_alphanumeric() {
+                continue;
             }
-            if new_end < text.len() {
-                let next = text[new_end..new_end + 1].chars().next().unwrap_or('\0');
-                // Reject if next character is alphanumeric (digit or letter)
-                if next.is_ascii_alphanumeric() {
-                    continue;
-                }
+            // Reject if next character is alphanumeric (digit or letter)
+            if new_end < text.len() && text.as_bytes()[new_end].is_ascii_alphanumeric() {
+                continue;
             }
 
             // Skip if trimmed candidate doesn't have enough digits
@@ -138,14 +178,14 @@ impl PiiDetector for PhoneNumberDetector {
                 continue;
             }
 
-            // Validate with phonelib
-            if self.is_valid_phone(trimmed) {
+            if self.validate(trimmed) {
                 detections.push(Detection {
                     pii_type: PiiType::PhoneNumber,
                     confidence: self.confidence(),
                     start: new_start,
                     end: new_end,
                     original: trimmed.to_string(),
+                    metadata: None,
                 });
             }
         }
@@ -153,6 +193,11 @@ impl PiiDetector for PhoneNumberDetector {
         detections
     }
 
+    /// Digit counts per numbering plan, then phonelib validation
+    fn validate(&self, candidate: &str) -> bool {
+        Self::has_valid_digit_count(candidate) && self.is_valid_phone(candidate)
+    }
+
     /// No reliable literal anchor — phone numbers vary widely in format.
     /// Falls back to full-text regex scan.
     fn anchor_patterns(&self) -> Vec<&'static str> {
@@ -184,6 +229,16 @@ mod tests {
         assert_eq!(detections[0].original, "+12025550123");
     }
 
+    #[test]
+    fn test_does_not_span_line_breaks() {
+        let detector = PhoneNumberDetector::new();
+        let text = "1,Bob,(202) 555-0123\r\n2,Al,202-555-0199\n3";
+        let detections = detector.detect(text);
+        assert_eq!(detections.len(), 2);
+        assert_eq!(detections[0].original, "(202) 555-0123");
+        assert_eq!(detections[1].original, "202-555-0199");
+    }
+
     #[test]
     fn test_detects_us_parentheses() {
         let detector = PhoneNumberDetector::new();
@@ -293,6 +348,40 @@ mod tests {
         assert_eq!(detections[0].original, "202-555-0123");
     }
 
+    #[test]
+    fn test_nanp_and_e164_offsets_and_digit_counts() {
+        let detector = PhoneNumberDetector::new();
+        let text = "Call +1 202-555-0143 or (202) 555-0143.";
+        let spans: Vec<(usize, usize)> = detector
+            .detect(text)
+            .iter()
+            .map(|d| (d.start, d.end))
+            .collect();
+        // Offsets include the leading `+` and the parentheses
+        assert_eq!(spans, vec![(5, 20), (24, 38)]);
+        assert_eq!(&text[5..20], "+1 202-555-0143");
+
+        assert!(detector.validate("+44 20 7946 0958"));
+        assert!(detector.validate("1-202-555-0143"));
+        // NANP numbers have exactly 10 digits, E.164 at most 15
+        assert!(!detector.validate("+1 202-555-014"));
+        assert!(!detector.validate("+1 202-555-01435"));
+        assert!(!detector.validate("202-555-01434"));
+        assert!(!detector.validate("(202) 555-01434"));
+        assert!(!detector.validate("+44 20 7946 0958 12345"));
+    }
+
+    #[test]
+    fn test_rejects_ssn_shape_and_four_digit_numbers() {
+        let detector = PhoneNumberDetector::new();
+        assert!(detector.detect("SSN 123-45-6789").is_empty());
+        assert!(!detector.validate("123-45-6789"));
+        // SSN-shaped values the SSN detector rejects still fall through
+        assert_eq!(detector.detect("ITIN 987-65-4321").len(), 1);
+        assert!(detector.detect("PIN 1234").is_empty());
+        assert!(!detector.validate("1234"));
+    }
+
     #[test]
     fn test_rejects_short_sequence() {
         let detector = PhoneNumberDetector::new();
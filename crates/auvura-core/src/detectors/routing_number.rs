@@ -0,0 +1,157 @@
+//! RoutingNumberDetector - Detects US bank (ABA) routing numbers
+//!
+//! A routing transit number is nine digits whose last digit is a check
+//! digit: `3·(d1+d4+d7) + 7·(d2+d5+d8) + (d3+d6+d9)` must be divisible by
+//! 10. A bare nine-digit run is also an SSN or an order number, so
+//! detection is gated on context: a keyword such as `routing` or `ABA`
+//! (case-insensitive) must appear shortly before the number.
+//! `with_keywords` replaces the keyword list.
+//!
+//! In `Mask` mode all but the last two digits are masked:
+//! `021000021` → `███████21`.
+
+use super::has_context;
+use crate::{
+    detector::{Confidence, Detection, PiiDetector},
+    types::PiiType,
+};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Default context keywords for routing numbers
+pub const ROUTING_KEYWORDS: &[&str] = &["routing", "ABA", "RTN", "routing transit number"];
+
+const ABA_WEIGHTS: [u32; 9] = [3, 7, 1, 3, 7, 1, 3, 7, 1];
+
+/// RoutingNumberDetector - detects and validates keyword-gated ABA routing numbers
+pub struct RoutingNumberDetector {
+    pattern: &'static Regex,
+    keywords: Vec<String>,
+}
+
+impl Default for RoutingNumberDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RoutingNumberDetector {
+    /// Create a new RoutingNumberDetector
+    pub fn new() -> Self {
+        static PATTERN: OnceLock<Regex> = OnceLock::new();
+        Self {
+            pattern: PATTERN
+                .get_or_init(|| Regex::new(r"\b\d{9}\b").expect("Routing number pattern is valid")),
+            keywords: ROUTING_KEYWORDS.iter().map(|k| k.to_string()).collect(),
+        }
+    }
+
+    /// Gate on these keywords instead of [`ROUTING_KEYWORDS`]
+    pub fn with_keywords(mut self, keywords: &[&str]) -> Self {
+        self.keywords = keywords.iter().map(|k| k.to_string()).collect();
+        self
+    }
+}
+
+impl PiiDetector for RoutingNumberDetector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::BankAccountRouting
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        self.detect_with_validation(text, true)
+    }
+
+    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
+        self.pattern
+            .find_iter(text)
+            .filter(|m| has_context(text, m.start(), &self.keywords))
+            .filter(|m| !validate || self.validate(m.as_str()))
+            .map(|m| Detection {
+                pii_type: PiiType::BankAccountRouting,
+                confidence: self.confidence(),
+                start: m.start(),
+                end: m.end(),
+                original: m.as_str().to_string(),
+                metadata: None,
+            })
+            .collect()
+    }
+
+    /// Nine digits passing the ABA checksum
+    fn validate(&self, candidate: &str) -> bool {
+        let digits: Vec<u32> = candidate.chars().filter_map(|c| c.to_digit(10)).collect();
+        if digits.len() != ABA_WEIGHTS.len() || candidate.len() != digits.len() {
+            return false;
+        }
+        let sum: u32 = digits.iter().zip(ABA_WEIGHTS).map(|(d, w)| d * w).sum();
+        sum.is_multiple_of(10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{policy::RedactionPolicy, redactor::Redactor};
+
+    fn found(text: &str) -> Vec<&str> {
+        RoutingNumberDetector::new()
+            .detect(text)
+            .iter()
+            .map(|d| &text[d.start..d.end])
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_valid_routing_number() {
+        let detections = RoutingNumberDetector::new().detect("Routing: 021000021");
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].pii_type, PiiType::BankAccountRouting);
+        assert_eq!(detections[0].original, "021000021");
+        assert_eq!(detections[0].start, 9);
+        assert_eq!(found("ABA 011000015, acct 12345"), vec!["011000015"]);
+    }
+
+    #[test]
+    fn test_rejects_invalid_checksum() {
+        assert!(found("Routing: 021000022").is_empty());
+        let detector = RoutingNumberDetector::new();
+        assert!(!detector.validate("021000022"));
+        assert!(detector.validate("021000021"));
+        assert!(!detector.validate("02100002"));
+        assert_eq!(
+            detector
+                .detect_with_validation("Routing: 021000022", false)
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_requires_keyword() {
+        assert!(found("Order 021000021 shipped").is_empty());
+        assert!(found("Routing: 0210000210").is_empty());
+        let detector = RoutingNumberDetector::new().with_keywords(&["Bankleitzahl"]);
+        assert!(detector.detect("Routing: 021000021").is_empty());
+    }
+
+    #[test]
+    fn test_pci_dss_masks_all_but_last_two_digits() {
+        let redactor = Redactor::new(
+            vec![
+                Box::new(RoutingNumberDetector::new()),
+                Box::new(crate::detectors::ssn::SSNDetector::new()),
+            ],
+            RedactionPolicy::pci_dss(),
+        );
+        // 322271627 is also SSN-shaped; the keyword-gated match wins
+        assert_eq!(
+            redactor.redact("ABA routing 322271627"),
+            "ABA routing ███████27"
+        );
+    }
+}
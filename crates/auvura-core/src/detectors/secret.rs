@@ -0,0 +1,223 @@
+//! SecretKeyDetector - name-driven detection of credential values
+//!
+//! Secrets rarely have a recognizable shape, but the key they are assigned
+//! to usually gives them away. This detector looks for sensitive key names
+//! in `key=value` (query strings, form bodies, env files) and `key: value`
+//! (HTTP headers, YAML, JSON) contexts and reports the *value*:
+//!
+//! - `GET /v1/items?page=2&api_key=s3cr3t` → `s3cr3t`
+//! - `X-Api-Key: abc123` → `abc123`
+//! - `{"token": "abc123"}` → `abc123`
+//! - `Authorization: Bearer abc123` → `abc123` (the auth scheme is kept)
+//!
+//! Key names are matched ASCII case-insensitively as whole tokens, so
+//! `token` matches `&token=` but not `tokenizer=`. The set of names is
+//! configurable. Detection is opt-in: `Secret` is not enabled in the
+//! default policy.
+
+use crate::{
+    detector::{Confidence, Detection, PiiDetector},
+    types::PiiType,
+};
+use regex::Regex;
+
+/// Key names treated as sensitive by [`SecretKeyDetector::new`]
+pub const DEFAULT_SECRET_KEYS: &[&str] = &[
+    "api_key",
+    "api-key",
+    "apikey",
+    "x-api-key",
+    "access_token",
+    "refresh_token",
+    "auth_token",
+    "token",
+    "secret",
+    "client_secret",
+    "password",
+    "passwd",
+    "authorization",
+];
+
+/// Schemes that prefix the credential in an `Authorization` header value
+const AUTH_SCHEMES: [&str; 3] = ["Bearer ", "Basic ", "Token "];
+
+/// SecretKeyDetector - detects values assigned to sensitive key names
+pub struct SecretKeyDetector {
+    pattern: Regex,
+}
+
+impl Default for SecretKeyDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SecretKeyDetector {
+    /// Create a detector for [`DEFAULT_SECRET_KEYS`]
+    pub fn new() -> Self {
+        Self::with_keys(DEFAULT_SECRET_KEYS)
+    }
+
+    /// Create a detector for a custom set of key names
+    pub fn with_keys(keys: &[&str]) -> Self {
+        let mut keys: Vec<&str> = keys.iter().copied().filter(|k| !k.is_empty()).collect();
+        // Longest first, so `x-api-key` wins over `api-key`
+        keys.sort_unstable_by_key(|k| std::cmp::Reverse(k.len()));
+        keys.dedup();
+        let alternation = if keys.is_empty() {
+            // Matches nothing: a key name cannot be empty
+            r"[^\s\S]".to_string()
+        } else {
+            keys.iter()
+                .map(|k| regex::escape(k))
+                .collect::<Vec<_>>()
+                .join("|")
+        };
+        // Key as a whole token, optionally quoted, then `=` or `:`, then an
+        // optional opening quote. The value is scanned by hand.
+        let pattern =
+            format!(r#"(?i)(?:^|[^A-Za-z0-9_\-])["']?(?:{alternation})["']?[ \t]*[:=][ \t]*["']?"#);
+        Self {
+            pattern: Regex::new(&pattern).expect("secret key pattern is valid"),
+        }
+    }
+}
+
+/// Length of the value starting at `text[start..]`
+fn value_len(text: &str) -> usize {
+    text.find(|c: char| {
+        c.is_whitespace()
+            || super::is_bidi_control(c)
+            || matches!(c, '&' | ';' | ',' | '"' | '\'' | '#' | '<' | '>' | '}')
+    })
+    .unwrap_or(text.len())
+}
+
+impl PiiDetector for SecretKeyDetector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::Secret
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        let mut detections = Vec::new();
+        let mut pos = 0;
+        while let Some(m) = self.pattern.find_at(text, pos) {
+            let mut start = m.end();
+            if let Some(scheme) = AUTH_SCHEMES.iter().find(|s| {
+                text[start..]
+                    .get(..s.len())
+                    .is_some_and(|p| p.eq_ignore_ascii_case(s))
+            }) {
+                start += scheme.len();
+            }
+            let end = start + value_len(&text[start..]);
+            if end > start {
+                detections.push(Detection {
+                    pii_type: PiiType::Secret,
+                    confidence: self.confidence(),
+                    start,
+                    end,
+                    original: text[start..end].to_string(),
+                    metadata: None,
+                });
+            }
+            // Continue after the value; the separator before the next key is
+            // still available to the pattern's leading boundary
+            pos = end.max(m.end());
+        }
+        detections
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{policy::RedactionPolicy, redactor::Redactor};
+
+    fn values(detector: &SecretKeyDetector, text: &str) -> Vec<String> {
+        detector
+            .detect(text)
+            .iter()
+            .map(|d| d.original.clone())
+            .collect()
+    }
+
+    fn secret_redactor() -> Redactor {
+        let policy = RedactionPolicy::builder().enable(PiiType::Secret).build();
+        Redactor::new(vec![Box::new(SecretKeyDetector::new())], policy)
+    }
+
+    #[test]
+    fn test_redacts_api_key_in_query_string() {
+        let input = "GET /v1/items?page=2&api_key=s3cr3t-Value&sort=asc";
+        let detections = SecretKeyDetector::new().detect(input);
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].pii_type, PiiType::Secret);
+        assert_eq!(
+            &input[detections[0].start..detections[0].end],
+            "s3cr3t-Value"
+        );
+
+        assert_eq!(
+            secret_redactor().redact(input),
+            "GET /v1/items?page=2&api_key=████████████&sort=asc"
+        );
+    }
+
+    #[test]
+    fn test_leaves_non_sensitive_keys_alone() {
+        let input = "GET /search?page=2&q=tokens&limit=10";
+        assert!(SecretKeyDetector::new().detect(input).is_empty());
+        assert_eq!(secret_redactor().redact(input), input);
+    }
+
+    #[test]
+    fn test_header_and_json_contexts() {
+        let detector = SecretKeyDetector::new();
+        assert_eq!(values(&detector, "X-API-Key: abc123\r\n"), vec!["abc123"]);
+        assert_eq!(
+            values(&detector, r#"{"token": "t0k3n", "user": "jane"}"#),
+            vec!["t0k3n"]
+        );
+        assert_eq!(
+            values(&detector, "Authorization: Bearer eyJhbGciOi.x.y"),
+            vec!["eyJhbGciOi.x.y"]
+        );
+        assert_eq!(values(&detector, "PASSWORD='hunter2'"), vec!["hunter2"]);
+    }
+
+    #[test]
+    fn test_key_must_be_whole_token() {
+        let detector = SecretKeyDetector::new();
+        assert!(detector.detect("tokenizer=bpe mytoken=x").is_empty());
+        // Empty values are not reported
+        assert!(detector.detect("api_key=&page=2").is_empty());
+        assert_eq!(
+            values(&detector, "token=a&token=b"),
+            vec!["a".to_string(), "b".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_custom_key_names() {
+        let detector = SecretKeyDetector::with_keys(&["session_id", "sig"]);
+        assert_eq!(
+            values(&detector, "?sig=deadbeef&session_id=42&api_key=x"),
+            vec!["deadbeef", "42"]
+        );
+        assert!(SecretKeyDetector::with_keys(&[])
+            .detect("api_key=x")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_zeroizes_on_drop() {
+        let detections = SecretKeyDetector::new().detect("token=abc");
+        assert_eq!(detections.len(), 1);
+        std::mem::drop(detections);
+    }
+}
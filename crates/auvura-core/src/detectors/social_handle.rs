@@ -0,0 +1,245 @@
+//! SocialHandleDetector - Detects `@username` social media handles
+//!
+//! Handles are online identifiers under GDPR but share the `@` with email
+//! addresses, so the detector only accepts an `@` that starts a token:
+//! - The character before `@` must not be part of an email local part
+//!   (alphanumeric, `.`, `_`, `%`, `+`, `-`), so `jane@example.com` never
+//!   yields a handle
+//! - A handle immediately followed by another `@` (`@user@host`) or, on
+//!   platforms without dots in names, by a `.tld`-like continuation is
+//!   rejected
+//!
+//! Length and character rules are configurable per platform via
+//! [`SocialPlatform`]. Detection is opt-in: `SocialHandle` is not enabled in
+//! the default policy.
+
+use crate::{
+    detector::{Detection, PiiDetector},
+    types::PiiType,
+};
+
+/// Platform-specific handle rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SocialPlatform {
+    /// Permissive rules: 1-30 of `[A-Za-z0-9_.]`
+    #[default]
+    Generic,
+    /// Twitter/X: 1-15 of `[A-Za-z0-9_]`
+    Twitter,
+    /// Instagram: 1-30 of `[A-Za-z0-9_.]`, no trailing dot
+    Instagram,
+    /// GitHub: 1-39 of `[A-Za-z0-9-]`, no leading hyphen
+    GitHub,
+}
+
+impl SocialPlatform {
+    fn max_len(self) -> usize {
+        match self {
+            Self::Generic | Self::Instagram => 30,
+            Self::Twitter => 15,
+            Self::GitHub => 39,
+        }
+    }
+
+    fn allows(self, b: u8) -> bool {
+        match self {
+            Self::Generic | Self::Instagram => b.is_ascii_alphanumeric() || b == b'_' || b == b'.',
+            Self::Twitter => b.is_ascii_alphanumeric() || b == b'_',
+            Self::GitHub => b.is_ascii_alphanumeric() || b == b'-',
+        }
+    }
+}
+
+/// Characters that can precede `@` inside an email address
+fn is_local_part_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'%' | b'+' | b'-')
+}
+
+/// SocialHandleDetector - detects `@handle` tokens for a platform
+pub struct SocialHandleDetector {
+    platform: SocialPlatform,
+}
+
+impl Default for SocialHandleDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SocialHandleDetector {
+    /// Create a detector using generic handle rules
+    pub fn new() -> Self {
+        Self::for_platform(SocialPlatform::Generic)
+    }
+
+    /// Create a detector using a specific platform's handle rules
+    pub fn for_platform(platform: SocialPlatform) -> Self {
+        Self { platform }
+    }
+
+    /// Try to read a handle whose `@` is at byte `at`; returns its end offset
+    fn handle_end(&self, bytes: &[u8], at: usize) -> Option<usize> {
+        if at > 0 && is_local_part_byte(bytes[at - 1]) {
+            return None;
+        }
+
+        let name_start = at + 1;
+        let mut end = name_start;
+        while end < bytes.len() && self.platform.allows(bytes[end]) {
+            end += 1;
+        }
+        // Dots are separators at the end of a sentence, not part of the name
+        while end > name_start && bytes[end - 1] == b'.' {
+            end -= 1;
+        }
+
+        let name = &bytes[name_start..end];
+        if name.is_empty() || name.len() > self.platform.max_len() {
+            return None;
+        }
+        if !name.iter().any(|b| b.is_ascii_alphabetic()) {
+            return None;
+        }
+        if self.platform == SocialPlatform::GitHub && name[0] == b'-' {
+            return None;
+        }
+
+        // Reject `@user@host` and domain-like `@example.com` continuations
+        let rest = &bytes[end..];
+        if rest.first() == Some(&b'@') {
+            return None;
+        }
+        if rest.len() >= 2 && rest[0] == b'.' && rest[1].is_ascii_alphabetic() {
+            return None;
+        }
+
+        Some(end)
+    }
+}
+
+impl PiiDetector for SocialHandleDetector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::SocialHandle
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        let bytes = text.as_bytes();
+        let mut detections = Vec::new();
+
+        for (at, _) in text.match_indices('@') {
+            if let Some(end) = self.handle_end(bytes, at) {
+                detections.push(Detection {
+                    pii_type: PiiType::SocialHandle,
+                    confidence: self.confidence(),
+                    start: at,
+                    end,
+                    original: text[at..end].to_string(),
+                    metadata: None,
+                });
+            }
+        }
+
+        detections
+    }
+
+    fn anchor_patterns(&self) -> Vec<&'static str> {
+        vec!["@"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_handle() {
+        let detector = SocialHandleDetector::new();
+        let detections = detector.detect("Thanks @jane_doe!");
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].pii_type, PiiType::SocialHandle);
+        assert_eq!(detections[0].original, "@jane_doe");
+        assert_eq!(detections[0].start, 7);
+    }
+
+    #[test]
+    fn test_email_is_not_a_handle() {
+        let detector = SocialHandleDetector::new();
+        assert!(detector.detect("jane@example.com").is_empty());
+        assert!(detector
+            .detect("mail jane.doe+tag@example.com now")
+            .is_empty());
+        assert!(detector.detect("mastodon @jane@example.social").is_empty());
+        let twitter = SocialHandleDetector::for_platform(SocialPlatform::Twitter);
+        assert!(twitter.detect("see @example.com").is_empty());
+    }
+
+    #[test]
+    fn test_trailing_punctuation_not_included() {
+        let detector = SocialHandleDetector::new();
+        let detections = detector.detect("Ask @jane. Or (@bob), @carol");
+        let handles: Vec<&str> = detections.iter().map(|d| d.original.as_str()).collect();
+        assert_eq!(handles, vec!["@jane", "@bob", "@carol"]);
+    }
+
+    #[test]
+    fn test_twitter_length_rules() {
+        let detector = SocialHandleDetector::for_platform(SocialPlatform::Twitter);
+        assert_eq!(detector.detect("@abcdefghijklmnop").len(), 0);
+        assert_eq!(detector.detect("@abcdefghijklmno").len(), 1);
+        assert_eq!(detector.detect("Ask @jane. Next")[0].original, "@jane");
+        assert!(detector.detect("@jane.doe").is_empty());
+    }
+
+    #[test]
+    fn test_instagram_allows_dots() {
+        let detector = SocialHandleDetector::for_platform(SocialPlatform::Instagram);
+        assert_eq!(detector.detect("by @jane.doe.")[0].original, "@jane.doe");
+    }
+
+    #[test]
+    fn test_github_rules() {
+        let detector = SocialHandleDetector::for_platform(SocialPlatform::GitHub);
+        assert_eq!(detector.detect("cc @octo-cat")[0].original, "@octo-cat");
+        assert!(detector.detect("cc @-octo").is_empty());
+    }
+
+    #[test]
+    fn test_rejects_non_handles() {
+        let detector = SocialHandleDetector::new();
+        assert!(detector.detect("meet @ 5").is_empty());
+        assert!(detector.detect("price @100").is_empty());
+    }
+
+    #[test]
+    fn test_integrates_with_redactor() {
+        use crate::{detectors::email::EmailDetector, policy::RedactionPolicy, redactor::Redactor};
+
+        let policy = RedactionPolicy::builder()
+            .enable(PiiType::SocialHandle)
+            .build();
+        let redactor = Redactor::new(
+            vec![
+                Box::new(EmailDetector::new()),
+                Box::new(SocialHandleDetector::new()),
+            ],
+            policy,
+        );
+
+        let input = "Ping @jane_doe or jane@example.com";
+        let ranges = redactor.redaction_ranges(input);
+        assert_eq!(
+            ranges,
+            vec![(5, 14, PiiType::SocialHandle), (18, 34, PiiType::Email)]
+        );
+        assert_eq!(redactor.redact(input), "Ping @████████ or ████@███████.com");
+    }
+
+    #[test]
+    fn test_zeroizes_on_drop() {
+        let detector = SocialHandleDetector::new();
+        let detections = detector.detect("@jane_doe");
+        assert_eq!(detections.len(), 1);
+        std::mem::drop(detections);
+    }
+}
@@ -9,7 +9,7 @@
 //! - Word boundary enforcement to prevent false positives
 
 use crate::{
-    detector::{Confidence, Detection, PiiDetector},
+    detector::{Confidence, Detection, PiiDetector, Span},
     types::PiiType,
 };
 use regex::Regex;
@@ -46,22 +46,7 @@ impl SSNDetector {
     /// Validate SSN components per SSA guidelines
     /// https://www.ssa.gov/employer/stateweb.htm
     fn is_valid_ssn(&self, area: u16, group: u8, serial: u16) -> bool {
-        // Invalid area numbers
-        if area == 0 || area == 666 || area >= 900 {
-            return false;
-        }
-
-        // Invalid group number
-        if group == 0 {
-            return false;
-        }
-
-        // Invalid serial number
-        if serial == 0 {
-            return false;
-        }
-
-        true
+        crate::ascii::is_valid_ssn_parts(area, group, serial)
     }
 
     /// Parse SSN string into components (area, group, serial)
@@ -77,60 +62,59 @@ impl SSNDetector {
 
         Some((area, group, serial))
     }
-}
-
-impl PiiDetector for SSNDetector {
-    fn pii_type(&self) -> PiiType {
-        PiiType::Ssn
-    }
-
-    fn confidence(&self) -> Confidence {
-        Confidence::High
-    }
-
-    fn detect(&self, text: &str) -> Vec<Detection> {
-        self.detect_with_validation(text, true)
-    }
 
-    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
-        let mut detections = Vec::new();
+    /// Matched spans, checked against the SSA ranges when `validate` is set
+    fn spans(&self, text: &str, validate: bool) -> Vec<Span> {
+        let mut spans = Vec::new();
 
         // Single pass with combined pattern prevents overlaps
         for m in self.combined_pattern.find_iter(text) {
-            let ssn_str = m.as_str();
-            if let Some((area, group, serial)) = self.parse_ssn(ssn_str) {
+            if let Some((area, group, serial)) = self.parse_ssn(m.as_str()) {
                 if !validate || self.is_valid_ssn(area, group, serial) {
-                    detections.push(Detection {
+                    spans.push(Span {
                         pii_type: PiiType::Ssn,
-                        confidence: self.confidence(),
                         start: m.start(),
                         end: m.end(),
-                        original: ssn_str.to_string(),
                     });
                 }
             }
         }
 
         // Sort by start position (required by trait contract)
-        detections.sort_by_key(|d| d.start);
-        detections
+        spans.sort_by_key(|s| s.start);
+        spans
     }
+}
 
-    /// Hyphen is the anchor for hyphenated SSN format (###-##-####)
-    fn anchor_patterns(&self) -> Vec<&'static str> {
-        vec!["-"]
+impl PiiDetector for SSNDetector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::Ssn
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
     }
 
-    fn detect_in_window(&self, window: &str, window_start: usize) -> Vec<Detection> {
-        self.detect_with_validation(window, true)
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        self.detect_with_validation(text, true)
+    }
+
+    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
+        self.spans(text, validate)
             .into_iter()
-            .map(|mut d| {
-                d.start += window_start;
-                d.end += window_start;
-                d
-            })
+            .map(|span| span.to_detection(text, self.confidence()))
             .collect()
     }
+
+    fn detect_spans(&self, text: &str) -> Vec<Span> {
+        self.spans(text, true)
+    }
+
+    /// No reliable literal anchor — the bare nine-digit form has no separator to anchor on.
+    /// Falls back to full-text regex scan.
+    fn anchor_patterns(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,279 @@
+//! TimestampDetector - Detects ISO-8601 and common date/time formats
+//!
+//! Precise timestamps are quasi-identifiers: combined with other attributes
+//! they can single out an individual. This detector is opt-in (Timestamp is
+//! not enabled in the default policy) and the redactor *generalizes* matches
+//! instead of masking them, truncating to the policy's
+//! `TimestampGranularity` (day, hour, or minute).
+//!
+//! Supported formats:
+//! - `2024-01-15T13:42:07Z`, `2024-01-15T13:42:07.123+01:00`
+//! - `2024-01-15 13:42` (space separator, optional seconds)
+//! - `2024-01-15` and `2024/01/15` (date only)
+//!
+//! Calendar ranges are validated (month 1-12, day 1-31, hour 0-23,
+//! minute 0-59, second 0-60) so version strings and ranges don't match.
+
+use crate::{
+    detector::{Confidence, Detection, PiiDetector},
+    policy::TimestampGranularity,
+    types::PiiType,
+};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// TimestampDetector - detects dates and date-times in year-first formats
+pub struct TimestampDetector {
+    pattern: &'static Regex,
+}
+
+impl Default for TimestampDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimestampDetector {
+    /// Create a new TimestampDetector
+    pub fn new() -> Self {
+        Self {
+            pattern: Self::get_pattern(),
+        }
+    }
+
+    fn get_pattern() -> &'static Regex {
+        static PATTERN: OnceLock<Regex> = OnceLock::new();
+        PATTERN.get_or_init(|| {
+            Regex::new(
+                r"\b([0-9]{4})[-/]([0-9]{2})[-/]([0-9]{2})(?:[T ]([0-9]{2}):([0-9]{2})(?::([0-9]{2})(?:[.,][0-9]{1,9})?)?(?:Z|[+-][0-9]{2}:?[0-9]{2})?)?\b",
+            )
+            .expect("Timestamp pattern is valid")
+        })
+    }
+
+    /// Check calendar ranges for the captured components
+    fn is_valid(caps: &regex::Captures<'_>) -> bool {
+        let field = |i: usize| caps.get(i).and_then(|m| m.as_str().parse::<u32>().ok());
+
+        let (Some(month), Some(day)) = (field(2), field(3)) else {
+            return false;
+        };
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return false;
+        }
+        if field(4).is_some_and(|h| h > 23) || field(5).is_some_and(|m| m > 59) {
+            return false;
+        }
+        field(6).is_none_or(|s| s <= 60)
+    }
+}
+
+impl PiiDetector for TimestampDetector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::Timestamp
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        let mut detections = Vec::new();
+
+        for caps in self.pattern.captures_iter(text) {
+            let m = caps.get(0).expect("group 0 always matches");
+            // Reject mixed separators like 2024-01/15
+            let sep_ok = text.as_bytes()[m.start() + 4] == text.as_bytes()[m.start() + 7];
+            if sep_ok && Self::is_valid(&caps) {
+                detections.push(Detection {
+                    pii_type: PiiType::Timestamp,
+                    confidence: self.confidence(),
+                    start: m.start(),
+                    end: m.end(),
+                    original: m.as_str().to_string(),
+                    metadata: None,
+                });
+            }
+        }
+
+        detections
+    }
+}
+
+/// Generalize a detected timestamp to the given granularity.
+///
+/// Time fields finer than `granularity` (and any fractional seconds) are
+/// replaced with zeros; the date, separators, and timezone are preserved.
+/// Date-only values are returned unchanged.
+pub fn generalize(timestamp: &str, granularity: TimestampGranularity) -> String {
+    let keep_fields = match granularity {
+        TimestampGranularity::Day => 0,
+        TimestampGranularity::Hour => 1,
+        TimestampGranularity::Minute => 2,
+    };
+
+    let bytes = timestamp.as_bytes();
+    if bytes.len() <= 11 || !matches!(bytes[10], b'T' | b' ') {
+        return timestamp.to_string();
+    }
+
+    let mut result = String::with_capacity(timestamp.len());
+    result.push_str(&timestamp[..11]);
+
+    let mut field = 0;
+    let mut in_zone = false;
+    for c in timestamp[11..].chars() {
+        if in_zone {
+            result.push(c);
+            continue;
+        }
+        match c {
+            '0'..='9' if field < keep_fields => result.push(c),
+            '0'..='9' => result.push('0'),
+            ':' | '.' | ',' => {
+                field += 1;
+                result.push(c);
+            }
+            // 'Z' or a +hh:mm / -hh:mm offset – keep verbatim
+            _ => {
+                in_zone = true;
+                result.push(c);
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_iso8601_utc() {
+        let detector = TimestampDetector::new();
+        let detections = detector.detect("Logged in at 2024-01-15T13:42:07Z from web");
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].pii_type, PiiType::Timestamp);
+        assert_eq!(detections[0].original, "2024-01-15T13:42:07Z");
+        assert_eq!(detections[0].start, 13);
+    }
+
+    #[test]
+    fn test_detects_offset_and_fraction() {
+        let detector = TimestampDetector::new();
+        let detections = detector.detect("at 2024-01-15T13:42:07.123+01:00.");
+
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].original, "2024-01-15T13:42:07.123+01:00");
+    }
+
+    #[test]
+    fn test_detects_space_separated_and_date_only() {
+        let detector = TimestampDetector::new();
+        let detections = detector.detect("from 2024-01-15 13:42 until 2024/02/01");
+
+        assert_eq!(detections.len(), 2);
+        assert_eq!(detections[0].original, "2024-01-15 13:42");
+        assert_eq!(detections[1].original, "2024/02/01");
+    }
+
+    #[test]
+    fn test_rejects_invalid_calendar_values() {
+        let detector = TimestampDetector::new();
+        assert!(detector.detect("2024-13-01").is_empty());
+        assert!(detector.detect("2024-01-32").is_empty());
+        assert!(detector.detect("2024-01/15").is_empty());
+        assert!(detector.detect("2024-01-15T25:00:00Z").is_empty());
+    }
+
+    #[test]
+    fn test_ignores_non_ascii_digits() {
+        let detector = TimestampDetector::new();
+        // Arabic-Indic and fullwidth digits
+        assert!(detector.detect("٢٠٢٤-٠١-١٥T١٣:٤٢:٠٧Z").is_empty());
+        assert!(detector.detect("２０２４-０１-１５").is_empty());
+        assert!(detector.detect("١٢٣٤2024-01-15").is_empty());
+        // Would otherwise pass the range checks and reach ASCII-only generalization
+        assert!(detector.detect("2024-01-15T١٣:٤٢").is_empty());
+        let detections = detector.detect("٢٠٢٤ 2024-01-15T13:42:07Z");
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].original, "2024-01-15T13:42:07Z");
+    }
+
+    #[test]
+    fn test_generalize_to_day() {
+        assert_eq!(
+            generalize("2024-01-15T13:42:07Z", TimestampGranularity::Day),
+            "2024-01-15T00:00:00Z"
+        );
+        assert_eq!(
+            generalize("2024-01-15T13:42:07.123+01:00", TimestampGranularity::Day),
+            "2024-01-15T00:00:00.000+01:00"
+        );
+    }
+
+    #[test]
+    fn test_generalize_to_hour() {
+        assert_eq!(
+            generalize("2024-01-15T13:42:07Z", TimestampGranularity::Hour),
+            "2024-01-15T13:00:00Z"
+        );
+        assert_eq!(
+            generalize("2024-01-15 13:42", TimestampGranularity::Hour),
+            "2024-01-15 13:00"
+        );
+    }
+
+    #[test]
+    fn test_generalize_date_only_unchanged() {
+        assert_eq!(
+            generalize("2024-01-15", TimestampGranularity::Hour),
+            "2024-01-15"
+        );
+    }
+
+    #[test]
+    fn test_integrates_with_redactor() {
+        use crate::{policy::RedactionPolicy, redactor::Redactor};
+
+        let policy = RedactionPolicy::builder()
+            .enable(PiiType::Timestamp)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(TimestampDetector::new())], policy);
+        assert_eq!(
+            redactor.redact("event at 2024-01-15T13:42:07Z"),
+            "event at 2024-01-15T00:00:00Z"
+        );
+
+        let policy = RedactionPolicy::builder()
+            .enable(PiiType::Timestamp)
+            .timestamp_granularity(TimestampGranularity::Hour)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(TimestampDetector::new())], policy);
+        assert_eq!(
+            redactor.redact("event at 2024-01-15T13:42:07Z"),
+            "event at 2024-01-15T13:00:00Z"
+        );
+    }
+
+    #[test]
+    fn test_opt_in_by_default() {
+        use crate::{policy::RedactionPolicy, redactor::Redactor};
+
+        let redactor = Redactor::new(
+            vec![Box::new(TimestampDetector::new())],
+            RedactionPolicy::default(),
+        );
+        let input = "event at 2024-01-15T13:42:07Z";
+        assert_eq!(redactor.redact(input), input);
+    }
+
+    #[test]
+    fn test_zeroizes_on_drop() {
+        let detector = TimestampDetector::new();
+        let detections = detector.detect("2024-01-15T13:42:07Z");
+        assert_eq!(detections.len(), 1);
+        std::mem::drop(detections);
+    }
+}
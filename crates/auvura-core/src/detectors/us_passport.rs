@@ -0,0 +1,129 @@
+//! UsPassportDetector - Detects US passport book numbers
+//!
+//! US passport numbers are nine characters: nine digits on older books, a
+//! letter followed by eight digits on Next Generation books issued since
+//! 2021. Either shape is just a short alphanumeric run, so detection is
+//! gated on context: the keyword `passport` (case-insensitive) must appear
+//! shortly before the number. `with_keywords` replaces the keyword list.
+//!
+//! The generic [`PassportDetector`](super::passport::PassportDetector)
+//! matches many national formats without context. Both may report the same
+//! span; this one is `High` confidence, so it wins the overlap.
+
+use super::has_context;
+use crate::{
+    detector::{Confidence, Detection, PiiDetector},
+    types::PiiType,
+};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// UsPassportDetector - detects keyword-gated nine-character passport numbers
+pub struct UsPassportDetector {
+    pattern: &'static Regex,
+    keywords: Vec<String>,
+}
+
+impl Default for UsPassportDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UsPassportDetector {
+    /// Create a new UsPassportDetector
+    pub fn new() -> Self {
+        static PATTERN: OnceLock<Regex> = OnceLock::new();
+        Self {
+            pattern: PATTERN.get_or_init(|| {
+                Regex::new(r"\b[0-9A-Z]{9}\b").expect("US passport pattern is valid")
+            }),
+            keywords: vec!["passport".to_string()],
+        }
+    }
+
+    /// Gate on these keywords instead of `passport`
+    pub fn with_keywords(mut self, keywords: &[&str]) -> Self {
+        self.keywords = keywords.iter().map(|k| k.to_string()).collect();
+        self
+    }
+}
+
+impl PiiDetector for UsPassportDetector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::UsPassport
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        self.detect_with_validation(text, true)
+    }
+
+    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
+        self.pattern
+            .find_iter(text)
+            .filter(|m| has_context(text, m.start(), &self.keywords))
+            .filter(|m| !validate || self.validate(m.as_str()))
+            .map(|m| Detection {
+                pii_type: PiiType::UsPassport,
+                confidence: self.confidence(),
+                start: m.start(),
+                end: m.end(),
+                original: m.as_str().to_string(),
+                metadata: None,
+            })
+            .collect()
+    }
+
+    /// Nine digits, or one letter and eight digits
+    fn validate(&self, candidate: &str) -> bool {
+        let bytes = candidate.as_bytes();
+        bytes.len() == 9
+            && (bytes[0].is_ascii_digit() || bytes[0].is_ascii_uppercase())
+            && bytes[1..].iter().all(u8::is_ascii_digit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn found(text: &str) -> Vec<&str> {
+        UsPassportDetector::new()
+            .detect(text)
+            .iter()
+            .map(|d| &text[d.start..d.end])
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_passport_after_keyword() {
+        let detections = UsPassportDetector::new().detect("Passport No: 123456789");
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].pii_type, PiiType::UsPassport);
+        assert_eq!(detections[0].original, "123456789");
+        assert_eq!(detections[0].start, 13);
+        assert_eq!(found("US passport A12345678 issued"), vec!["A12345678"]);
+    }
+
+    #[test]
+    fn test_requires_keyword() {
+        assert!(found("Order No: 123456789").is_empty());
+        assert!(found("ref A12345678").is_empty());
+        let detector = UsPassportDetector::new().with_keywords(&["travel document"]);
+        assert_eq!(detector.detect("Travel document 123456789").len(), 1);
+        assert!(detector.detect("Passport 123456789").is_empty());
+    }
+
+    #[test]
+    fn test_rejects_other_nine_character_runs() {
+        assert!(found("passport AB1234567").is_empty());
+        assert!(found("passport 12345678").is_empty());
+        assert!(found("passport 1234567890").is_empty());
+        assert!(found("passport X123456789Y").is_empty());
+        assert!(!UsPassportDetector::new().validate("PASSPORTS"));
+    }
+}
@@ -0,0 +1,377 @@
+//! VatDetector - UK and EU VAT identification numbers
+//!
+//! A VAT number is a two-letter country prefix followed by a country-specific
+//! body, e.g. `GB 980 7806 84`, `DE136695976`, or `NL004495445B01`. Grouping
+//! spaces and hyphens are allowed. Supported countries and their checks:
+//!
+//! | Prefix | Body | Check |
+//! |--------|------|-------|
+//! | `GB` | 9 or 12 digits; `GD`/`HA` + 3 digits | mod-97 (old and 2010 schemes) |
+//! | `DE` | 9 digits | ISO 7064 MOD 11,10 |
+//! | `FR` | 2-char key + 9-digit SIREN | numeric key = (12 + 3·(SIREN mod 97)) mod 97 |
+//! | `IT` | 11 digits | Luhn |
+//! | `NL` | 9 digits + `B` + 2 digits | mod-11, or mod-97 for post-2020 numbers |
+//! | `BE` | 10 digits starting 0 or 1 | 97 − (first 8 mod 97) = last 2 |
+//! | `PL` | 10 digits | weighted mod-11 |
+//! | `AT` | `U` + 8 digits | weighted mod-10 |
+//! | `DK` | 8 digits | weighted mod-11 |
+//! | `ES`, `IE`, `SE` | country format | structure only |
+//!
+//! Country prefixes collide with ordinary words and codes, so detection is
+//! gated on context: a VAT keyword (`VAT`, `TVA`, `IVA`, `BTW`, `USt`,
+//! `MwSt`, `moms`; case-insensitive) must appear shortly before the number;
+//! `with_keywords` replaces the list.
+//! In `Mask` mode the country prefix is kept: `GB 980 7806 84` →
+//! `GB ███ ████ ██`.
+
+use super::has_context;
+use crate::{
+    detector::{Confidence, Detection, PiiDetector},
+    types::PiiType,
+};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Default context keywords, in the languages of the supported countries
+pub const VAT_KEYWORDS: [&str; 7] = ["VAT", "TVA", "IVA", "BTW", "USt", "MwSt", "moms"];
+
+fn digits(s: &str) -> Vec<u32> {
+    s.chars().filter_map(|c| c.to_digit(10)).collect()
+}
+
+fn is_digits(s: &str, len: usize) -> bool {
+    s.len() == len && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Whether `body` (separators removed) has the shape of `country`'s numbers
+fn has_structure(country: &str, body: &str) -> bool {
+    let b = body.as_bytes();
+    match country {
+        "GB" => {
+            is_digits(body, 9)
+                || is_digits(body, 12)
+                || ((body.starts_with("GD") || body.starts_with("HA")) && is_digits(&body[2..], 3))
+        }
+        "DE" => is_digits(body, 9),
+        "FR" => {
+            body.len() == 11
+                && b[..2].iter().all(|&c| {
+                    c.is_ascii_digit() || (c.is_ascii_uppercase() && c != b'I' && c != b'O')
+                })
+                && is_digits(&body[2..], 9)
+        }
+        "IT" => is_digits(body, 11),
+        "NL" => {
+            body.len() == 12
+                && is_digits(&body[..9], 9)
+                && b[9] == b'B'
+                && is_digits(&body[10..], 2)
+        }
+        "BE" => is_digits(body, 10) && matches!(b[0], b'0' | b'1'),
+        "PL" => is_digits(body, 10),
+        "AT" => body.len() == 9 && b[0] == b'U' && is_digits(&body[1..], 8),
+        "DK" => is_digits(body, 8),
+        "ES" => {
+            body.len() == 9
+                && b[0].is_ascii_alphanumeric()
+                && is_digits(&body[1..8], 7)
+                && b[8].is_ascii_alphanumeric()
+                && !b[0].is_ascii_lowercase()
+                && !b[8].is_ascii_lowercase()
+        }
+        "IE" => {
+            let valid_tail = |t: &[u8]| match t {
+                [c] => (b'A'..=b'W').contains(c),
+                [c, d] => (b'A'..=b'W').contains(c) && ((b'A'..=b'I').contains(d) || *d == b'W'),
+                _ => false,
+            };
+            (body.len() >= 8 && is_digits(&body[..7], 7) && valid_tail(&b[7..]))
+                || (body.len() == 8
+                    && b[0].is_ascii_digit()
+                    && (b[1].is_ascii_uppercase() || b[1] == b'+' || b[1] == b'*')
+                    && is_digits(&body[2..7], 5)
+                    && valid_tail(&b[7..]))
+        }
+        "SE" => is_digits(body, 12) && body.ends_with("01"),
+        _ => false,
+    }
+}
+
+fn luhn(d: &[u32]) -> bool {
+    let sum: u32 = d
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &x)| match (i % 2, x * 2) {
+            (1, doubled) if doubled > 9 => doubled - 9,
+            (1, doubled) => doubled,
+            _ => x,
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+fn weighted(d: &[u32], weights: &[u32]) -> u32 {
+    d.iter().zip(weights).map(|(x, w)| x * w).sum()
+}
+
+/// Country checksum over a structurally valid `body`
+fn checksum(country: &str, body: &str) -> bool {
+    let d = digits(body);
+    match country {
+        "GB" if d.len() >= 9 && !body.starts_with(['G', 'H']) => {
+            let sum = weighted(&d[..7], &[8, 7, 6, 5, 4, 3, 2]) + d[7] * 10 + d[8];
+            sum.is_multiple_of(97) || (sum + 55).is_multiple_of(97)
+        }
+        "DE" => {
+            let mut product = 10;
+            for &x in &d[..8] {
+                let mut sum = (x + product) % 10;
+                if sum == 0 {
+                    sum = 10;
+                }
+                product = (2 * sum) % 11;
+            }
+            (11 - product) % 10 == d[8]
+        }
+        "FR" if body.as_bytes()[..2].iter().all(u8::is_ascii_digit) => {
+            let siren = d[2..].iter().fold(0u64, |n, &x| n * 10 + u64::from(x));
+            u64::from(d[0] * 10 + d[1]) == (12 + 3 * (siren % 97)) % 97
+        }
+        "IT" => luhn(&d),
+        "NL" => {
+            let old = weighted(&d[..8], &[9, 8, 7, 6, 5, 4, 3, 2]) % 11 == d[8];
+            // Post-2020 sole-trader numbers: ISO 7064 mod 97-10 over "NL" + body
+            let new = format!("NL{body}")
+                .chars()
+                .filter_map(|c| c.to_digit(36))
+                .fold(0u64, |n, x| {
+                    let width = if x > 9 { 100 } else { 10 };
+                    (n * width + u64::from(x)) % 97
+                })
+                == 1;
+            old || new
+        }
+        "BE" => {
+            let first8 = d[..8].iter().fold(0u32, |n, &x| n * 10 + x);
+            97 - first8 % 97 == d[8] * 10 + d[9]
+        }
+        "PL" => weighted(&d[..9], &[6, 5, 7, 2, 3, 4, 5, 6, 7]) % 11 == d[9],
+        "AT" => {
+            let sum: u32 = d[..7]
+                .iter()
+                .enumerate()
+                .map(|(i, &x)| {
+                    if i % 2 == 1 {
+                        x * 2 / 10 + x * 2 % 10
+                    } else {
+                        x
+                    }
+                })
+                .sum();
+            (10 - (sum + 4) % 10) % 10 == d[7]
+        }
+        "DK" => weighted(&d, &[2, 7, 6, 5, 4, 3, 2, 1]).is_multiple_of(11),
+        // Government/health GB numbers, alphabetic FR keys, and the
+        // structure-only countries have no check here
+        _ => true,
+    }
+}
+
+/// Split a candidate into its country prefix and separator-free body
+fn split(candidate: &str) -> (&str, String) {
+    let (country, rest) = candidate.split_at(2);
+    let body = rest.chars().filter(|c| *c != ' ' && *c != '-').collect();
+    (country, body)
+}
+
+/// VatDetector - detects and validates UK/EU VAT numbers
+pub struct VatDetector {
+    pattern: &'static Regex,
+    keywords: Vec<String>,
+}
+
+impl Default for VatDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VatDetector {
+    /// Create a new VatDetector
+    pub fn new() -> Self {
+        static PATTERN: OnceLock<Regex> = OnceLock::new();
+        Self {
+            pattern: PATTERN.get_or_init(|| {
+                Regex::new(
+                    r"\b(?:GB|DE|FR|IT|NL|BE|PL|AT|DK|ES|IE|SE)[ -]?[0-9A-Z+*]+(?:[ -][0-9A-Z]+)*",
+                )
+                .expect("VAT pattern is valid")
+            }),
+            keywords: VAT_KEYWORDS.iter().map(|k| k.to_string()).collect(),
+        }
+    }
+
+    /// Gate on these keywords instead of [`VAT_KEYWORDS`]
+    pub fn with_keywords(mut self, keywords: &[&str]) -> Self {
+        self.keywords = keywords.iter().map(|k| k.to_string()).collect();
+        self
+    }
+}
+
+impl PiiDetector for VatDetector {
+    fn pii_type(&self) -> PiiType {
+        PiiType::VatNumber
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, text: &str) -> Vec<Detection> {
+        self.detect_with_validation(text, true)
+    }
+
+    fn detect_with_validation(&self, text: &str, validate: bool) -> Vec<Detection> {
+        let mut detections = Vec::new();
+        for m in self.pattern.find_iter(text) {
+            if text[m.end()..]
+                .chars()
+                .next()
+                .is_some_and(char::is_alphanumeric)
+                || !has_context(text, m.start(), &self.keywords)
+            {
+                continue;
+            }
+            // The pattern may run on into following groups (`GB 980 7806 84 2024`):
+            // take the longest prefix that ends on a group and has the country's shape
+            let ends = m
+                .as_str()
+                .char_indices()
+                .filter(|&(i, c)| i > 2 && (c == ' ' || c == '-'))
+                .map(|(i, _)| i)
+                .chain([m.len()]);
+            let mut ends: Vec<usize> = ends.collect();
+            ends.reverse();
+            let Some(len) = ends.into_iter().find(|&len| {
+                let (country, body) = split(&m.as_str()[..len]);
+                has_structure(country, &body)
+            }) else {
+                continue;
+            };
+            let candidate = &m.as_str()[..len];
+            if validate && !self.validate(candidate) {
+                continue;
+            }
+            detections.push(Detection {
+                pii_type: PiiType::VatNumber,
+                confidence: self.confidence(),
+                start: m.start(),
+                end: m.start() + len,
+                original: candidate.to_string(),
+                metadata: None,
+            });
+        }
+        detections
+    }
+
+    fn validate(&self, candidate: &str) -> bool {
+        if candidate.len() < 3 || !candidate.is_char_boundary(2) {
+            return false;
+        }
+        let (country, body) = split(candidate);
+        has_structure(country, &body) && checksum(country, &body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{policy::RedactionPolicy, redactor::Redactor};
+
+    fn vat_redactor(strict: bool) -> Redactor {
+        let policy = RedactionPolicy::builder()
+            .enable(PiiType::VatNumber)
+            .strict_validation(strict)
+            .build();
+        Redactor::new(vec![Box::new(VatDetector::new())], policy)
+    }
+
+    #[test]
+    fn test_valid_gb_vat_passes_checksum() {
+        let detector = VatDetector::new();
+        assert!(detector.validate("GB980780684"));
+        assert!(detector.validate("GB 980 7806 84"));
+        assert!(!detector.validate("GB980780685"));
+
+        let detections = detector.detect("Our VAT no. is GB 980 7806 84.");
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].pii_type, PiiType::VatNumber);
+        assert_eq!(detections[0].original, "GB 980 7806 84");
+    }
+
+    #[test]
+    fn test_invalid_gb_vat_rejected_under_strict_mode() {
+        let input = "VAT: GB980780685";
+        assert_eq!(vat_redactor(true).redact(input), input);
+        assert_eq!(vat_redactor(false).redact(input), "VAT: GB█████████");
+    }
+
+    #[test]
+    fn test_mask_keeps_country_prefix() {
+        let redactor = vat_redactor(true);
+        assert_eq!(
+            redactor.redact("VAT GB 980 7806 84, USt-IdNr DE136695976"),
+            "VAT GB ███ ████ ██, USt-IdNr DE█████████"
+        );
+        assert_eq!(
+            redactor.redact("BTW-nummer NL004495445B01"),
+            "BTW-nummer NL████████████"
+        );
+    }
+
+    #[test]
+    fn test_eu_checksums() {
+        let detector = VatDetector::new();
+        for valid in [
+            "DE136695976",
+            "FR83404833048",
+            "IT00743110157",
+            "NL004495445B01",
+            "BE0403019261",
+            "PL5260250995",
+            "ATU10223006",
+            "DK13585628",
+        ] {
+            assert!(detector.validate(valid), "{valid}");
+        }
+        for invalid in [
+            "DE136695977",
+            "FR84404833048",
+            "IT00743110158",
+            "NL004495446B01",
+            "BE0403019262",
+            "PL5260250996",
+            "ATU10223007",
+            "DK13585629",
+            // Wrong shape for the country
+            "DE13669597",
+            "BE2403019261",
+        ] {
+            assert!(!detector.validate(invalid), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_requires_context_and_boundaries() {
+        let detector = VatDetector::new();
+        assert!(detector.detect("ref GB980780684").is_empty());
+        // Keyword must be a whole word
+        assert!(detector.detect("VATICAN GB980780684").is_empty());
+        assert!(detector.detect("VAT GB980780684X").is_empty());
+        // Trailing groups that don't belong to the number are left out
+        let detections = detector.detect("VAT GB 980 7806 84 2024");
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].original, "GB 980 7806 84");
+    }
+}
@@ -0,0 +1,201 @@
+//! Reversible redaction for access-controlled viewing.
+//!
+//! In `Encrypt` mode each value is replaced by a token like
+//! `[ENC:EMAIL:3f9a…]`, encrypted under the key configured for its
+//! `PiiType`. Giving each type its own key supports role-based partial
+//! revelation: [`reveal`] decrypts only the types whose keys the viewer
+//! holds, and every other token stays opaque.
+//!
+//! Tokens are deterministic (equal values under one key give equal tokens),
+//! so they can be joined on like `Hash` mode output. Values are sealed with
+//! AES-SIV (RFC 5297), a deterministic authenticated encryption mode, with
+//! the type label as associated data: tampered tokens, wrong keys, and
+//! tokens relabelled as another type are all rejected on reveal.
+//!
+//! # Example
+//!
+//! ```rust
+//! use auvura_core::encryption::{reveal, Key};
+//! use auvura_core::policy::RedactionMode;
+//! use auvura_core::redactor::Redactor;
+//! use auvura_core::types::PiiType;
+//! use std::collections::HashMap;
+//!
+//! let key = Key::from_bytes([7; 32]);
+//! let redactor = Redactor::builder()
+//!     .enable(PiiType::Email)
+//!     .with_mode(RedactionMode::Encrypt)
+//!     .policy(|policy| policy.with_encryption_key(PiiType::Email, key.clone()))
+//!     .build()
+//!     .unwrap();
+//!
+//! let redacted = redactor.redact("mail jane@example.com");
+//! assert!(redacted.starts_with("mail [ENC:EMAIL:"));
+//! let keys = HashMap::from([(PiiType::Email, key)]);
+//! assert_eq!(reveal(&redacted, &keys), "mail jane@example.com");
+//! ```
+
+use crate::types::PiiType;
+use aes_siv::{siv::Aes256Siv, KeyInit};
+use std::collections::HashMap;
+use zeroize::{Zeroize, Zeroizing};
+
+const TOKEN_PREFIX: &str = "[ENC:";
+/// Length of the synthetic IV that prefixes each ciphertext
+const TAG_LEN: usize = 16;
+
+/// A 256-bit encryption key, zeroized on drop
+#[derive(Clone)]
+pub struct Key([u8; 32]);
+
+impl Key {
+    /// Use raw key bytes, e.g. fetched from a KMS
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Derive a key from secret material, separated by `context` (e.g.
+    /// `"acme support-role email 2026"`) so one secret can back several keys
+    pub fn derive(context: &str, material: &[u8]) -> Self {
+        Self(blake3::derive_key(context, material))
+    }
+
+    /// AES-SIV cipher keyed by the 512-bit expansion of this key
+    fn cipher(&self) -> Aes256Siv {
+        let mut siv_key = Zeroizing::new([0; 64]);
+        blake3::Hasher::new_derive_key("auvura encryption v2 aes-siv")
+            .update(&self.0)
+            .finalize_xof()
+            .fill(siv_key.as_mut());
+        Aes256Siv::new(siv_key.as_ref().into())
+    }
+}
+
+impl std::fmt::Debug for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Key(..)")
+    }
+}
+
+impl Drop for Key {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Encrypt `value` as `pii_type` into a self-describing token
+pub fn encrypt(key: &Key, pii_type: PiiType, value: &str) -> String {
+    let label = pii_type.label();
+    let sealed = key
+        .cipher()
+        .encrypt([label.as_bytes()], value.as_bytes())
+        .expect("AES-SIV accepts any plaintext with one header");
+
+    let mut token = format!("{TOKEN_PREFIX}{label}:");
+    for byte in &sealed {
+        token.push_str(&format!("{byte:02x}"));
+    }
+    token.push(']');
+    token
+}
+
+/// Recover the value of a token's hex payload, or `None` if the key is
+/// wrong or the payload was altered
+fn decrypt(key: &Key, label: &str, hex: &str) -> Option<String> {
+    if !hex.len().is_multiple_of(2) || hex.len() < TAG_LEN * 2 {
+        return None;
+    }
+    let sealed = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    let data = key.cipher().decrypt([label.as_bytes()], &sealed).ok()?;
+    String::from_utf8(data)
+        .map_err(|err| err.into_bytes().zeroize())
+        .ok()
+}
+
+/// Decrypt the tokens in `text` whose type has a key in `keys`.
+///
+/// Tokens of other types, and tokens that fail to authenticate under the
+/// supplied key, are left as they are.
+pub fn reveal(text: &str, keys: &HashMap<PiiType, Key>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(TOKEN_PREFIX) {
+        out.push_str(&rest[..start]);
+        let token = &rest[start..];
+        let Some(end) = token.find(']') else {
+            rest = token;
+            break;
+        };
+        let revealed = token[TOKEN_PREFIX.len()..end]
+            .split_once(':')
+            .and_then(|(label, hex)| {
+                let (_, key) = keys.iter().find(|(t, _)| t.label() == label)?;
+                decrypt(key, label, hex)
+            });
+        match revealed {
+            Some(value) => out.push_str(&value),
+            None => out.push_str(&token[..=end]),
+        }
+        rest = &token[end + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_and_determinism() {
+        let key = Key::from_bytes([1; 32]);
+        let token = encrypt(&key, PiiType::Ssn, "123-45-6789");
+        assert!(token.starts_with("[ENC:SSN:") && token.ends_with(']'));
+        assert!(!token.contains("6789"));
+        assert_eq!(token, encrypt(&key, PiiType::Ssn, "123-45-6789"));
+
+        let keys = HashMap::from([(PiiType::Ssn, key)]);
+        assert_eq!(reveal(&format!("ssn {token}."), &keys), "ssn 123-45-6789.");
+    }
+
+    #[test]
+    fn test_wrong_key_and_tampering_leave_token() {
+        let token = encrypt(&Key::from_bytes([1; 32]), PiiType::Email, "a@b.com");
+        let wrong = HashMap::from([(PiiType::Email, Key::from_bytes([2; 32]))]);
+        assert_eq!(reveal(&token, &wrong), token);
+
+        let right = HashMap::from([(PiiType::Email, Key::from_bytes([1; 32]))]);
+        let last = token.len() - 2;
+        let flipped = if &token[last..last + 1] == "0" {
+            "1"
+        } else {
+            "0"
+        };
+        let tampered = format!("{}{flipped}]", &token[..last]);
+        assert_eq!(reveal(&tampered, &right), tampered);
+    }
+
+    #[test]
+    fn test_relabelled_token_is_rejected() {
+        let key = Key::from_bytes([1; 32]);
+        let token = encrypt(&key, PiiType::Ssn, "123-45-6789");
+        let relabelled = token.replacen("[ENC:SSN:", "[ENC:EMAIL:", 1);
+        let keys = HashMap::from([(PiiType::Email, key)]);
+        assert_eq!(reveal(&relabelled, &keys), relabelled);
+    }
+
+    #[test]
+    fn test_other_types_and_debug() {
+        let key = Key::derive("auvura test", b"secret");
+        let token = encrypt(&key, PiiType::Other("PERSON"), "Jane Doe");
+        assert!(token.starts_with("[ENC:PERSON:"));
+        let keys = HashMap::from([(PiiType::Other("PERSON"), key)]);
+        assert_eq!(reveal(&token, &keys), "Jane Doe");
+        assert_eq!(format!("{:?}", keys[&PiiType::Other("PERSON")]), "Key(..)");
+        // Unterminated or foreign brackets pass through
+        assert_eq!(reveal("[ENC:broken", &keys), "[ENC:broken");
+    }
+}
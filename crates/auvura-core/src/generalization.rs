@@ -0,0 +1,220 @@
+//! Generalization of quasi-identifiers
+//!
+//! Suppressing every quasi-identifier (ZIP code, date of birth, location)
+//! destroys data that analytics still needs. A [`GeneralizationPolicy`]
+//! instead coarsens the values of selected PII types, k-anonymity style:
+//!
+//! | Generalization         | Input                 | Output          |
+//! |------------------------|-----------------------|-----------------|
+//! | `KeepLeadingDigits(3)` | `94107`               | `941**`         |
+//! | `Year`                 | `1985-03-14`          | `1985`          |
+//! | `Date`                 | `2024-01-15T13:42Z`   | `2024-01-15`    |
+//! | `RoundCoordinates(2)`  | `37.77493,-122.41942` | `37.77,-122.42` |
+//!
+//! Values a generalization cannot interpret (no year in a DOB, too few
+//! digits in a ZIP) fall back to the policy's normal redaction, so nothing
+//! passes through unprotected. Applied generalizations are recorded in the
+//! [`RedactionReport`](crate::report::RedactionReport) as
+//! [`Disposition::Generalized`](crate::report::Disposition::Generalized).
+
+use crate::types::PiiType;
+use std::collections::HashMap;
+
+/// How a value is coarsened
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Generalization {
+    /// Keep the first `n` digits and replace later digits with `*`;
+    /// separators are kept (`94107-1234` → `941**-****` for `n = 3`)
+    KeepLeadingDigits(usize),
+    /// Reduce a date to its four-digit year
+    Year,
+    /// Reduce a year-first timestamp to its `YYYY-MM-DD` date
+    Date,
+    /// Round every decimal number in the value to `n` decimal places
+    RoundCoordinates(u8),
+}
+
+impl Generalization {
+    /// Generalize `value`; `None` if it doesn't have the expected shape
+    pub fn apply(&self, value: &str) -> Option<String> {
+        match *self {
+            Self::KeepLeadingDigits(keep) => keep_leading_digits(value, keep),
+            Self::Year => find_year(value).map(str::to_string),
+            Self::Date => leading_date(value).map(str::to_string),
+            Self::RoundCoordinates(places) => round_numbers(value, places),
+        }
+    }
+}
+
+/// Per-type generalization rules
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GeneralizationPolicy {
+    rules: HashMap<PiiType, Generalization>,
+}
+
+impl GeneralizationPolicy {
+    /// Create an empty policy (no type is generalized)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generalize values of `pii_type` instead of redacting them
+    pub fn generalize(mut self, pii_type: PiiType, generalization: Generalization) -> Self {
+        self.rules.insert(pii_type, generalization);
+        self
+    }
+
+    /// The generalization configured for `pii_type`, if any
+    pub fn get(&self, pii_type: PiiType) -> Option<Generalization> {
+        self.rules.get(&pii_type).copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// This policy with `other`'s generalizations layered on top
+    pub(crate) fn overlay(&self, other: &Self) -> Self {
+        let mut rules = self.rules.clone();
+        rules.extend(other.rules.iter().map(|(t, g)| (*t, *g)));
+        Self { rules }
+    }
+}
+
+fn keep_leading_digits(value: &str, keep: usize) -> Option<String> {
+    let digits = value.bytes().filter(u8::is_ascii_digit).count();
+    if digits <= keep {
+        return None;
+    }
+    let mut seen = 0;
+    Some(
+        value
+            .chars()
+            .map(|c| {
+                if !c.is_ascii_digit() {
+                    return c;
+                }
+                seen += 1;
+                if seen <= keep {
+                    c
+                } else {
+                    '*'
+                }
+            })
+            .collect(),
+    )
+}
+
+/// The first standalone run of exactly four digits in 1000-2999
+fn find_year(value: &str) -> Option<&str> {
+    let bytes = value.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i - start == 4 && matches!(bytes[start], b'1' | b'2') {
+            return Some(&value[start..i]);
+        }
+    }
+    None
+}
+
+/// A leading `YYYY-MM-DD` (or `YYYY/MM/DD`)
+fn leading_date(value: &str) -> Option<&str> {
+    let date = value.get(..10)?;
+    let b = date.as_bytes();
+    let digits = |r: std::ops::Range<usize>| b[r].iter().all(u8::is_ascii_digit);
+    let separated = matches!((b[4], b[7]), (b'-', b'-') | (b'/', b'/'));
+    (digits(0..4) && digits(5..7) && digits(8..10) && separated).then_some(date)
+}
+
+fn round_numbers(value: &str, places: u8) -> Option<String> {
+    let mut out = String::with_capacity(value.len());
+    let mut rounded_any = false;
+    let mut rest = value;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit() || c == '-') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let len = rest
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| !(c.is_ascii_digit() || c == '.'))
+            .map_or(rest.len(), |(i, _)| i);
+        let number = &rest[..len];
+        match number.parse::<f64>() {
+            Ok(n) if number.contains('.') => {
+                out.push_str(&format!("{n:.prec$}", prec = places as usize));
+                rounded_any = true;
+            }
+            _ => out.push_str(number),
+        }
+        rest = &rest[len..];
+    }
+    out.push_str(rest);
+    rounded_any.then_some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keep_leading_digits() {
+        let zip3 = Generalization::KeepLeadingDigits(3);
+        assert_eq!(zip3.apply("94107").as_deref(), Some("941**"));
+        assert_eq!(zip3.apply("94107-1234").as_deref(), Some("941**-****"));
+        assert_eq!(zip3.apply("941"), None);
+    }
+
+    #[test]
+    fn test_year() {
+        let year = Generalization::Year;
+        assert_eq!(year.apply("1985-03-14").as_deref(), Some("1985"));
+        assert_eq!(year.apply("03/14/1985").as_deref(), Some("1985"));
+        assert_eq!(year.apply("14.03.85"), None);
+    }
+
+    #[test]
+    fn test_date() {
+        let date = Generalization::Date;
+        assert_eq!(
+            date.apply("2024-01-15T13:42:07Z").as_deref(),
+            Some("2024-01-15")
+        );
+        assert_eq!(date.apply("2024/01/15").as_deref(), Some("2024/01/15"));
+        assert_eq!(date.apply("15 Jan 2024"), None);
+    }
+
+    #[test]
+    fn test_round_coordinates() {
+        let geo = Generalization::RoundCoordinates(2);
+        assert_eq!(
+            geo.apply("37.77493,-122.41942").as_deref(),
+            Some("37.77,-122.42")
+        );
+        assert_eq!(
+            geo.apply("lat 51.50735 lon -0.12776").as_deref(),
+            Some("lat 51.51 lon -0.13")
+        );
+        assert_eq!(geo.apply("no numbers"), None);
+        assert_eq!(geo.apply("12-34"), None);
+    }
+
+    #[test]
+    fn test_policy_lookup() {
+        let policy =
+            GeneralizationPolicy::new().generalize(PiiType::Other("ZIP"), Generalization::Year);
+        assert_eq!(
+            policy.get(PiiType::Other("ZIP")),
+            Some(Generalization::Year)
+        );
+        assert_eq!(policy.get(PiiType::Email), None);
+        assert!(GeneralizationPolicy::new().is_empty());
+    }
+}
@@ -1,11 +1,50 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub mod ascii;
+pub mod detection;
+pub mod types;
+
+#[cfg(feature = "std")]
 pub mod audit;
+#[cfg(feature = "std")]
+pub mod crash_log;
+#[cfg(feature = "std")]
+pub mod csv;
+#[cfg(feature = "std")]
 pub mod detector;
+#[cfg(feature = "std")]
 pub mod detectors;
+#[cfg(feature = "std")]
+pub mod encryption;
+#[cfg(feature = "std")]
+pub mod generalization;
+#[cfg(feature = "std")]
 pub mod json;
+#[cfg(feature = "std")]
+pub mod manifest;
+#[cfg(feature = "std")]
 pub mod policy;
+#[cfg(feature = "std")]
+pub mod pseudonym;
+#[cfg(feature = "std")]
 pub mod redactor;
+#[cfg(feature = "std")]
+pub mod report;
+#[cfg(feature = "std")]
+pub mod rules;
+#[cfg(feature = "std")]
+pub mod sink;
+#[cfg(feature = "std")]
 pub mod stream;
-pub mod types;
+#[cfg(all(feature = "std", any(test, feature = "test-util")))]
+pub mod test_util;
+#[cfg(feature = "std")]
+pub mod vault;
 
 #[cfg(feature = "ner")]
 pub mod ner;
+
+#[cfg(feature = "xml")]
+pub mod xml;
@@ -0,0 +1,110 @@
+//! Re-identification manifest for de-identified documents
+//!
+//! [`Redactor::deidentify`](crate::redactor::Redactor::deidentify) replaces
+//! every PII value with a per-document pseudonym token and records each
+//! replacement in a [`Manifest`]. The manifest holds a digest of the
+//! original value – never the value itself – so it can be stored apart from
+//! the released document and consulted through a controlled process: hash a
+//! candidate value with the same policy hasher and look it up.
+//!
+//! Digests come from the policy's hasher: BLAKE3 under a random
+//! per-process key by default, so the manifest can't be reversed by
+//! enumerating candidates. To look values up after the process exits, set
+//! a stored key with `PolicyBuilder::with_hash_key`.
+
+use crate::types::PiiType;
+
+/// One replaced value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestEntry {
+    pub pii_type: PiiType,
+    /// UTF-8 byte offset in the original text
+    pub start: usize,
+    /// UTF-8 byte offset in the original text
+    pub end: usize,
+    /// Hex digest of the original value
+    pub original_hash: String,
+    /// Token that replaced it, e.g. `[REDACTED_EMAIL_1]`; repeats of a
+    /// value within a document share a token
+    pub replacement: String,
+}
+
+/// Replacements made by one `Redactor::deidentify` call
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    doc_id: String,
+    entries: Vec<ManifestEntry>,
+}
+
+impl Manifest {
+    pub(crate) fn new(doc_id: &str, entries: Vec<ManifestEntry>) -> Self {
+        Self {
+            doc_id: doc_id.to_string(),
+            entries,
+        }
+    }
+
+    /// The document the manifest belongs to
+    pub fn doc_id(&self) -> &str {
+        &self.doc_id
+    }
+
+    /// All replacements, sorted by position
+    pub fn entries(&self) -> &[ManifestEntry] {
+        &self.entries
+    }
+
+    /// Entries whose original value has the digest `original_hash`
+    pub fn lookup<'a>(&'a self, original_hash: &'a str) -> impl Iterator<Item = &'a ManifestEntry> {
+        self.entries
+            .iter()
+            .filter(move |e| e.original_hash == original_hash)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Pseudonym token for the `n`th distinct value of `pii_type` (from 1)
+pub(crate) fn pseudonym(pii_type: PiiType, n: usize) -> String {
+    let placeholder = pii_type.placeholder();
+    let base = placeholder.strip_suffix(']').unwrap_or(placeholder);
+    format!("{base}_{n}]")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pseudonym_extends_type_placeholder() {
+        assert_eq!(pseudonym(PiiType::Email, 1), "[REDACTED_EMAIL_1]");
+        assert_eq!(pseudonym(PiiType::IpAddressV4, 12), "[REDACTED_IPv4_12]");
+    }
+
+    #[test]
+    fn test_lookup_by_hash() {
+        let entry = |start, hash: &str| ManifestEntry {
+            pii_type: PiiType::Email,
+            start,
+            end: start + 3,
+            original_hash: hash.to_string(),
+            replacement: "[REDACTED_EMAIL_1]".to_string(),
+        };
+        let manifest = Manifest::new(
+            "doc-1",
+            vec![entry(0, "aa"), entry(10, "bb"), entry(20, "aa")],
+        );
+        assert_eq!(manifest.doc_id(), "doc-1");
+        assert_eq!(manifest.len(), 3);
+        let starts: Vec<usize> = manifest.lookup("aa").map(|e| e.start).collect();
+        assert_eq!(starts, vec![0, 20]);
+        assert_eq!(manifest.lookup("cc").count(), 0);
+        assert!(Manifest::default().is_empty());
+    }
+}
@@ -1,5 +1,16 @@
+use crate::detector::Confidence;
+use crate::detectors;
+use crate::encryption::Key;
+use crate::generalization::GeneralizationPolicy;
+use crate::pseudonym::PseudonymKey;
+use crate::rules::RuleSet;
 use crate::types::{PiiType, PiiTypeConfig};
+use sha2::{Digest, Sha256};
 use std::collections::{HashMap, HashSet};
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+use zeroize::Zeroize;
 
 /// Redaction mode – determines HOW PII is transformed
 #[derive(
@@ -11,12 +22,62 @@ pub enum RedactionMode {
     /// keeping structure like `███-██-████` for SSNs
     #[default]
     Mask,
-    /// Full replacement: replaces entire match with a placeholder string
+    /// Full replacement: replaces entire match with the type's placeholder
+    /// (`policy.placeholder_for`), e.g. `[REDACTED_EMAIL]`
     Replace,
-    /// Hash: replaces with Blake3 hash of the original value (hex-encoded, first 16 chars)
+    /// Hash: replaces with a keyed BLAKE3 hash of the original value
+    /// (hex-encoded, first 16 chars). Without `with_hash_key` the key is
+    /// random per process, so tokens only join within one process.
     Hash,
     /// Tokenize: replaces with sequential tokens `[[PII_0]]`, `[[PII_1]]`, etc.
     Tokenize,
+    /// Fixed-length mask: replaces every value with exactly this many `█`,
+    /// whatever its type or length. A length of 0 renders as a single `█`.
+    ///
+    /// `Mask` and `FormatPreserving` keep one mask character per character,
+    /// so the length of a value shows through: enough to tell a 4-digit PIN
+    /// from a 12-character password, or to narrow down a short name. A fixed
+    /// width leaks nothing about the value, but also drops its shape, so
+    /// readers can no longer tell an email from a card number by looking.
+    FixedLengthMask(usize),
+    /// Partial reveal for light-touch display: keeps the first `lead`
+    /// characters of each whitespace-separated word and the last
+    /// `keep_last` characters of the value, masking the rest. `John Doe` →
+    /// `J███ D██` with `lead: 1`; `4111 1111 1111 1111` →
+    /// `████ ████ ████ 1111` with `keep_last: 4`. Whitespace is kept and
+    /// does not count towards `keep_last`. Applies to every type alike,
+    /// without the structured masks of `Mask`.
+    PartialReveal {
+        #[serde(default)]
+        lead: usize,
+        #[serde(default)]
+        keep_last: usize,
+    },
+    /// Like `Mask`, but every masked letter or digit becomes `mask_char`
+    /// and punctuation, separators, and length are always kept:
+    /// `4111 1111 1111 1234` → `XXXX XXXX XXXX 1234`, `jane.doe@corp.com`
+    /// → `XXXX.XXX@XXXX.com`, and `10.0.0.1` → `XX.X.X.X` for types
+    /// `Mask` would blank out entirely
+    FormatPreserving {
+        #[serde(default = "default_mask_char")]
+        mask_char: char,
+    },
+    /// Remove the value entirely, leaving no trace of it in the output
+    Remove,
+    /// Reversible: replaces with `[ENC:<TYPE>:<hex>]`, encrypted under the
+    /// key set for the type with `with_encryption_key`. Holders of that key
+    /// can restore the value with `encryption::reveal`. Types without a key
+    /// fall back to their placeholder, as in `Replace`.
+    Encrypt,
+    /// Irreversible but joinable: replaces with `<TYPE>_<hmac>`, e.g.
+    /// `EMAIL_K7Q2M4XA`, where `<hmac>` is the first `len` base32
+    /// characters of an HMAC-SHA256 of the value under the key set with
+    /// `with_pseudonym_key`. Without a key, values fall back to their
+    /// placeholder, as in `Replace`.
+    Pseudonymize {
+        #[serde(default = "default_pseudonym_len")]
+        len: usize,
+    },
 }
 
 impl RedactionMode {
@@ -26,16 +87,227 @@ impl RedactionMode {
             Self::Replace => "replace",
             Self::Hash => "hash",
             Self::Tokenize => "tokenize",
+            Self::FixedLengthMask(_) => "fixed_length_mask",
+            Self::PartialReveal { .. } => "partial_reveal",
+            Self::FormatPreserving { .. } => "format_preserving",
+            Self::Remove => "remove",
+            Self::Encrypt => "encrypt",
+            Self::Pseudonymize { .. } => "pseudonymize",
         }
     }
 }
 
+fn default_mask_char() -> char {
+    'X'
+}
+
+fn default_pseudonym_len() -> usize {
+    8
+}
+
 impl std::fmt::Display for RedactionMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.as_str())
     }
 }
 
+/// How far timestamps are generalized when redacted in `Mask` mode.
+///
+/// Fields finer than the granularity are zeroed while the overall format is
+/// kept, e.g. `2024-01-15T13:42:07Z` → `2024-01-15T13:00:00Z` at `Hour`.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampGranularity {
+    /// Keep the date only (default)
+    #[default]
+    Day,
+    /// Keep the date and hour
+    Hour,
+    /// Keep the date, hour, and minute
+    Minute,
+}
+
+/// How `Mask` mode renders an email whose domain has no `.`, such as
+/// `john@localhost` or an intranet `john@mailserver`.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TldlessDomain {
+    /// Mask the whole domain: `john@localhost` → `████@█████████` (default)
+    #[default]
+    Mask,
+    /// Keep the host for diagnostics: `john@localhost` → `████@localhost`
+    KeepHost,
+}
+
+/// Which part of an email `Mask` mode hides
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailMaskTarget {
+    /// Mask the local part and the domain, keeping the TLD:
+    /// `john.doe@example.com` → `████.███@███████.com` (default)
+    #[default]
+    Both,
+    /// Mask only the local part, e.g. for studying sender domains:
+    /// `john.doe@example.com` → `████.███@example.com`
+    LocalOnly,
+    /// Mask only the domain, TLD included:
+    /// `john.doe@example.com` → `john.doe@███████████`
+    DomainOnly,
+}
+
+/// Which list decides when an allowlist and a blocklist term overlap,
+/// e.g. allowlisted `support@example.com` and blocklisted `example.com`.
+///
+/// A term that is on both lists is always masked: the blocklist wins ties
+/// under either setting, so a contradictory policy fails closed.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ListPrecedence {
+    /// Blocklist hits inside an allowlisted term are left alone, so the
+    /// protected term stays intact (default)
+    #[default]
+    AllowlistWins,
+    /// Blocklist hits are masked even inside an allowlisted term; the
+    /// allowlist still protects it from PII detectors
+    BlocklistWins,
+}
+
+/// Drops detections of `pii_type` that start within `window` bytes after
+/// `keyword`, e.g. a 9-digit "SSN" that is really an invoice number
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContextSuppressor {
+    pub pii_type: PiiType,
+    /// Matched ASCII case-insensitively, as a whole word
+    pub keyword: String,
+    /// Maximum bytes between the keyword's end and the detection's start
+    pub window: usize,
+}
+
+impl ContextSuppressor {
+    /// Whether a detection of `pii_type` starting at `start` in `text`
+    /// follows this suppressor's keyword closely enough to be dropped
+    pub fn suppresses(&self, pii_type: PiiType, text: &str, start: usize) -> bool {
+        let keyword = self.keyword.as_bytes();
+        if pii_type != self.pii_type || keyword.is_empty() || start > text.len() {
+            return false;
+        }
+        let bytes = text.as_bytes();
+        let from = start.saturating_sub(self.window + keyword.len());
+        (from..=start.saturating_sub(keyword.len())).any(|i| {
+            let end = i + keyword.len();
+            bytes[i..end].eq_ignore_ascii_case(keyword)
+                && (i == 0 || !bytes[i - 1].is_ascii_alphanumeric())
+                && (end == bytes.len() || !bytes[end].is_ascii_alphanumeric())
+        })
+    }
+}
+
+/// Digest function used by `RedactionMode::Hash`: value bytes in, digest out
+pub type HashFn = dyn Fn(&[u8]) -> Vec<u8> + Send + Sync;
+
+/// Key for the keyed BLAKE3 digest of `Hash` mode, zeroized on drop
+#[derive(Clone)]
+pub struct HashKey([u8; 32]);
+
+impl HashKey {
+    /// Use raw key bytes, e.g. fetched from a KMS
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Key used when the policy configures none: random, generated once
+    /// per process
+    fn process_default() -> &'static Self {
+        static KEY: OnceLock<HashKey> = OnceLock::new();
+        KEY.get_or_init(|| {
+            let mut bytes = [0; 32];
+            getrandom::fill(&mut bytes).expect("OS random source is available");
+            Self(bytes)
+        })
+    }
+
+    fn digest(&self, value: &[u8]) -> Vec<u8> {
+        blake3::keyed_hash(&self.0, value).as_bytes().to_vec()
+    }
+}
+
+impl From<[u8; 32]> for HashKey {
+    fn from(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl std::fmt::Debug for HashKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("HashKey(..)")
+    }
+}
+
+impl Drop for HashKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// User-supplied hasher (wrapped so the policy stays `Debug` + `Clone`)
+#[derive(Clone)]
+struct CustomHasher(Arc<HashFn>);
+
+impl std::fmt::Debug for CustomHasher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CustomHasher")
+    }
+}
+
+/// Per-value placeholder: matched text in, replacement out
+pub type PlaceholderFn = dyn Fn(&str) -> String + Send + Sync;
+
+/// User-supplied placeholder function (wrapped so the policy stays `Debug`)
+#[derive(Clone)]
+struct CustomPlaceholderFn(Arc<PlaceholderFn>);
+
+impl std::fmt::Debug for CustomPlaceholderFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CustomPlaceholderFn")
+    }
+}
+
+/// Placeholder function rendering a value as its type label plus the first
+/// 8 hex digits of its SHA-256, e.g. `[EMAIL_a1b2c3d4]`, so repeats of a
+/// value collapse to one token without any plaintext being kept.
+///
+/// The digest is unkeyed: anyone who can guess a value can confirm it by
+/// hashing it. Use `RedactionMode::Hash` with a hash key where that matters.
+pub fn hashed_placeholder(pii_type: PiiType) -> Arc<PlaceholderFn> {
+    let label = pii_type.label();
+    Arc::new(move |original: &str| {
+        let digest = Sha256::digest(original.as_bytes());
+        let hex: String = digest.iter().take(4).map(|b| format!("{b:02x}")).collect();
+        format!("[{label}_{hex}]")
+    })
+}
+
+/// Dynamic allowlist: return `true` to pass a detection through unredacted
+pub type AllowPredicate = dyn Fn(PiiType, &str) -> bool + Send + Sync;
+
+/// User-supplied allow predicate (wrapped so the policy stays `Debug`)
+#[derive(Clone)]
+struct CustomAllow(Arc<AllowPredicate>);
+
+impl std::fmt::Debug for CustomAllow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CustomAllow")
+    }
+}
+
 /// Redaction policy – defines WHAT to redact and HOW to redact it
 #[derive(Debug, Clone)]
 pub struct RedactionPolicy {
@@ -45,17 +317,120 @@ pub struct RedactionPolicy {
     /// Custom placeholder per PII type (overrides defaults)
     placeholder_map: HashMap<PiiType, String>,
 
+    /// Per-type placeholder functions, applied before `placeholder_map`.
+    /// Not part of `RedactionPolicyConfig`.
+    placeholder_fns: HashMap<PiiType, CustomPlaceholderFn>,
+
     /// Global redaction mode (default: Mask)
     mode: RedactionMode,
 
+    /// Per-type modes overriding `mode`
+    type_modes: HashMap<PiiType, RedactionMode>,
+
+    /// Per-type minimum confidence; weaker detections are not redacted
+    min_confidence: HashMap<PiiType, Confidence>,
+
+    /// Per-type maximum gap (bytes of whitespace/punctuation) across which
+    /// neighbouring detections of the type are merged into one
+    merge_adjacent: HashMap<PiiType, usize>,
+
+    /// Per-type cap on redactions per text; later detections are left as is
+    max_redactions: HashMap<PiiType, usize>,
+
     /// Allowlist: terms NEVER redacted (e.g., "Apple", "Paris")
     allowlist: Vec<String>,
 
+    /// Only protect allowlist matches that are whole words
+    allowlist_word_boundaries: bool,
+
     /// Blocklist: terms ALWAYS redacted (e.g., known employee names)
     blocklist: Vec<String>,
 
+    /// Maximum edit distance for fuzzy blocklist matches (0: exact only)
+    fuzzy_blocklist_distance: usize,
+
+    /// Match blocklist terms regardless of case
+    case_insensitive_blocklist: bool,
+
+    /// Which list decides where allowlist and blocklist terms overlap
+    list_precedence: ListPrecedence,
+
     /// Require validation for types that support it (e.g., Luhn check)
     strict_validation: bool,
+
+    /// Generalization level applied to timestamps in `Mask` mode
+    timestamp_granularity: TimestampGranularity,
+
+    /// Drop a leading UTF-8 byte order mark from redacted output
+    strip_bom: bool,
+
+    /// Keyword-and-window rules dropping detections in known non-PII
+    /// contexts. Not part of `RedactionPolicyConfig`.
+    context_suppressors: Vec<ContextSuppressor>,
+
+    /// Detect on a copy with `\r\n` / `\r` normalized to `\n`
+    normalize_newlines: bool,
+
+    /// Leave the first occurrence of each distinct value unredacted
+    keep_first_occurrence: bool,
+
+    /// Rendering of email domains without a TLD in `Mask` mode
+    tldless_domain: TldlessDomain,
+
+    /// Part of an email hidden in `Mask` mode
+    email_mask_target: EmailMaskTarget,
+
+    /// Emit `fully_redacted_sentinel` instead of the output when less than
+    /// this share of the input's visible characters survives (0: never)
+    fully_redacted_below: f64,
+
+    /// Stand-in for output that is (nearly) all redactions
+    fully_redacted_sentinel: String,
+
+    /// Window overlap for `Redactor::redact_io`, in bytes
+    stream_overlap: usize,
+
+    /// Ordered per-detection rules; unmatched detections use `mode`.
+    /// Not part of `RedactionPolicyConfig`.
+    rules: RuleSet,
+
+    /// Per-type generalization applied instead of redaction.
+    /// Not part of `RedactionPolicyConfig`.
+    generalization: GeneralizationPolicy,
+
+    /// Digest used by `Hash` mode (default: BLAKE3 keyed per process).
+    /// Not part of `RedactionPolicyConfig`.
+    hasher: Option<CustomHasher>,
+
+    /// Per-type keys used by `Encrypt` mode.
+    /// Not part of `RedactionPolicyConfig`.
+    encryption_keys: HashMap<PiiType, Key>,
+
+    /// HMAC key used by `Pseudonymize` mode.
+    /// Not part of `RedactionPolicyConfig`.
+    pseudonym_key: Option<PseudonymKey>,
+
+    /// Per-detection allow decision consulted after the static allowlist.
+    /// Not part of `RedactionPolicyConfig`.
+    allow_predicate: Option<CustomAllow>,
+}
+
+/// Serializes as its [`RedactionPolicyConfig`]; settings outside the config
+/// (rules, generalization, custom hashers, placeholder functions, keys, and
+/// predicates) are not
+/// serialized and come back at their defaults.
+#[cfg(feature = "serde")]
+impl serde::Serialize for RedactionPolicy {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RedactionPolicy::serialize(self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RedactionPolicy {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        RedactionPolicyConfig::deserialize(deserializer).map(|config| Self::from_config(&config))
+    }
 }
 
 /// Serializable representation of `RedactionPolicy`.
@@ -77,23 +452,114 @@ pub struct RedactionPolicyConfig {
     #[serde(default)]
     pub mode: RedactionMode,
 
+    /// Per-type modes overriding `mode`
+    #[serde(default)]
+    pub type_modes: HashMap<PiiTypeConfig, RedactionMode>,
+
+    /// Per-type minimum confidence
+    #[serde(default)]
+    pub min_confidence: HashMap<PiiTypeConfig, Confidence>,
+
+    /// Per-type maximum gap in bytes across which neighbouring detections
+    /// are merged (default: none)
+    #[serde(default)]
+    pub merge_adjacent: HashMap<PiiTypeConfig, usize>,
+
+    /// Per-type maximum number of detections redacted per text, counted in
+    /// document order (default: unlimited)
+    #[serde(default)]
+    pub max_redactions_per_type: HashMap<PiiTypeConfig, usize>,
+
     /// Terms that should never be redacted
     #[serde(default)]
     pub allowlist: Vec<String>,
 
+    /// Allowlist terms only match as whole words, so `cat` does not protect
+    /// the `cat` in `locate` (default: true)
+    #[serde(default = "default_allowlist_word_boundaries")]
+    pub allowlist_word_boundaries: bool,
+
     /// Terms that must always be redacted
     #[serde(default)]
     pub blocklist: Vec<String>,
 
+    /// Edit distance for fuzzy blocklist matching (default: 0, exact only)
+    #[serde(default)]
+    pub fuzzy_blocklist_distance: usize,
+
+    /// Match blocklist terms regardless of case (default: false)
+    #[serde(default)]
+    pub case_insensitive_blocklist: bool,
+
+    /// Allowlist or blocklist wins where their terms overlap (default: allowlist)
+    #[serde(default)]
+    pub list_precedence: ListPrecedence,
+
     /// Whether to require validation (e.g., Luhn check) before redacting
     #[serde(default = "default_strict_validation")]
     pub strict_validation: bool,
+
+    /// Generalization level for timestamps (default: day)
+    #[serde(default)]
+    pub timestamp_granularity: TimestampGranularity,
+
+    /// Drop a leading UTF-8 BOM from output (default: false)
+    #[serde(default)]
+    pub strip_bom: bool,
+
+    /// Normalize CR/CRLF line endings before detection (default: false)
+    #[serde(default)]
+    pub normalize_newlines: bool,
+
+    /// Show the first occurrence of each value, redact repeats (default: false)
+    #[serde(default)]
+    pub keep_first_occurrence: bool,
+
+    /// Mask or keep TLD-less email domains like `localhost` (default: mask)
+    #[serde(default)]
+    pub tldless_domain: TldlessDomain,
+
+    /// Mask an email's local part, domain, or both (default: both)
+    #[serde(default)]
+    pub email_mask_target: EmailMaskTarget,
+
+    /// Replace output keeping less than this share of visible input
+    /// characters with `fully_redacted_sentinel` (default: 0, never)
+    #[serde(default)]
+    pub fully_redacted_below: f64,
+
+    /// Sentinel for fully redacted output (default: `[DOCUMENT FULLY REDACTED]`)
+    #[serde(default = "default_fully_redacted_sentinel")]
+    pub fully_redacted_sentinel: String,
+
+    /// Window overlap in bytes when redacting readers; at least the longest
+    /// expected PII span (default: 256)
+    #[serde(default = "default_stream_overlap")]
+    pub stream_overlap: usize,
+}
+
+/// Default window overlap for stream redaction, in bytes
+pub const DEFAULT_STREAM_OVERLAP: usize = 256;
+
+fn default_stream_overlap() -> usize {
+    DEFAULT_STREAM_OVERLAP
+}
+
+/// Default stand-in for output with (nearly) nothing left but redactions
+pub const FULLY_REDACTED_SENTINEL: &str = "[DOCUMENT FULLY REDACTED]";
+
+fn default_fully_redacted_sentinel() -> String {
+    FULLY_REDACTED_SENTINEL.to_string()
 }
 
 fn default_strict_validation() -> bool {
     true
 }
 
+fn default_allowlist_word_boundaries() -> bool {
+    true
+}
+
 impl Default for RedactionPolicy {
     fn default() -> Self {
         let mut enabled = HashSet::new();
@@ -111,10 +577,35 @@ impl Default for RedactionPolicy {
         Self {
             enabled_types: enabled,
             placeholder_map: HashMap::new(),
+            placeholder_fns: HashMap::new(),
             mode: RedactionMode::default(),
+            type_modes: HashMap::new(),
+            min_confidence: HashMap::new(),
+            merge_adjacent: HashMap::new(),
+            max_redactions: HashMap::new(),
             allowlist: Vec::new(),
+            allowlist_word_boundaries: true,
             blocklist: Vec::new(),
+            fuzzy_blocklist_distance: 0,
+            case_insensitive_blocklist: false,
+            list_precedence: ListPrecedence::default(),
             strict_validation: true, // Fail-safe default
+            timestamp_granularity: TimestampGranularity::default(),
+            strip_bom: false,
+            context_suppressors: Vec::new(),
+            normalize_newlines: false,
+            keep_first_occurrence: false,
+            tldless_domain: TldlessDomain::default(),
+            email_mask_target: EmailMaskTarget::default(),
+            fully_redacted_below: 0.0,
+            fully_redacted_sentinel: default_fully_redacted_sentinel(),
+            stream_overlap: DEFAULT_STREAM_OVERLAP,
+            rules: RuleSet::new(),
+            generalization: GeneralizationPolicy::new(),
+            hasher: None,
+            encryption_keys: HashMap::new(),
+            pseudonym_key: None,
+            allow_predicate: None,
         }
     }
 }
@@ -130,19 +621,91 @@ impl RedactionPolicy {
         self.mode
     }
 
+    /// Mode applied to `pii_type`: its override if set, else the global mode
+    pub fn mode_for(&self, pii_type: PiiType) -> RedactionMode {
+        self.type_mode(pii_type).unwrap_or(self.mode)
+    }
+
+    /// Per-type override of the global mode, if any
+    pub fn type_mode(&self, pii_type: PiiType) -> Option<RedactionMode> {
+        self.type_modes.get(&pii_type).copied()
+    }
+
+    /// Minimum confidence required to redact `pii_type`, if set
+    pub fn min_confidence(&self, pii_type: PiiType) -> Option<Confidence> {
+        self.min_confidence.get(&pii_type).copied()
+    }
+
+    /// Whether a detection of `pii_type` at `confidence` meets the type's
+    /// minimum (always true when none is set)
+    pub fn meets_min_confidence(&self, pii_type: PiiType, confidence: Confidence) -> bool {
+        self.min_confidence(pii_type)
+            .is_none_or(|min| confidence >= min)
+    }
+
+    /// Largest gap across which neighbouring `pii_type` detections are
+    /// merged, if merging is on for the type
+    pub fn merge_adjacent_gap(&self, pii_type: PiiType) -> Option<usize> {
+        self.merge_adjacent.get(&pii_type).copied()
+    }
+
+    /// Most `pii_type` detections redacted per text, if the type is capped
+    pub fn max_redactions(&self, pii_type: PiiType) -> Option<usize> {
+        self.max_redactions.get(&pii_type).copied()
+    }
+
     /// Serialize this policy to a `RedactionPolicyConfig`.
     pub fn serialize(&self) -> RedactionPolicyConfig {
         RedactionPolicyConfig {
-            enabled_types: self.enabled_types.iter().map(|t| (*t).into()).collect(),
+            enabled_types: {
+                // Sorted, so serialized policies are stable and diffable
+                let mut types: Vec<PiiTypeConfig> =
+                    self.enabled_types.iter().map(|t| (*t).into()).collect();
+                types.sort_by_key(|t| format!("{t:?}"));
+                types
+            },
             placeholders: self
                 .placeholder_map
                 .iter()
                 .map(|(k, v)| ((*k).into(), v.clone()))
                 .collect(),
             mode: self.mode,
+            type_modes: self
+                .type_modes
+                .iter()
+                .map(|(k, v)| ((*k).into(), *v))
+                .collect(),
+            min_confidence: self
+                .min_confidence
+                .iter()
+                .map(|(k, v)| ((*k).into(), *v))
+                .collect(),
+            merge_adjacent: self
+                .merge_adjacent
+                .iter()
+                .map(|(k, v)| ((*k).into(), *v))
+                .collect(),
+            max_redactions_per_type: self
+                .max_redactions
+                .iter()
+                .map(|(k, v)| ((*k).into(), *v))
+                .collect(),
             allowlist: self.allowlist.clone(),
+            allowlist_word_boundaries: self.allowlist_word_boundaries,
             blocklist: self.blocklist.clone(),
+            fuzzy_blocklist_distance: self.fuzzy_blocklist_distance,
+            case_insensitive_blocklist: self.case_insensitive_blocklist,
+            list_precedence: self.list_precedence,
             strict_validation: self.strict_validation,
+            timestamp_granularity: self.timestamp_granularity,
+            strip_bom: self.strip_bom,
+            normalize_newlines: self.normalize_newlines,
+            keep_first_occurrence: self.keep_first_occurrence,
+            tldless_domain: self.tldless_domain,
+            email_mask_target: self.email_mask_target,
+            fully_redacted_below: self.fully_redacted_below,
+            fully_redacted_sentinel: self.fully_redacted_sentinel.clone(),
+            stream_overlap: self.stream_overlap,
         }
     }
 
@@ -163,13 +726,143 @@ impl RedactionPolicy {
             .filter_map(|(k, v)| k.to_pii_type().map(|p| (p, v.clone())))
             .collect();
 
+        let type_modes: HashMap<PiiType, RedactionMode> = config
+            .type_modes
+            .iter()
+            .filter_map(|(k, v)| k.to_pii_type().map(|p| (p, *v)))
+            .collect();
+
+        let min_confidence: HashMap<PiiType, Confidence> = config
+            .min_confidence
+            .iter()
+            .filter_map(|(k, v)| k.to_pii_type().map(|p| (p, *v)))
+            .collect();
+
+        let merge_adjacent: HashMap<PiiType, usize> = config
+            .merge_adjacent
+            .iter()
+            .filter_map(|(k, v)| k.to_pii_type().map(|p| (p, *v)))
+            .collect();
+
+        let max_redactions: HashMap<PiiType, usize> = config
+            .max_redactions_per_type
+            .iter()
+            .filter_map(|(k, v)| k.to_pii_type().map(|p| (p, *v)))
+            .collect();
+
         Self {
             enabled_types,
             placeholder_map,
+            placeholder_fns: HashMap::new(),
             mode: config.mode,
-            allowlist: config.allowlist.clone(),
-            blocklist: config.blocklist.clone(),
+            type_modes,
+            min_confidence,
+            merge_adjacent,
+            max_redactions,
+            allowlist: dedup_terms(config.allowlist.iter().cloned()),
+            allowlist_word_boundaries: config.allowlist_word_boundaries,
+            blocklist: dedup_terms(config.blocklist.iter().cloned()),
+            fuzzy_blocklist_distance: config.fuzzy_blocklist_distance,
+            case_insensitive_blocklist: config.case_insensitive_blocklist,
+            list_precedence: config.list_precedence,
             strict_validation: config.strict_validation,
+            timestamp_granularity: config.timestamp_granularity,
+            strip_bom: config.strip_bom,
+            context_suppressors: Vec::new(),
+            normalize_newlines: config.normalize_newlines,
+            keep_first_occurrence: config.keep_first_occurrence,
+            tldless_domain: config.tldless_domain,
+            email_mask_target: config.email_mask_target,
+            fully_redacted_below: config.fully_redacted_below,
+            fully_redacted_sentinel: config.fully_redacted_sentinel.clone(),
+            stream_overlap: config.stream_overlap,
+            rules: RuleSet::new(),
+            generalization: GeneralizationPolicy::new(),
+            hasher: None,
+            encryption_keys: HashMap::new(),
+            pseudonym_key: None,
+            allow_predicate: None,
+        }
+    }
+
+    /// Layer `other` on top of this policy, e.g. `gdpr()` plus a team's
+    /// extra types and blocklist terms.
+    ///
+    /// Precedence:
+    /// - Enabled types, the allowlist, and the blocklist are unioned. Terms
+    ///   keep their order, this policy's first, without duplicates. A type
+    ///   enabled here stays enabled even if `other` disables it.
+    /// - Per-type settings (placeholders and placeholder functions, modes,
+    ///   minimum confidence, adjacent-merge gaps, redaction limits,
+    ///   generalization, encryption keys) are overlaid:
+    ///   `other` wins for a type both set.
+    /// - `strict_validation`, `case_insensitive_blocklist`, and
+    ///   `allowlist_word_boundaries` are ORed, and the larger
+    ///   `fuzzy_blocklist_distance` and `fully_redacted_below` apply, so
+    ///   merging never weakens them.
+    /// - `other`'s rules are evaluated before this policy's.
+    /// - The hasher, pseudonym key, and allow predicate are `other`'s if
+    ///   set, else this policy's. Every other setting (global mode, list
+    ///   precedence, email and timestamp rendering, sentinel, stream
+    ///   overlap, ...) is `other`'s.
+    pub fn merge(&self, other: &RedactionPolicy) -> RedactionPolicy {
+        fn overlay<V: Clone>(
+            base: &HashMap<PiiType, V>,
+            top: &HashMap<PiiType, V>,
+        ) -> HashMap<PiiType, V> {
+            let mut merged = base.clone();
+            merged.extend(top.iter().map(|(t, v)| (*t, v.clone())));
+            merged
+        }
+
+        Self {
+            enabled_types: self
+                .enabled_types
+                .union(&other.enabled_types)
+                .copied()
+                .collect(),
+            placeholder_map: overlay(&self.placeholder_map, &other.placeholder_map),
+            placeholder_fns: overlay(&self.placeholder_fns, &other.placeholder_fns),
+            type_modes: overlay(&self.type_modes, &other.type_modes),
+            min_confidence: overlay(&self.min_confidence, &other.min_confidence),
+            merge_adjacent: overlay(&self.merge_adjacent, &other.merge_adjacent),
+            max_redactions: overlay(&self.max_redactions, &other.max_redactions),
+            allowlist: dedup_terms(self.allowlist.iter().chain(&other.allowlist).cloned()),
+            allowlist_word_boundaries: self.allowlist_word_boundaries
+                || other.allowlist_word_boundaries,
+            blocklist: dedup_terms(self.blocklist.iter().chain(&other.blocklist).cloned()),
+            fuzzy_blocklist_distance: self
+                .fuzzy_blocklist_distance
+                .max(other.fuzzy_blocklist_distance),
+            case_insensitive_blocklist: self.case_insensitive_blocklist
+                || other.case_insensitive_blocklist,
+            strict_validation: self.strict_validation || other.strict_validation,
+            fully_redacted_below: self.fully_redacted_below.max(other.fully_redacted_below),
+            context_suppressors: dedup_suppressors(
+                self.context_suppressors
+                    .iter()
+                    .chain(&other.context_suppressors)
+                    .cloned(),
+            ),
+            rules: other
+                .rules
+                .rules()
+                .iter()
+                .chain(self.rules.rules())
+                .cloned()
+                .fold(RuleSet::new(), RuleSet::rule),
+            generalization: self.generalization.overlay(&other.generalization),
+            hasher: other.hasher.clone().or_else(|| self.hasher.clone()),
+            encryption_keys: overlay(&self.encryption_keys, &other.encryption_keys),
+            pseudonym_key: other
+                .pseudonym_key
+                .clone()
+                .or_else(|| self.pseudonym_key.clone()),
+            allow_predicate: other
+                .allow_predicate
+                .clone()
+                .or_else(|| self.allow_predicate.clone()),
+            ..other.clone()
         }
     }
 
@@ -178,6 +871,11 @@ impl RedactionPolicy {
         self.enabled_types.contains(&pii_type)
     }
 
+    /// All enabled PII types, in no particular order
+    pub fn enabled_types(&self) -> impl Iterator<Item = PiiType> + '_ {
+        self.enabled_types.iter().copied()
+    }
+
     /// Get redaction placeholder for a PII type
     pub fn placeholder_for(&self, pii_type: PiiType) -> &str {
         self.placeholder_map
@@ -191,32 +889,317 @@ impl RedactionPolicy {
         self.placeholder_map.get(&pii_type).map(String::as_str)
     }
 
+    /// Render `original` with the placeholder function set for `pii_type`,
+    /// if any
+    pub fn dynamic_placeholder(&self, pii_type: PiiType, original: &str) -> Option<String> {
+        self.placeholder_fns
+            .get(&pii_type)
+            .map(|CustomPlaceholderFn(render)| render(original))
+    }
+
+    /// All configured custom placeholders (non-empty ones only)
+    pub fn custom_placeholders(&self) -> impl Iterator<Item = &str> {
+        self.placeholder_map
+            .values()
+            .map(String::as_str)
+            .filter(|p| !p.is_empty())
+    }
+
     /// Check if text is in allowlist (should NEVER be redacted)
     pub fn is_allowed(&self, text: &str) -> bool {
         self.allowlist.iter().any(|term| text.contains(term))
     }
 
+    /// Whether the allow predicate passes this detected value through
+    pub fn is_allowed_by_predicate(&self, pii_type: PiiType, value: &str) -> bool {
+        self.allow_predicate
+            .as_ref()
+            .is_some_and(|CustomAllow(allow)| allow(pii_type, value))
+    }
+
     /// Check if text is in blocklist (should ALWAYS be redacted)
     pub fn is_blocked(&self, text: &str) -> bool {
-        self.blocklist.iter().any(|term| text.contains(term))
+        if self.case_insensitive_blocklist {
+            let text = text.to_lowercase();
+            self.blocklist
+                .iter()
+                .any(|term| text.contains(&term.to_lowercase()))
+        } else {
+            self.blocklist.iter().any(|term| text.contains(term))
+        }
+    }
+
+    /// Whether `term` is itself a blocklist entry (compared case-insensitively
+    /// when the blocklist is), as opposed to merely containing one
+    pub fn is_blocklist_term(&self, term: &str) -> bool {
+        if self.case_insensitive_blocklist {
+            let term = term.to_lowercase();
+            self.blocklist.iter().any(|t| t.to_lowercase() == term)
+        } else {
+            self.blocklist.iter().any(|t| t == term)
+        }
+    }
+
+    /// Whether a context suppressor drops a `pii_type` detection starting
+    /// at `start` in `text`
+    pub fn is_suppressed_by_context(&self, pii_type: PiiType, text: &str, start: usize) -> bool {
+        self.context_suppressors
+            .iter()
+            .any(|s| s.suppresses(pii_type, text, start))
+    }
+
+    /// Configured context suppressors, in the order they were added
+    pub fn context_suppressors(&self) -> &[ContextSuppressor] {
+        &self.context_suppressors
+    }
+
+    /// Check the policy for contradictions, returning the first found:
+    ///
+    /// - strict validation with no enabled types, which redacts nothing
+    /// - a term on both the allowlist and the blocklist (the blocklist wins,
+    ///   so the allowlist entry is dead)
+    /// - a custom placeholder that the built-in detector of an enabled type
+    ///   matches, so redacted output would be flagged again on re-redaction
+    pub fn validate(&self) -> Result<(), PolicyError> {
+        if self.strict_validation && self.enabled_types.is_empty() {
+            return Err(PolicyError::NoEnabledTypes);
+        }
+        if let Some(term) = self
+            .allowlist
+            .iter()
+            .find(|term| self.is_blocklist_term(term))
+        {
+            return Err(PolicyError::ListOverlap { term: term.clone() });
+        }
+        self.check_placeholders()
+    }
+
+    /// No custom placeholder matches the built-in detector of an enabled type
+    fn check_placeholders(&self) -> Result<(), PolicyError> {
+        let mut placeholders: Vec<(&PiiType, &String)> = self.placeholder_map.iter().collect();
+        placeholders.sort_by_key(|(pii_type, _)| format!("{pii_type:?}"));
+        let mut enabled: Vec<PiiType> = self.enabled_types.iter().copied().collect();
+        enabled.sort_by_key(|pii_type| format!("{pii_type:?}"));
+
+        for (&placeholder_for, placeholder) in placeholders {
+            for &detected_as in &enabled {
+                let Some(detector) = detectors::default_detector(detected_as) else {
+                    continue;
+                };
+                if !detector
+                    .detect_with_validation(placeholder, self.strict_validation)
+                    .is_empty()
+                {
+                    return Err(PolicyError::PlaceholderMatchesDetector {
+                        placeholder_for,
+                        detected_as,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether to require validation (e.g., Luhn check) before redacting
+    pub fn requires_validation(&self) -> bool {
+        self.strict_validation
+    }
+
+    /// Generalization level applied to timestamps
+    pub fn timestamp_granularity(&self) -> TimestampGranularity {
+        self.timestamp_granularity
+    }
+
+    /// Whether a leading UTF-8 BOM is dropped from output
+    pub fn strip_bom(&self) -> bool {
+        self.strip_bom
+    }
+
+    /// Whether detectors see CR/CRLF line endings normalized to `\n`.
+    /// Spans are mapped back, so output keeps the original line endings.
+    pub fn normalize_newlines(&self) -> bool {
+        self.normalize_newlines
+    }
+
+    /// Whether the first occurrence of each distinct value is kept visible
+    pub fn keep_first_occurrence(&self) -> bool {
+        self.keep_first_occurrence
+    }
+
+    /// How email domains without a TLD are rendered in `Mask` mode
+    pub fn tldless_domain(&self) -> TldlessDomain {
+        self.tldless_domain
+    }
+
+    /// Which part of an email `Mask` mode hides
+    pub fn email_mask_target(&self) -> EmailMaskTarget {
+        self.email_mask_target
+    }
+
+    /// Share of visible input characters below which output is replaced
+    /// by the fully redacted sentinel; 0 if disabled
+    pub fn fully_redacted_below(&self) -> f64 {
+        self.fully_redacted_below
+    }
+
+    /// Output emitted for an input that is (nearly) all PII
+    pub fn fully_redacted_sentinel(&self) -> &str {
+        &self.fully_redacted_sentinel
+    }
+
+    /// Bytes of overlap between windows when redacting a reader
+    pub fn stream_overlap(&self) -> usize {
+        self.stream_overlap
+    }
+
+    /// Digest `value` with the configured hasher. The default is BLAKE3
+    /// under a random per-process key, so low-entropy values like SSNs
+    /// can't be recovered by hashing every candidate.
+    pub fn hash(&self, value: &[u8]) -> Vec<u8> {
+        match &self.hasher {
+            Some(CustomHasher(hasher)) => hasher(value),
+            None => HashKey::process_default().digest(value),
+        }
+    }
+
+    /// Key `Encrypt` mode uses for `pii_type`, if one is set
+    pub fn encryption_key(&self, pii_type: PiiType) -> Option<&Key> {
+        self.encryption_keys.get(&pii_type)
+    }
+
+    /// Key `Pseudonymize` mode uses, if one is set
+    pub fn pseudonym_key(&self) -> Option<&PseudonymKey> {
+        self.pseudonym_key.as_ref()
+    }
+
+    /// Declarative rules evaluated per detection before `mode`
+    pub fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
+
+    /// Quasi-identifier generalizations applied instead of `mode`
+    pub fn generalization(&self) -> &GeneralizationPolicy {
+        &self.generalization
+    }
+
+    /// Allowlist terms, de-duplicated, in first-seen order. Spans matching
+    /// any of them are never redacted.
+    pub fn allowlist_terms(&self) -> &[String] {
+        &self.allowlist
+    }
+
+    /// Number of distinct allowlist terms
+    pub fn allowlist_len(&self) -> usize {
+        self.allowlist.len()
+    }
+
+    /// Whether allowlist terms only match as whole words
+    pub fn allowlist_word_boundaries(&self) -> bool {
+        self.allowlist_word_boundaries
+    }
+
+    /// Blocklist terms, de-duplicated, in first-seen order. Every occurrence
+    /// is redacted, whether or not a detector fires.
+    pub fn blocklist_terms(&self) -> &[String] {
+        &self.blocklist
+    }
+
+    /// Number of distinct blocklist terms
+    pub fn blocklist_len(&self) -> usize {
+        self.blocklist.len()
+    }
+
+    /// Maximum edit distance for fuzzy blocklist matches; 0 if disabled
+    pub fn fuzzy_blocklist_distance(&self) -> usize {
+        self.fuzzy_blocklist_distance
     }
 
-    /// Whether to require validation (e.g., Luhn check) before redacting
-    pub fn requires_validation(&self) -> bool {
-        self.strict_validation
+    /// Whether blocklist terms match regardless of case
+    pub fn case_insensitive_blocklist(&self) -> bool {
+        self.case_insensitive_blocklist
     }
 
-    /// Get allowlist terms (for span detection in redactor)
-    pub fn allowlist_terms(&self) -> &[String] {
-        &self.allowlist
+    /// Which list decides where allowlist and blocklist terms overlap
+    pub fn list_precedence(&self) -> ListPrecedence {
+        self.list_precedence
     }
+}
 
-    /// Get blocklist terms (for exact-match redaction)
-    pub fn blocklist_terms(&self) -> &[String] {
-        &self.blocklist
+/// Terms with repeats dropped, keeping first-seen order
+fn dedup_terms(terms: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    terms
+        .into_iter()
+        .filter(|term| seen.insert(term.clone()))
+        .collect()
+}
+
+/// Suppressors with repeats dropped, keeping first-seen order
+fn dedup_suppressors(
+    suppressors: impl IntoIterator<Item = ContextSuppressor>,
+) -> Vec<ContextSuppressor> {
+    let mut seen = HashSet::new();
+    suppressors
+        .into_iter()
+        .filter(|s| seen.insert(s.clone()))
+        .collect()
+}
+
+/// Terms of a list file: one per line, trimmed, without blank lines or
+/// `#` comment lines
+fn read_term_file(path: &Path) -> io::Result<Vec<String>> {
+    Ok(std::fs::read_to_string(path)?
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(String::from)
+        .collect())
+}
+
+/// A policy configuration problem found by [`RedactionPolicy::validate`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolicyError {
+    /// Strict validation is on but no type is enabled, so nothing is ever
+    /// redacted
+    NoEnabledTypes,
+    /// A term is on both lists; the blocklist wins, so the allowlist entry
+    /// has no effect
+    ListOverlap {
+        /// The allowlist entry, as configured
+        term: String,
+    },
+    /// A custom placeholder would itself be detected as PII, so redacted
+    /// output would be flagged again (or corrupted) on re-redaction
+    PlaceholderMatchesDetector {
+        /// Type the placeholder is configured for
+        placeholder_for: PiiType,
+        /// Enabled type whose detector matches the placeholder
+        detected_as: PiiType,
+    },
+}
+
+impl std::fmt::Display for PolicyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoEnabledTypes => {
+                write!(f, "strict validation is on but no PII types are enabled")
+            }
+            Self::ListOverlap { term } => {
+                write!(f, "{term:?} is on both the allowlist and the blocklist")
+            }
+            Self::PlaceholderMatchesDetector {
+                placeholder_for,
+                detected_as,
+            } => write!(
+                f,
+                "placeholder for {placeholder_for:?} is itself detected as {detected_as:?}"
+            ),
+        }
     }
 }
 
+impl std::error::Error for PolicyError {}
+
 /// Builder for RedactionPolicy – enables fluent configuration
 #[derive(Default)]
 pub struct PolicyBuilder {
@@ -234,6 +1217,12 @@ impl PolicyBuilder {
         self
     }
 
+    /// Disable every type, to enable an explicit set from scratch
+    pub fn disable_all(mut self) -> Self {
+        self.policy.enabled_types.clear();
+        self
+    }
+
     pub fn with_placeholder(mut self, pii_type: PiiType, placeholder: &str) -> Self {
         self.policy
             .placeholder_map
@@ -241,18 +1230,130 @@ impl PolicyBuilder {
         self
     }
 
+    /// Render each `pii_type` detection with `render`, called with the
+    /// matched text (before it is zeroized), e.g. [`hashed_placeholder`].
+    /// Takes precedence over a static placeholder for the type.
+    pub fn with_placeholder_fn(mut self, pii_type: PiiType, render: Arc<PlaceholderFn>) -> Self {
+        self.policy
+            .placeholder_fns
+            .insert(pii_type, CustomPlaceholderFn(render));
+        self
+    }
+
     pub fn with_mode(mut self, mode: RedactionMode) -> Self {
         self.policy.mode = mode;
         self
     }
 
+    /// Use `mode` for `pii_type` instead of the global mode
+    pub fn with_mode_for(mut self, pii_type: PiiType, mode: RedactionMode) -> Self {
+        self.policy.type_modes.insert(pii_type, mode);
+        self
+    }
+
+    /// Redact `pii_type` only at `confidence` or above, e.g. `High` for a
+    /// loose heuristic that should only fire when validated
+    pub fn min_confidence(mut self, pii_type: PiiType, confidence: Confidence) -> Self {
+        self.policy.min_confidence.insert(pii_type, confidence);
+        self
+    }
+
+    /// Merge `pii_type` detections separated by at most `max_gap` bytes of
+    /// whitespace or punctuation into one span covering both and the gap,
+    /// e.g. a phone number a detector reports as `555 123` and `4567`
+    pub fn merge_adjacent(mut self, pii_type: PiiType, max_gap: usize) -> Self {
+        self.policy.merge_adjacent.insert(pii_type, max_gap);
+        self
+    }
+
+    /// Redact at most `max` `pii_type` detections per text, the first ones
+    /// in document order, and leave the rest as they are (e.g. to sample
+    /// what a detector matches). Counted after overlap resolution and the
+    /// allowlist.
+    pub fn max_redactions_per_type(mut self, pii_type: PiiType, max: usize) -> Self {
+        self.policy.max_redactions.insert(pii_type, max);
+        self
+    }
+
+    /// Replace the allowlist. Repeated terms are kept once.
     pub fn with_allowlist(mut self, terms: Vec<&str>) -> Self {
-        self.policy.allowlist = terms.into_iter().map(String::from).collect();
+        self.policy.allowlist = dedup_terms(terms.into_iter().map(String::from));
         self
     }
 
+    /// Replace the blocklist. Repeated terms are kept once.
     pub fn with_blocklist(mut self, terms: Vec<&str>) -> Self {
-        self.policy.blocklist = terms.into_iter().map(String::from).collect();
+        self.policy.blocklist = dedup_terms(terms.into_iter().map(String::from));
+        self
+    }
+
+    /// Append the terms in the file at `path` to the allowlist: one term
+    /// per line, trimmed, skipping blank lines and `#` comments. Terms
+    /// already listed are kept once.
+    pub fn with_allowlist_file(mut self, path: impl AsRef<Path>) -> io::Result<Self> {
+        let terms = read_term_file(path.as_ref())?;
+        let current = std::mem::take(&mut self.policy.allowlist);
+        self.policy.allowlist = dedup_terms(current.into_iter().chain(terms));
+        Ok(self)
+    }
+
+    /// Append the terms in the file at `path` to the blocklist, read as
+    /// for [`with_allowlist_file`](Self::with_allowlist_file)
+    pub fn with_blocklist_file(mut self, path: impl AsRef<Path>) -> io::Result<Self> {
+        let terms = read_term_file(path.as_ref())?;
+        let current = std::mem::take(&mut self.policy.blocklist);
+        self.policy.blocklist = dedup_terms(current.into_iter().chain(terms));
+        Ok(self)
+    }
+
+    /// Also match single-word blocklist terms against words within
+    /// `max_distance` edits (insertions, deletions, substitutions, adjacent
+    /// transpositions), so `Jonh` hits `John` at distance 1. Noisy: only
+    /// terms of at least four characters are matched fuzzily, and only
+    /// against words whose length is within `max_distance` of the term's.
+    /// 0 (the default) disables fuzzy matching.
+    pub fn fuzzy_blocklist(mut self, max_distance: usize) -> Self {
+        self.policy.fuzzy_blocklist_distance = max_distance;
+        self
+    }
+
+    /// Match blocklist terms regardless of case (Unicode-aware), so
+    /// `CONFIDENTIAL` also hits `confidential`. The matched text is masked
+    /// at its own width, whatever the term's.
+    pub fn case_insensitive_blocklist(mut self, enabled: bool) -> Self {
+        self.policy.case_insensitive_blocklist = enabled;
+        self
+    }
+
+    /// Only let allowlist terms protect whole-word matches (default), so
+    /// `cat` does not shield PII containing `locate`. Turn off to protect
+    /// every occurrence, e.g. for terms that are fragments of identifiers.
+    pub fn allowlist_word_boundaries(mut self, enabled: bool) -> Self {
+        self.policy.allowlist_word_boundaries = enabled;
+        self
+    }
+
+    /// Choose whether an allowlisted term protects blocklist hits inside it
+    /// (`AllowlistWins`, the default) or not (`BlocklistWins`)
+    pub fn list_precedence(mut self, precedence: ListPrecedence) -> Self {
+        self.policy.list_precedence = precedence;
+        self
+    }
+
+    /// Drop `pii_type` detections starting within `window` bytes after
+    /// `keyword` (a whole word, ASCII case-insensitive), e.g. `Invoice`
+    /// before a number shaped like an SSN
+    pub fn with_context_suppressor(
+        mut self,
+        pii_type: PiiType,
+        keyword: &str,
+        window: usize,
+    ) -> Self {
+        self.policy.context_suppressors.push(ContextSuppressor {
+            pii_type,
+            keyword: keyword.to_string(),
+            window,
+        });
         self
     }
 
@@ -261,9 +1362,136 @@ impl PolicyBuilder {
         self
     }
 
+    pub fn timestamp_granularity(mut self, granularity: TimestampGranularity) -> Self {
+        self.policy.timestamp_granularity = granularity;
+        self
+    }
+
+    pub fn strip_bom(mut self, enabled: bool) -> Self {
+        self.policy.strip_bom = enabled;
+        self
+    }
+
+    pub fn normalize_newlines(mut self, enabled: bool) -> Self {
+        self.policy.normalize_newlines = enabled;
+        self
+    }
+
+    /// Leave the first occurrence of each distinct PII value intact and
+    /// redact every later one (e.g. so support can verify a notification).
+    /// A kept occurrence is not masked by the blocklist either.
+    pub fn keep_first_occurrence(mut self, enabled: bool) -> Self {
+        self.policy.keep_first_occurrence = enabled;
+        self
+    }
+
+    /// Mask (default) or keep email domains without a TLD, e.g. the host
+    /// in `john@mailserver`
+    pub fn tldless_domain(mut self, handling: TldlessDomain) -> Self {
+        self.policy.tldless_domain = handling;
+        self
+    }
+
+    /// Mask an email's local part, domain, or both (default) in `Mask` mode
+    pub fn email_mask_target(mut self, target: EmailMaskTarget) -> Self {
+        self.policy.email_mask_target = target;
+        self
+    }
+
+    /// Emit the fully redacted sentinel instead of output that keeps less
+    /// than `min_kept_ratio` (0.0–1.0) of the input's non-whitespace
+    /// characters, so all-placeholder output isn't mistaken for corruption
+    pub fn fully_redacted_below(mut self, min_kept_ratio: f64) -> Self {
+        self.policy.fully_redacted_below = min_kept_ratio;
+        self
+    }
+
+    /// Use `sentinel` in place of `[DOCUMENT FULLY REDACTED]`
+    pub fn fully_redacted_sentinel(mut self, sentinel: &str) -> Self {
+        self.policy.fully_redacted_sentinel = sentinel.to_string();
+        self
+    }
+
+    /// Overlap windows by `bytes` when redacting a reader. Must be at least
+    /// the longest PII span expected (e.g. long secrets or addresses), or
+    /// values straddling a window boundary may be missed.
+    pub fn stream_overlap(mut self, bytes: usize) -> Self {
+        self.policy.stream_overlap = bytes;
+        self
+    }
+
+    /// Evaluate `rules` per detection; the first matching rule decides
+    /// the action, unmatched detections use the global mode
+    pub fn with_rules(mut self, rules: RuleSet) -> Self {
+        self.policy.rules = rules;
+        self
+    }
+
+    /// Generalize values of the configured types (e.g. ZIP → ZIP3)
+    /// instead of redacting them. Rules still take precedence.
+    pub fn with_generalization(mut self, generalization: GeneralizationPolicy) -> Self {
+        self.policy.generalization = generalization;
+        self
+    }
+
+    /// Use a custom digest for `Hash` mode, e.g. SHA-3 or an HMAC keyed
+    /// from a KMS. The first 8 bytes of the digest form the token.
+    pub fn with_hasher(
+        mut self,
+        hasher: impl Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static,
+    ) -> Self {
+        self.policy.hasher = Some(CustomHasher(Arc::new(hasher)));
+        self
+    }
+
+    /// Use keyed BLAKE3 under `key` for `Hash` mode. Unlike the default
+    /// per-process key, tokens and manifest digests stay stable across
+    /// restarts and hosts sharing the key.
+    pub fn with_hash_key(self, key: impl Into<HashKey>) -> Self {
+        let key = key.into();
+        self.with_hasher(move |value| key.digest(value))
+    }
+
+    /// Encrypt values of `pii_type` under `key` in `Encrypt` mode. Give each
+    /// type its own key to reveal types selectively per viewer role.
+    pub fn with_encryption_key(mut self, pii_type: PiiType, key: Key) -> Self {
+        self.policy.encryption_keys.insert(pii_type, key);
+        self
+    }
+
+    /// Key the HMAC behind `Pseudonymize` mode. Keep it stable to keep
+    /// pseudonyms joinable across runs; rotate it to unlink them.
+    pub fn with_pseudonym_key(mut self, key: PseudonymKey) -> Self {
+        self.policy.pseudonym_key = Some(key);
+        self
+    }
+
+    /// Decide per detection whether to pass it through unredacted, e.g.
+    /// allow every email on the company domain
+    pub fn with_allow_predicate(
+        mut self,
+        allow: impl Fn(PiiType, &str) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.policy.allow_predicate = Some(CustomAllow(Arc::new(allow)));
+        self
+    }
+
     pub fn build(self) -> RedactionPolicy {
         self.policy
     }
+
+    /// Same as [`try_build`](Self::try_build), which checks placeholders
+    /// along with every other conflict
+    #[deprecated(note = "use `try_build`")]
+    pub fn build_checked(self) -> Result<RedactionPolicy, PolicyError> {
+        self.try_build()
+    }
+
+    /// Build the policy if [`RedactionPolicy::validate`] finds no conflict
+    pub fn try_build(self) -> Result<RedactionPolicy, PolicyError> {
+        self.policy.validate()?;
+        Ok(self.policy)
+    }
 }
 
 /// Predefined compliance profiles (GDPR, HIPAA, PCI-DSS)
@@ -275,6 +1503,7 @@ impl RedactionPolicy {
             .enable(PiiType::PhoneNumber)
             .enable(PiiType::IpAddressV4)
             .enable(PiiType::IpAddressV6)
+            .enable(PiiType::MacAddress)
             .disable(PiiType::Ssn) // US-specific
             .build()
     }
@@ -285,6 +1514,8 @@ impl RedactionPolicy {
             .enable(PiiType::Ssn)
             .enable(PiiType::PhoneNumber)
             .enable(PiiType::IpAddressV4)
+            .enable(PiiType::MedicareBeneficiaryId)
+            .enable(PiiType::DateOfBirth)
             .with_allowlist(vec!["hospital", "clinic", "medical center"])
             .build()
     }
@@ -293,7 +1524,8 @@ impl RedactionPolicy {
     pub fn pci_dss() -> Self {
         PolicyBuilder::default()
             .enable(PiiType::CreditCard)
-            .strict_validation(true) // MUST validate with Luhn
+            .enable(PiiType::BankAccountRouting)
+            .strict_validation(true) // MUST validate with Luhn and ABA checksums
             .build()
     }
 }
@@ -335,12 +1567,21 @@ mod tests {
         let policy = RedactionPolicy::gdpr();
         assert!(!policy.is_enabled(PiiType::Ssn));
         assert!(policy.is_enabled(PiiType::IpAddressV4));
+        assert!(policy.is_enabled(PiiType::MacAddress));
+    }
+
+    #[test]
+    fn test_hipaa_profile_enables_mbi() {
+        assert!(RedactionPolicy::hipaa().is_enabled(PiiType::MedicareBeneficiaryId));
+        assert!(!RedactionPolicy::default().is_enabled(PiiType::MedicareBeneficiaryId));
     }
 
     #[test]
     fn test_pci_requires_validation() {
         let policy = RedactionPolicy::pci_dss();
         assert!(policy.requires_validation());
+        assert!(policy.is_enabled(PiiType::BankAccountRouting));
+        assert!(!RedactionPolicy::default().is_enabled(PiiType::BankAccountRouting));
     }
 
     #[test]
@@ -492,6 +1733,214 @@ mod tests {
         assert_eq!(policy.mode(), RedactionMode::Hash);
     }
 
+    #[test]
+    fn test_type_modes_override_global_and_round_trip() {
+        let policy = PolicyBuilder::default()
+            .with_mode(RedactionMode::Hash)
+            .with_mode_for(PiiType::Email, RedactionMode::Remove)
+            .build();
+        assert_eq!(policy.mode_for(PiiType::Email), RedactionMode::Remove);
+        assert_eq!(policy.mode_for(PiiType::Ssn), RedactionMode::Hash);
+        assert_eq!(policy.type_mode(PiiType::Ssn), None);
+
+        let json = serde_json::to_string(&policy.serialize()).unwrap();
+        assert!(json.contains("\"type_modes\":{\"email\":\"remove\"}"));
+        let config: RedactionPolicyConfig = serde_json::from_str(&json).unwrap();
+        let restored = RedactionPolicy::from_config(&config);
+        assert_eq!(restored.mode_for(PiiType::Email), RedactionMode::Remove);
+        assert_eq!(restored.mode_for(PiiType::Ssn), RedactionMode::Hash);
+    }
+
+    #[test]
+    fn test_format_preserving_mode_round_trip() {
+        let mode = RedactionMode::FormatPreserving { mask_char: '*' };
+        let json = serde_json::to_string(&mode).unwrap();
+        assert_eq!(json, r#"{"format_preserving":{"mask_char":"*"}}"#);
+        assert_eq!(serde_json::from_str::<RedactionMode>(&json).unwrap(), mode);
+        assert_eq!(
+            serde_json::from_str::<RedactionMode>(r#"{"format_preserving":{}}"#).unwrap(),
+            RedactionMode::FormatPreserving { mask_char: 'X' }
+        );
+        assert_eq!(mode.to_string(), "format_preserving");
+    }
+
+    #[test]
+    fn test_context_suppressor_window() {
+        let suppressor = ContextSuppressor {
+            pii_type: PiiType::Ssn,
+            keyword: "Invoice".to_string(),
+            window: 3,
+        };
+        let text = "INVOICE: 123-45-6789";
+        assert!(suppressor.suppresses(PiiType::Ssn, text, 9));
+        assert!(!suppressor.suppresses(PiiType::Ssn, "INVOICE:   123", 11));
+        assert!(!suppressor.suppresses(PiiType::PhoneNumber, text, 9));
+        assert!(!suppressor.suppresses(PiiType::Ssn, "preinvoice 123", 11));
+
+        let merged = PolicyBuilder::default()
+            .with_context_suppressor(PiiType::Ssn, "Invoice", 3)
+            .build()
+            .merge(
+                &PolicyBuilder::default()
+                    .with_context_suppressor(PiiType::Ssn, "Invoice", 3)
+                    .build(),
+            );
+        assert_eq!(merged.context_suppressors(), [suppressor]);
+    }
+
+    #[test]
+    fn test_lists_are_deduplicated() {
+        let policy = PolicyBuilder::default()
+            .with_allowlist(vec!["Paris", "Paris"])
+            .with_blocklist(vec!["Falcon", "Hawk", "Falcon"])
+            .build();
+        assert_eq!(policy.allowlist_terms(), ["Paris"]);
+        assert_eq!(policy.allowlist_len(), 1);
+        assert_eq!(policy.blocklist_terms(), ["Falcon", "Hawk"]);
+        assert_eq!(policy.blocklist_len(), 2);
+
+        let mut config = policy.serialize();
+        config.allowlist.push("Paris".to_string());
+        let restored = RedactionPolicy::from_config(&config);
+        assert_eq!(restored.allowlist_len(), 1);
+    }
+
+    #[test]
+    fn test_merge_layers_profiles() {
+        let team = PolicyBuilder::default()
+            .enable(PiiType::Ssn)
+            .with_allowlist(vec!["Acme", "Paris"])
+            .with_blocklist(vec!["Project Falcon", "Falcon-7"])
+            .with_placeholder(PiiType::Ssn, "<ssn>")
+            .strict_validation(false)
+            .build();
+        let base = RedactionPolicy::builder()
+            .disable(PiiType::Ssn)
+            .with_allowlist(vec!["Paris", "Berlin"])
+            .with_blocklist(vec!["Falcon-7"])
+            .with_placeholder(PiiType::Ssn, "[SSN]")
+            .with_placeholder(PiiType::Email, "[EMAIL]")
+            .strict_validation(true)
+            .build();
+        let gdpr = RedactionPolicy::gdpr();
+        assert!(!gdpr.is_enabled(PiiType::Ssn));
+
+        let merged = gdpr.merge(&base).merge(&team);
+        assert!(merged.is_enabled(PiiType::IpAddressV4));
+        assert!(merged.is_enabled(PiiType::IpAddressV6));
+        assert!(merged.is_enabled(PiiType::Ssn));
+        assert_eq!(merged.allowlist_terms(), ["Paris", "Berlin", "Acme"]);
+        assert_eq!(merged.blocklist_terms(), ["Falcon-7", "Project Falcon"]);
+        // The later policy's placeholder wins; others carry over
+        assert_eq!(merged.custom_placeholder(PiiType::Ssn), Some("<ssn>"));
+        assert_eq!(merged.custom_placeholder(PiiType::Email), Some("[EMAIL]"));
+        // Validation stays on when either side requires it
+        assert!(merged.requires_validation());
+    }
+
+    #[test]
+    fn test_merge_precedence_for_other_settings() {
+        let strict = PolicyBuilder::default()
+            .fuzzy_blocklist(2)
+            .case_insensitive_blocklist(true)
+            .with_mode(RedactionMode::Hash)
+            .with_mode_for(PiiType::Email, RedactionMode::Remove)
+            .build();
+        let overlay = PolicyBuilder::default()
+            .with_mode(RedactionMode::Replace)
+            .min_confidence(PiiType::Email, Confidence::High)
+            .build();
+
+        let merged = strict.merge(&overlay);
+        assert_eq!(merged.fuzzy_blocklist_distance(), 2);
+        assert!(merged.case_insensitive_blocklist());
+        assert_eq!(merged.mode(), RedactionMode::Replace);
+        assert_eq!(merged.mode_for(PiiType::Email), RedactionMode::Remove);
+        assert_eq!(
+            merged.min_confidence(PiiType::Email),
+            Some(Confidence::High)
+        );
+    }
+
+    #[test]
+    fn test_min_confidence_round_trip() {
+        let policy = PolicyBuilder::default()
+            .min_confidence(PiiType::PhysicalAddress, Confidence::High)
+            .build();
+        assert!(!policy.meets_min_confidence(PiiType::PhysicalAddress, Confidence::Medium));
+        assert!(policy.meets_min_confidence(PiiType::PhysicalAddress, Confidence::High));
+        assert!(policy.meets_min_confidence(PiiType::Email, Confidence::Low));
+
+        let json = serde_json::to_string(&policy.serialize()).unwrap();
+        assert!(json.contains("\"min_confidence\":{\"physical_address\":\"high\"}"));
+        let config: RedactionPolicyConfig = serde_json::from_str(&json).unwrap();
+        let restored = RedactionPolicy::from_config(&config);
+        assert_eq!(
+            restored.min_confidence(PiiType::PhysicalAddress),
+            Some(Confidence::High)
+        );
+        assert_eq!(restored.min_confidence(PiiType::Email), None);
+    }
+
+    #[test]
+    fn test_list_files_skip_comments_and_blank_lines() {
+        let dir = std::env::temp_dir().join(format!("auvura_lists_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let allow = dir.join("allow.txt");
+        let block = dir.join("block.txt");
+        std::fs::write(
+            &allow,
+            "# brands\nApple\n\n   Paris  \r\n  # cities\nApple\n",
+        )
+        .unwrap();
+        std::fs::write(&block, "\nProject Falcon\n#Project Owl\n\t\nAlice Smith\n").unwrap();
+
+        let policy = PolicyBuilder::default()
+            .with_allowlist(vec!["Acme"])
+            .with_allowlist_file(&allow)
+            .unwrap()
+            .with_blocklist_file(&block)
+            .unwrap()
+            .build();
+        assert_eq!(policy.allowlist_terms(), ["Acme", "Apple", "Paris"]);
+        assert_eq!(policy.blocklist_terms(), ["Project Falcon", "Alice Smith"]);
+
+        let missing = PolicyBuilder::default().with_blocklist_file(dir.join("missing.txt"));
+        assert_eq!(missing.err().unwrap().kind(), io::ErrorKind::NotFound);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_merge_adjacent_round_trip() {
+        let policy = PolicyBuilder::default()
+            .merge_adjacent(PiiType::PhoneNumber, 2)
+            .build();
+        assert_eq!(policy.merge_adjacent_gap(PiiType::PhoneNumber), Some(2));
+        assert_eq!(policy.merge_adjacent_gap(PiiType::Email), None);
+
+        let json = serde_json::to_string(&policy.serialize()).unwrap();
+        assert!(json.contains("\"merge_adjacent\":{\"phone_number\":2}"));
+        let config: RedactionPolicyConfig = serde_json::from_str(&json).unwrap();
+        let restored = RedactionPolicy::from_config(&config);
+        assert_eq!(restored.merge_adjacent_gap(PiiType::PhoneNumber), Some(2));
+    }
+
+    #[test]
+    fn test_max_redactions_per_type_round_trip() {
+        let policy = PolicyBuilder::default()
+            .max_redactions_per_type(PiiType::Email, 1)
+            .build();
+        assert_eq!(policy.max_redactions(PiiType::Email), Some(1));
+        assert_eq!(policy.max_redactions(PiiType::Ssn), None);
+
+        let json = serde_json::to_string(&policy.serialize()).unwrap();
+        assert!(json.contains("\"max_redactions_per_type\":{\"email\":1}"));
+        let config: RedactionPolicyConfig = serde_json::from_str(&json).unwrap();
+        let restored = RedactionPolicy::from_config(&config);
+        assert_eq!(restored.max_redactions(PiiType::Email), Some(1));
+    }
+
     #[test]
     fn test_redaction_mode_round_trip() {
         let policy = PolicyBuilder::default()
@@ -504,6 +1953,117 @@ mod tests {
         assert_eq!(restored.mode(), RedactionMode::Tokenize);
     }
 
+    #[test]
+    fn test_timestamp_granularity_round_trip() {
+        let policy = PolicyBuilder::default()
+            .timestamp_granularity(TimestampGranularity::Hour)
+            .build();
+        let json = serde_json::to_string(&policy.serialize()).unwrap();
+        assert!(json.contains("\"timestamp_granularity\":\"hour\""));
+
+        let config: RedactionPolicyConfig = serde_json::from_str(&json).unwrap();
+        let restored = RedactionPolicy::from_config(&config);
+        assert_eq!(restored.timestamp_granularity(), TimestampGranularity::Hour);
+    }
+
+    #[test]
+    fn test_tldless_domain_round_trip() {
+        let policy = PolicyBuilder::default()
+            .tldless_domain(TldlessDomain::KeepHost)
+            .build();
+        let json = serde_json::to_string(&policy.serialize()).unwrap();
+        assert!(json.contains("\"tldless_domain\":\"keep_host\""));
+
+        let config: RedactionPolicyConfig = serde_json::from_str(&json).unwrap();
+        let restored = RedactionPolicy::from_config(&config);
+        assert_eq!(restored.tldless_domain(), TldlessDomain::KeepHost);
+        assert_eq!(
+            RedactionPolicy::default().tldless_domain(),
+            TldlessDomain::Mask
+        );
+    }
+
+    #[test]
+    fn test_list_precedence_round_trip() {
+        let policy = PolicyBuilder::default()
+            .list_precedence(ListPrecedence::BlocklistWins)
+            .build();
+        let json = serde_json::to_string(&policy.serialize()).unwrap();
+        assert!(json.contains("\"list_precedence\":\"blocklist_wins\""));
+
+        let config: RedactionPolicyConfig = serde_json::from_str(&json).unwrap();
+        let restored = RedactionPolicy::from_config(&config);
+        assert_eq!(restored.list_precedence(), ListPrecedence::BlocklistWins);
+        assert_eq!(
+            RedactionPolicy::default().list_precedence(),
+            ListPrecedence::AllowlistWins
+        );
+    }
+
+    #[test]
+    fn test_case_insensitive_blocklist_round_trip() {
+        let policy = PolicyBuilder::default()
+            .with_blocklist(vec!["CONFIDENTIAL"])
+            .case_insensitive_blocklist(true)
+            .build();
+        assert!(policy.is_blocked("strictly confidential"));
+        assert!(!RedactionPolicy::from_config(&RedactionPolicyConfig {
+            case_insensitive_blocklist: false,
+            ..policy.serialize()
+        })
+        .is_blocked("strictly confidential"));
+
+        let json = serde_json::to_string(&policy.serialize()).unwrap();
+        let config: RedactionPolicyConfig = serde_json::from_str(&json).unwrap();
+        assert!(RedactionPolicy::from_config(&config).case_insensitive_blocklist());
+        assert!(!RedactionPolicy::default().case_insensitive_blocklist());
+    }
+
+    #[test]
+    fn test_stream_overlap_round_trip() {
+        let policy = PolicyBuilder::default().stream_overlap(1024).build();
+        let json = serde_json::to_string(&policy.serialize()).unwrap();
+        let config: RedactionPolicyConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(RedactionPolicy::from_config(&config).stream_overlap(), 1024);
+        assert_eq!(RedactionPolicy::default().stream_overlap(), 256);
+    }
+
+    #[test]
+    fn test_fully_redacted_sentinel_round_trip() {
+        let policy = PolicyBuilder::default()
+            .fully_redacted_below(0.25)
+            .fully_redacted_sentinel("<redacted>")
+            .build();
+        let json = serde_json::to_string(&policy.serialize()).unwrap();
+        let config: RedactionPolicyConfig = serde_json::from_str(&json).unwrap();
+        let restored = RedactionPolicy::from_config(&config);
+        assert_eq!(restored.fully_redacted_below(), 0.25);
+        assert_eq!(restored.fully_redacted_sentinel(), "<redacted>");
+
+        let config: RedactionPolicyConfig = serde_json::from_str("{}").unwrap();
+        let policy = RedactionPolicy::from_config(&config);
+        assert_eq!(policy.fully_redacted_below(), 0.0);
+        assert_eq!(policy.stream_overlap(), DEFAULT_STREAM_OVERLAP);
+        assert_eq!(policy.fully_redacted_sentinel(), FULLY_REDACTED_SENTINEL);
+    }
+
+    #[test]
+    fn test_email_mask_target_round_trip() {
+        let policy = PolicyBuilder::default()
+            .email_mask_target(EmailMaskTarget::DomainOnly)
+            .build();
+        let json = serde_json::to_string(&policy.serialize()).unwrap();
+        assert!(json.contains("\"email_mask_target\":\"domain_only\""));
+
+        let config: RedactionPolicyConfig = serde_json::from_str(&json).unwrap();
+        let restored = RedactionPolicy::from_config(&config);
+        assert_eq!(restored.email_mask_target(), EmailMaskTarget::DomainOnly);
+        assert_eq!(
+            RedactionPolicy::default().email_mask_target(),
+            EmailMaskTarget::Both
+        );
+    }
+
     #[test]
     fn test_redaction_mode_serde() {
         let modes = vec![
@@ -511,6 +2071,22 @@ mod tests {
             (RedactionMode::Replace, "\"replace\""),
             (RedactionMode::Hash, "\"hash\""),
             (RedactionMode::Tokenize, "\"tokenize\""),
+            (
+                RedactionMode::FixedLengthMask(8),
+                "{\"fixed_length_mask\":8}",
+            ),
+            (
+                RedactionMode::PartialReveal {
+                    lead: 2,
+                    keep_last: 0,
+                },
+                "{\"partial_reveal\":{\"lead\":2,\"keep_last\":0}}",
+            ),
+            (RedactionMode::Remove, "\"remove\""),
+            (
+                RedactionMode::Pseudonymize { len: 12 },
+                "{\"pseudonymize\":{\"len\":12}}",
+            ),
         ];
 
         for (mode, expected_json) in modes {
@@ -521,4 +2097,109 @@ mod tests {
             assert_eq!(parsed, mode);
         }
     }
+
+    #[test]
+    fn test_try_build_rejects_placeholder_matching_detector() {
+        let result = RedactionPolicy::builder()
+            .with_placeholder(PiiType::Email, "555-1234")
+            .try_build();
+        assert_eq!(
+            result.unwrap_err(),
+            PolicyError::PlaceholderMatchesDetector {
+                placeholder_for: PiiType::Email,
+                detected_as: PiiType::PhoneNumber,
+            }
+        );
+    }
+
+    #[test]
+    fn test_try_build_ignores_disabled_types() {
+        let result = RedactionPolicy::builder()
+            .disable(PiiType::PhoneNumber)
+            .with_placeholder(PiiType::Email, "555-1234")
+            .try_build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_try_build_accepts_plain_placeholders() {
+        let policy = RedactionPolicy::builder()
+            .with_placeholder(PiiType::Email, "<email>")
+            .with_placeholder(PiiType::PhoneNumber, "[PHONE]")
+            .try_build()
+            .unwrap();
+        assert_eq!(policy.placeholder_for(PiiType::Email), "<email>");
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_build_checked_runs_every_check() {
+        let result = RedactionPolicy::builder().disable_all().build_checked();
+        assert_eq!(result.unwrap_err(), PolicyError::NoEnabledTypes);
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_enabled_set_when_strict() {
+        let result = RedactionPolicy::builder().disable_all().try_build();
+        assert_eq!(result.unwrap_err(), PolicyError::NoEnabledTypes);
+
+        let lax = RedactionPolicy::builder()
+            .disable_all()
+            .strict_validation(false)
+            .build();
+        assert_eq!(lax.validate(), Ok(()));
+        assert_eq!(RedactionPolicy::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_rejects_term_on_both_lists() {
+        let result = RedactionPolicy::builder()
+            .with_allowlist(vec!["Acme", "Globex"])
+            .with_blocklist(vec!["globex"])
+            .case_insensitive_blocklist(true)
+            .try_build();
+        assert_eq!(
+            result.unwrap_err(),
+            PolicyError::ListOverlap {
+                term: "Globex".into()
+            }
+        );
+
+        // Case-sensitive blocklist: "globex" doesn't block "Globex"
+        let policy = RedactionPolicy::builder()
+            .with_allowlist(vec!["Globex"])
+            .with_blocklist(vec!["globex"])
+            .build();
+        assert_eq!(policy.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_rejects_placeholder_matching_detector() {
+        let policy = RedactionPolicy::builder()
+            .with_placeholder(PiiType::Email, "555-1234")
+            .build();
+        assert_eq!(
+            policy.validate(),
+            Err(PolicyError::PlaceholderMatchesDetector {
+                placeholder_for: PiiType::Email,
+                detected_as: PiiType::PhoneNumber,
+            })
+        );
+        assert!(RedactionPolicy::builder()
+            .with_placeholder(PiiType::Email, "<email>")
+            .try_build()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_policy_error_display() {
+        let err = PolicyError::PlaceholderMatchesDetector {
+            placeholder_for: PiiType::Email,
+            detected_as: PiiType::PhoneNumber,
+        };
+        assert_eq!(
+            err.to_string(),
+            "placeholder for Email is itself detected as PhoneNumber"
+        );
+    }
 }
@@ -1,11 +1,55 @@
 use crate::{
     audit::{AuditEvent, AuditLogger, NoopAuditLogger},
-    detector::{Detection, MultiDetector, PiiDetector},
-    policy::{RedactionMode, RedactionPolicy},
+    detector::{Detection, DetectorProfile, InvalidSpanHandler, MultiDetector, PiiDetector},
+    detectors::{self, timestamp, DetectorRegistry},
+    encryption,
+    generalization::Generalization,
+    manifest::{self, Manifest, ManifestEntry},
+    policy::{
+        EmailMaskTarget, ListPrecedence, PolicyBuilder, RedactionMode, RedactionPolicy,
+        TldlessDomain,
+    },
+    pseudonym,
+    report::{distinct_key, Disposition, DistinctKey, RedactionReport, ReportEntry},
+    rules::RuleAction,
+    sink::{RedactionEvent, RedactionSink},
+    stream::{WindowConfig, Windower},
     types::PiiType,
+    vault::TokenVault,
 };
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
+use regex::Regex;
 use std::borrow::Cow;
-use std::sync::Arc;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::io::{self, Read, Write};
+use std::ops::Range;
+use std::sync::{Arc, OnceLock};
+use zeroize::Zeroize;
+
+/// Bytes of context rescanned on each side of an edit by
+/// [`Redactor::redact_incremental`]; longer than any built-in pattern's
+/// typical match and than the keyword window of context-gated detectors
+pub const INCREMENTAL_MARGIN: usize = 128;
+
+/// `start..end` widened by [`INCREMENTAL_MARGIN`], then out to the nearest
+/// whitespace (or the text's ends) so no token is cut
+fn incremental_window(text: &str, start: usize, end: usize) -> (usize, usize) {
+    let mut from = start.saturating_sub(INCREMENTAL_MARGIN);
+    while !text.is_char_boundary(from) {
+        from -= 1;
+    }
+    let from = text[..from].rfind(char::is_whitespace).map_or(0, |i| {
+        i + text[i..].chars().next().map_or(0, char::len_utf8)
+    });
+    let mut to = (end + INCREMENTAL_MARGIN).min(text.len());
+    while !text.is_char_boundary(to) {
+        to += 1;
+    }
+    let to = text[to..]
+        .find(char::is_whitespace)
+        .map_or(text.len(), |i| to + i);
+    (from, to)
+}
 
 /// Helper enum to track what kind of redaction to apply at a span
 enum RedactionKind<'a> {
@@ -13,11 +57,140 @@ enum RedactionKind<'a> {
     Blocklist,
 }
 
+/// Spans selected for redaction, in original-text coordinates
+#[derive(Default)]
+struct RedactionPlan {
+    /// Enabled, allowlist-filtered, overlap-resolved PII detections
+    detections: Vec<Detection>,
+    /// Blocklist hits not already covered by a PII detection (sorted, disjoint)
+    blocklist_spans: Vec<(usize, usize)>,
+    /// Candidates passed through unredacted, for the report
+    skipped: Vec<ReportEntry>,
+}
+
+impl RedactionPlan {
+    fn is_empty(&self) -> bool {
+        self.detections.is_empty() && self.blocklist_spans.is_empty()
+    }
+}
+
+/// Output of [`Redactor::redact_window`]
+pub(crate) struct StreamWindow {
+    /// Redacted text for the committed part of the window
+    pub(crate) output: String,
+    /// End (exclusive) of the committed part in window coordinates
+    pub(crate) cut: usize,
+    /// Number of PII detections in the committed part
+    pub(crate) detections: usize,
+}
+
+/// Report accumulated over the windows of a stream
+#[derive(Default)]
+pub(crate) struct StreamReport {
+    /// Stream offset of the current window's first byte
+    pub(crate) offset: usize,
+    entries: Vec<ReportEntry>,
+    blocklist_spans: Vec<(usize, usize)>,
+    distinct: HashSet<DistinctKey>,
+}
+
+impl StreamReport {
+    pub(crate) fn into_report(self) -> RedactionReport {
+        RedactionReport::new(self.entries)
+            .with_distinct_keys(&self.distinct)
+            .with_blocklist_spans(&self.blocklist_spans)
+    }
+}
+
 /// Core redaction engine – orchestrates policy, detection, and redaction
+///
+/// `Redactor` is `Send + Sync`: detectors are required to be thread-safe and
+/// redaction only takes `&self`, so one instance can serve many threads.
+/// Wrap it in [`SharedRedactor`] to hand cheap clones to request handlers.
+///
+/// Everything expensive – detector regexes, the anchor automaton, blocklist
+/// matchers – is compiled when the redactor is built, never per call, and
+/// there is no interior mutability, so concurrent calls don't contend.
 pub struct Redactor {
     detector: MultiDetector,
     policy: RedactionPolicy,
     audit_logger: Arc<dyn AuditLogger>,
+    sink: Option<Arc<dyn RedactionSink>>,
+    blocklist: BlocklistMatcher,
+}
+
+/// The policy's exact blocklist terms, compiled once per redactor.
+///
+/// One Aho-Corasick automaton finds every term in a single pass, ASCII
+/// case-insensitively when the blocklist is case-insensitive. Unicode case
+/// folding goes further, so a case-insensitive blocklist also keeps a
+/// pattern per term for what the automaton can't fold: non-ASCII terms
+/// always, ASCII terms only in the rare text with a character that folds
+/// to an ASCII letter.
+#[derive(Debug, Clone)]
+struct BlocklistMatcher {
+    /// Every term, or in a case-insensitive blocklist every ASCII term
+    automaton: Option<AhoCorasick>,
+    /// Case-insensitive blocklists only: each term's pattern, flagged
+    /// whether the term is ASCII (and so also in the automaton)
+    folded: Vec<(bool, Regex)>,
+}
+
+/// The only non-ASCII characters Unicode simple case folding maps to ASCII
+/// letters: KELVIN SIGN to `k` and LONG S to `s`
+const FOLDS_TO_ASCII: [char; 2] = ['\u{212A}', '\u{17F}'];
+
+impl BlocklistMatcher {
+    fn new(policy: &RedactionPolicy) -> Self {
+        let terms = policy.blocklist_terms();
+        let case_insensitive = policy.case_insensitive_blocklist();
+        let automaton_terms: Vec<&str> = terms
+            .iter()
+            .map(String::as_str)
+            .filter(|term| !case_insensitive || term.is_ascii())
+            .collect();
+        let automaton = (!automaton_terms.is_empty()).then(|| {
+            AhoCorasickBuilder::new()
+                .match_kind(MatchKind::Standard)
+                .ascii_case_insensitive(case_insensitive)
+                .build(&automaton_terms)
+                .expect("blocklist terms are valid Aho-Corasick patterns")
+        });
+        let folded = if case_insensitive {
+            terms
+                .iter()
+                .map(|term| {
+                    let pattern = Regex::new(&format!("(?i){}", regex::escape(term)))
+                        .expect("escaped blocklist term is a valid pattern");
+                    (term.is_ascii(), pattern)
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        Self { automaton, folded }
+    }
+
+    /// Byte ranges of every term occurrence in `text`, overlapping ones
+    /// included, in no particular order. Case folding can change byte
+    /// lengths, so ranges are the matched text, not the term.
+    fn find(&self, text: &str) -> Vec<(usize, usize)> {
+        let unicode_folds = !self.folded.is_empty() && text.contains(FOLDS_TO_ASCII);
+        let mut hits = Vec::new();
+        if let Some(automaton) = self.automaton.as_ref().filter(|_| !unicode_folds) {
+            hits.extend(
+                automaton
+                    .find_overlapping_iter(text)
+                    .map(|m| (m.start(), m.end())),
+            );
+        }
+        for (ascii, pattern) in &self.folded {
+            if !ascii || unicode_folds {
+                hits.extend(pattern.find_iter(text).map(|m| (m.start(), m.end())));
+            }
+        }
+        hits
+    }
 }
 
 /// Check if a match at `start..end` in `text` is at a word boundary.
@@ -29,16 +202,229 @@ fn is_word_boundary_match(text: &str, start: usize, end: usize) -> bool {
     before_ok && after_ok
 }
 
+/// Shortest blocklist term matched fuzzily; shorter terms only match exactly
+const FUZZY_MIN_TERM_CHARS: usize = 4;
+
+/// Spans of words within `max_distance` edits of a single-word term
+fn fuzzy_blocklist_matches(
+    text: &str,
+    terms: &[String],
+    max_distance: usize,
+) -> Vec<(usize, usize)> {
+    let terms: Vec<Vec<char>> = terms
+        .iter()
+        .filter(|t| t.chars().all(char::is_alphanumeric))
+        .map(|t| t.chars().collect::<Vec<char>>())
+        .filter(|t| t.len() >= FUZZY_MIN_TERM_CHARS)
+        .collect();
+    if terms.is_empty() {
+        return Vec::new();
+    }
+
+    let mut spans = Vec::new();
+    let mut words = text.char_indices().peekable();
+    while let Some((start, c)) = words.next() {
+        if !c.is_alphanumeric() {
+            continue;
+        }
+        let mut word = vec![c];
+        let mut end = start + c.len_utf8();
+        while let Some(&(i, c)) = words.peek() {
+            if !c.is_alphanumeric() {
+                break;
+            }
+            word.push(c);
+            end = i + c.len_utf8();
+            words.next();
+        }
+        if terms.iter().any(|term| {
+            term.len().abs_diff(word.len()) <= max_distance
+                && edit_distance(term, &word) <= max_distance
+        }) {
+            spans.push((start, end));
+        }
+    }
+    spans
+}
+
+/// Optimal string alignment distance: Levenshtein plus adjacent transpositions
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    // Three rolling rows: i-2, i-1, i
+    let mut prev2: Vec<usize> = vec![0; b.len() + 1];
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut row: Vec<usize> = vec![0; b.len() + 1];
+    for i in 1..=a.len() {
+        row[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = (prev[j] + 1).min(row[j - 1] + 1).min(prev[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(prev2[j - 2] + 1);
+            }
+        }
+        std::mem::swap(&mut prev2, &mut prev);
+        std::mem::swap(&mut prev, &mut row);
+    }
+    prev[b.len()]
+}
+
+/// Normalize `\r\n` and lone `\r` to `\n`.
+///
+/// Returns the normalized text plus a map from each normalized byte offset
+/// (and the end offset) to the corresponding offset in `text`.
+fn normalize_newlines(text: &str) -> (String, Vec<usize>) {
+    let bytes = text.as_bytes();
+    let mut normalized = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\r' {
+            normalized.push('\n');
+            offsets.push(i);
+            i += if bytes.get(i + 1) == Some(&b'\n') {
+                2
+            } else {
+                1
+            };
+            continue;
+        }
+        // Copy up to the next CR in one go (CR is ASCII, so this stays on
+        // char boundaries)
+        let next = bytes[i..]
+            .iter()
+            .position(|&b| b == b'\r')
+            .map_or(bytes.len(), |p| i + p);
+        normalized.push_str(&text[i..next]);
+        offsets.extend(i..next);
+        i = next;
+    }
+    offsets.push(text.len());
+
+    (normalized, offsets)
+}
+
+/// Output formats this crate itself produces: type placeholders, `Hash`,
+/// `Tokenize`, and `Encrypt` output, vault tokens, masked emails with their
+/// kept top-level domain, and `█` mask runs (with the separators structured
+/// masks keep between them)
+fn placeholder_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(
+            r"\[REDACTED_[A-Za-z0-9_]+\]|\[HASH:[0-9a-f]{2,16}\]|\[\[PII_\d+\]\]|\[PII:\d+\]|\[ENC:[A-Za-z0-9_]+:[0-9a-f]+\]|⟪tok_\d{4,}⟫|█[█.]*@█+\.[A-Za-z]{2,63}|█(?:[█\-.()@]*█)?",
+        )
+        .expect("placeholder pattern is valid")
+    })
+}
+
+/// Merge neighbouring detections of a type the policy merges when only
+/// whitespace and punctuation, at most the type's gap in bytes, separate
+/// them. `detections` must be sorted by start.
+fn merge_adjacent(
+    text: &str,
+    policy: &RedactionPolicy,
+    detections: Vec<Detection>,
+) -> Vec<Detection> {
+    let mut merged: Vec<Detection> = Vec::with_capacity(detections.len());
+    for d in detections {
+        if let Some(prev) = merged.last_mut() {
+            let gap = text.get(prev.end..d.start).unwrap_or("x");
+            let mergeable = prev.pii_type == d.pii_type
+                && policy
+                    .merge_adjacent_gap(d.pii_type)
+                    .is_some_and(|max_gap| gap.len() <= max_gap)
+                && gap
+                    .chars()
+                    .all(|c| c.is_whitespace() || c.is_ascii_punctuation());
+            if mergeable {
+                prev.end = d.end;
+                prev.confidence = prev.confidence.min(d.confidence);
+                prev.original.zeroize();
+                prev.original = text[prev.start..prev.end].to_string();
+                continue;
+            }
+        }
+        merged.push(d);
+    }
+    merged
+}
+
+/// Check if `start..end` overlaps any of the given spans
+fn overlaps_any(spans: &[(usize, usize)], start: usize, end: usize) -> bool {
+    spans.iter().any(|&(s, e)| start < e && end > s)
+}
+
+/// Check if `start..end` lies entirely inside one of the given spans
+fn within_any(spans: &[(usize, usize)], start: usize, end: usize) -> bool {
+    spans.iter().any(|&(s, e)| s <= start && end <= e)
+}
+
+/// Push the parts of `start..end` outside the sorted `covered` spans
+fn push_uncovered(
+    out: &mut Vec<(usize, usize)>,
+    covered: &[(usize, usize)],
+    start: usize,
+    end: usize,
+) {
+    let mut cursor = start;
+    for &(s, e) in covered {
+        if s >= end {
+            break;
+        }
+        if e <= cursor {
+            continue;
+        }
+        if s > cursor {
+            out.push((cursor, s));
+        }
+        cursor = e;
+    }
+    if cursor < end {
+        out.push((cursor, end));
+    }
+}
+
 impl Redactor {
     /// Create a new redactor with detectors and policy
     pub fn new(detectors: Vec<Box<dyn PiiDetector>>, policy: RedactionPolicy) -> Self {
         Self {
             detector: MultiDetector::new(detectors),
+            blocklist: BlocklistMatcher::new(&policy),
             policy,
             audit_logger: Arc::new(NoopAuditLogger),
+            sink: None,
         }
     }
 
+    /// Create a redactor running the registered detector of each type
+    /// `policy` enables.
+    ///
+    /// Detectors of disabled types are dropped, not run. Fails with
+    /// [`RedactorBuildError::MissingDetector`] naming the first enabled type
+    /// (in name order) that has no registered detector.
+    pub fn from_registry(
+        mut registry: DetectorRegistry,
+        policy: RedactionPolicy,
+    ) -> Result<Self, RedactorBuildError> {
+        let mut enabled: Vec<PiiType> = policy.enabled_types().collect();
+        enabled.sort_by_key(|pii_type| format!("{pii_type:?}"));
+        let detectors = enabled
+            .into_iter()
+            .map(|pii_type| {
+                registry
+                    .remove(pii_type)
+                    .ok_or(RedactorBuildError::MissingDetector(pii_type))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::new(detectors, policy))
+    }
+
+    /// Start a [`RedactorBuilder`] with no types enabled
+    pub fn builder() -> RedactorBuilder {
+        RedactorBuilder::new()
+    }
+
     /// Create a new redactor with an audit logger for compliance logging.
     ///
     /// # Example
@@ -58,76 +444,461 @@ impl Redactor {
     ) -> Self {
         Self {
             detector: MultiDetector::new(detectors),
+            blocklist: BlocklistMatcher::new(&policy),
             policy,
             audit_logger: Arc::new(audit_logger),
+            sink: None,
         }
     }
 
+    /// Swap in a new policy, keeping the compiled detectors, e.g. when
+    /// hot-reloading config.
+    ///
+    /// Allowlists, blocklists, modes, placeholders, validation, rules, and
+    /// every other policy setting take effect on the next call. Detectors
+    /// are not added or removed: enabling a type that has no registered
+    /// detector finds nothing until the redactor is rebuilt (e.g. with
+    /// [`Redactor::builder`]), while disabling a type works immediately.
+    pub fn update_policy(&mut self, policy: RedactionPolicy) {
+        self.blocklist = BlocklistMatcher::new(&policy);
+        self.policy = policy;
+    }
+
     /// Get a reference to the audit logger.
     pub fn audit_logger(&self) -> &dyn AuditLogger {
         self.audit_logger.as_ref()
     }
 
+    /// Push a [`RedactionEvent`] to `sink` for every redacted PII value
+    pub fn with_sink(mut self, sink: Arc<dyn RedactionSink>) -> Self {
+        self.sink = Some(sink);
+        self
+    }
+
+    /// Call `handler` for each detection dropped for a span that can't be
+    /// sliced from the text (reversed, out of bounds, or inside a multi-byte
+    /// character). Such detections are skipped either way; `try_redact`
+    /// fails on them instead.
+    pub fn on_invalid_span(
+        mut self,
+        handler: impl Fn(&RedactError) + Send + Sync + 'static,
+    ) -> Self {
+        self.detector.set_invalid_span_handler(Arc::new(handler));
+        self
+    }
+
+    /// Report a redacted detection to the audit logger and the sink
+    fn record(&self, detection: &Detection, redacted_form: &str) {
+        self.audit_logger
+            .log(AuditEvent::from_detection(detection, redacted_form));
+        if let Some(sink) = &self.sink {
+            sink.on_detection(&RedactionEvent::from_detection(detection));
+        }
+    }
+
     /// Redact PII from text – returns Cow<str> for zero-copy optimization
     pub fn redact<'a>(&self, text: &'a str) -> Cow<'a, str> {
-        if text.is_empty() {
-            return Cow::Borrowed(text);
+        let (_, body) = self.input_body(text);
+        if body.is_empty() {
+            return Cow::Borrowed(body);
         }
+        let plan = self.plan(body);
+        self.redact_or_sentinel(body, &plan)
+    }
 
-        // Step 1: Find allowlist spans on ORIGINAL text (before any modification)
-        let allowlist_spans = self.find_allowlist_spans(text);
+    /// Redact each of `inputs` on the rayon thread pool, returning outputs
+    /// in input order. Each output equals `redact` on that input.
+    #[cfg(feature = "rayon")]
+    pub fn redact_parallel<'a>(&self, inputs: &[&'a str]) -> Vec<Cow<'a, str>> {
+        use rayon::prelude::*;
+        inputs.par_iter().map(|text| self.redact(text)).collect()
+    }
 
-        // Step 2: Run detectors on ORIGINAL text (not modified by blocklist)
-        // Pass validation flag from policy
-        let detections = self
-            .detector
-            .detect_with_validation(text, self.policy.requires_validation());
+    /// Redact PII from bytes that need not be valid UTF-8, such as raw log
+    /// lines.
+    ///
+    /// Each valid UTF-8 run is redacted as text; invalid bytes end a run and
+    /// are copied through untouched, so PII never matches across them. BOM
+    /// stripping and the fully-redacted sentinel don't apply.
+    pub fn redact_bytes<'a>(&self, input: &'a [u8]) -> Cow<'a, [u8]> {
+        let mut out: Option<Vec<u8>> = None;
+        let mut pos = 0;
+        for chunk in input.utf8_chunks() {
+            let valid = chunk.valid();
+            let redacted = if valid.is_empty() {
+                Cow::Borrowed(valid)
+            } else {
+                self.redact_planned(valid, &self.plan(valid))
+            };
+            if let Cow::Owned(_) = redacted {
+                out.get_or_insert_with(|| input[..pos].to_vec());
+            }
+            if let Some(buf) = &mut out {
+                buf.extend_from_slice(redacted.as_bytes());
+                buf.extend_from_slice(chunk.invalid());
+            }
+            pos += valid.len() + chunk.invalid().len();
+        }
+        out.map_or(Cow::Borrowed(input), Cow::Owned)
+    }
 
-        // Step 2b: Filter out detections for disabled PII types
-        let enabled_detections: Vec<Detection> = detections
-            .into_iter()
-            .filter(|d| self.policy.is_enabled(d.pii_type))
+    /// Redact PII from text, failing instead of dropping detections with
+    /// malformed spans.
+    ///
+    /// `redact` silently drops a detection whose span is reversed, runs
+    /// past the end of `text`, or splits a UTF-8 character; this returns a
+    /// [`RedactError`] naming the first such detector instead, for callers
+    /// that want broken third-party detectors to surface. Otherwise the
+    /// result is the same as `redact`'s, and nothing is logged on error.
+    pub fn try_redact<'a>(&self, text: &'a str) -> Result<Cow<'a, str>, RedactError> {
+        let (_, body) = self.input_body(text);
+        if body.is_empty() {
+            return Ok(Cow::Borrowed(body));
+        }
+        let plan = self.plan_candidates(body, self.try_detect(body)?, false);
+        Ok(self.redact_or_sentinel(body, &plan))
+    }
+
+    /// Apply `plan` to `text` and log the request, as `redact` does,
+    /// swapping in the policy's sentinel if the plan covers (nearly) all of
+    /// `text`
+    fn redact_or_sentinel<'a>(&self, text: &'a str, plan: &RedactionPlan) -> Cow<'a, str> {
+        let result = self.redact_planned(text, plan);
+        match self.fully_redacted(text, plan) {
+            Some(sentinel) => Cow::Owned(sentinel.to_string()),
+            None => result,
+        }
+    }
+
+    /// The policy's sentinel if `plan` leaves less than the configured
+    /// share of `text`'s non-whitespace characters unredacted
+    fn fully_redacted(&self, text: &str, plan: &RedactionPlan) -> Option<&str> {
+        let min_kept = self.policy.fully_redacted_below();
+        if min_kept <= 0.0 || plan.is_empty() {
+            return None;
+        }
+        let visible = |s: &str| s.chars().filter(|c| !c.is_whitespace()).count();
+        let total = visible(text);
+        let redacted: usize = plan
+            .detections
+            .iter()
+            .map(|d| (d.start, d.end))
+            .chain(plan.blocklist_spans.iter().copied())
+            .map(|(start, end)| visible(&text[start..end]))
+            .sum();
+        let kept = total.saturating_sub(redacted) as f64 / total.max(1) as f64;
+        (kept < min_kept).then(|| self.policy.fully_redacted_sentinel())
+    }
+
+    /// Redact `text` once per entry of `modes`, running detection only once.
+    ///
+    /// Each output matches what `redact` returns with the policy's mode set
+    /// to that entry, e.g. a masked copy for display and a tokenized one for
+    /// processing. Rule actions and generalization apply to every output,
+    /// as in `redact`. Detections are logged once, with their rendering in
+    /// the first mode.
+    pub fn redact_multi(&self, text: &str, modes: &[RedactionMode]) -> Vec<String> {
+        let (_, body) = self.input_body(text);
+        let plan = if body.is_empty() {
+            RedactionPlan::default()
+        } else {
+            self.plan(body)
+        };
+        let outputs = modes
+            .iter()
+            .enumerate()
+            .map(|(i, &mode)| {
+                if plan.is_empty() {
+                    return body.to_string();
+                }
+                let output = self.apply_in_mode(body, &plan, mode, i == 0);
+                match self.fully_redacted(body, &plan) {
+                    Some(sentinel) => sentinel.to_string(),
+                    None => output,
+                }
+            })
             .collect();
+        self.audit_logger.log(AuditEvent::RequestProcessed {
+            had_pii: !plan.detections.is_empty(),
+            detection_count: plan.detections.len(),
+            redacted: !plan.is_empty(),
+        });
+        outputs
+    }
 
-        // Step 3: Filter detections - remove those overlapping with allowlist
-        let filtered_detections: Vec<Detection> = enabled_detections
-            .into_iter()
-            .filter(|d| {
-                !allowlist_spans
-                    .iter()
-                    .any(|&(start, end)| d.start < end && d.end > start)
+    /// Redact only the byte range `range` of `text`, returning the full text.
+    ///
+    /// Detection runs on the selected region alone, so no detection extends
+    /// past it; text outside the range is copied unchanged. A range that is
+    /// reversed, out of bounds, or splits a character selects nothing, and
+    /// the text is returned as it is.
+    pub fn redact_range<'a>(&self, text: &'a str, range: Range<usize>) -> Cow<'a, str> {
+        let Some(region) = text.get(range.clone()) else {
+            return Cow::Borrowed(text);
+        };
+
+        match self.redact(region) {
+            Cow::Borrowed(_) => Cow::Borrowed(text),
+            Cow::Owned(region) => {
+                let mut result = String::with_capacity(text.len());
+                result.push_str(&text[..range.start]);
+                result.push_str(&region);
+                result.push_str(&text[range.end..]);
+                Cow::Owned(result)
+            }
+        }
+    }
+
+    /// Redact `text` after an edit, re-running detectors only near the
+    /// change.
+    ///
+    /// `prior` is the detection list returned for the previous version of
+    /// the text and `dirty_range` the bytes of `text` that changed. Detectors
+    /// run over `dirty_range` widened by [`INCREMENTAL_MARGIN`] bytes each
+    /// side and out to whitespace, so matches and keyword context crossing
+    /// the edit are found again; prior detections outside that window are
+    /// reused if their value is still at their offsets. Text after an edit
+    /// that changed its length has moved, so when a later prior no longer
+    /// matches, the rest of the text is rescanned.
+    ///
+    /// Returns the redacted text, as `redact` would produce it, and the
+    /// detector output for `text` (before policy filtering, so it can be
+    /// passed back as `prior`). Offsets refer to `text` as given.
+    pub fn redact_incremental<'a>(
+        &self,
+        text: &'a str,
+        dirty_range: Range<usize>,
+        prior: &[Detection],
+    ) -> (Cow<'a, str>, Vec<Detection>) {
+        let (offset, body) = self.input_body(text);
+        if body.is_empty() {
+            return (Cow::Borrowed(body), Vec::new());
+        }
+
+        let dirty_start = dirty_range.start.saturating_sub(offset).min(body.len());
+        let dirty_end = dirty_range
+            .end
+            .saturating_sub(offset)
+            .min(body.len())
+            .max(dirty_start);
+        let (mut win_start, mut win_end) = incremental_window(body, dirty_start, dirty_end);
+        let still_valid = |d: &Detection| {
+            d.start >= offset
+                && body.get(d.start - offset..d.end - offset) == Some(d.original.as_str())
+        };
+        if prior
+            .iter()
+            .any(|d| d.end <= win_start + offset && !still_valid(d))
+        {
+            win_start = 0;
+        }
+        if prior
+            .iter()
+            .any(|d| d.start >= win_end + offset && !still_valid(d))
+        {
+            win_end = body.len();
+        }
+
+        // A prior whose value is gone (the text moved or shrank) is dropped
+        // here: its offsets may lie before the BOM or past the end of `body`
+        let mut candidates: Vec<Detection> = prior
+            .iter()
+            .filter(|d| d.end <= win_start + offset || d.start >= win_end + offset)
+            .filter(|d| still_valid(d))
+            .map(|d| {
+                let mut d = d.clone();
+                d.start -= offset;
+                d.end -= offset;
+                d
             })
             .collect();
+        let validate = self.policy.requires_validation();
+        candidates.extend(
+            self.detect(&body[win_start..win_end], validate, None)
+                .into_iter()
+                .map(|mut d| {
+                    d.start += win_start;
+                    d.end += win_start;
+                    d
+                }),
+        );
+        candidates.sort_by_key(|d| (d.start, d.end));
 
-        // Step 4: Find blocklist spans on ORIGINAL text, filter by allowlist
-        let blocklist_spans: Vec<(usize, usize)> = self
-            .policy
-            .blocklist_terms()
+        let detections = candidates
             .iter()
-            .flat_map(|term| {
-                let allowlist = &allowlist_spans;
-                text.match_indices(term.as_str())
-                    .filter_map(move |(start, _)| {
-                        let end = start + term.len();
-                        // Only match whole words — skip partial matches like
-                        // "CONFIDENTIAL" inside "CONFIDENTIALITY"
-                        if !is_word_boundary_match(text, start, end) {
-                            return None;
-                        }
-                        let overlaps_allowlist = allowlist
-                            .iter()
-                            .any(|&(a_start, a_end)| start < a_end && end > a_start);
-                        if overlaps_allowlist {
-                            None
-                        } else {
-                            Some((start, end))
-                        }
-                    })
+            .map(|d| {
+                let mut d = d.clone();
+                d.start += offset;
+                d.end += offset;
+                d
             })
             .collect();
+        let plan = self.plan_candidates(body, candidates, false);
+        (self.redact_or_sentinel(body, &plan), detections)
+    }
+
+    /// Redact PII and report what was redacted or passed through.
+    ///
+    /// Every candidate detection gets a [`Disposition`] explaining why it
+    /// was or wasn't redacted – including candidates of disabled types and,
+    /// under strict validation, matches that failed their checksum. The
+    /// report holds types, offsets, and dispositions only, never the
+    /// original values, plus blocklist hits and the bytes masked; see
+    /// [`RedactionReport::summary`] for a loggable one-liner. Offsets refer
+    /// to `text` as given. The output is what `redact` returns, including
+    /// the fully-redacted sentinel, which the report records.
+    pub fn redact_with_report<'a>(&self, text: &'a str) -> (Cow<'a, str>, RedactionReport) {
+        let (offset, body) = self.input_body(text);
+        if body.is_empty() {
+            return (Cow::Borrowed(body), RedactionReport::default());
+        }
+
+        let plan = self.build_plan(body, true, None);
+        let mut entries = self.report_entries(&plan.detections);
+        entries.extend(plan.skipped.iter().cloned());
+        for entry in &mut entries {
+            entry.start += offset;
+            entry.end += offset;
+        }
+
+        let sentinel = self.fully_redacted(body, &plan);
+        let report = RedactionReport::new(entries)
+            .with_distinct_values(&plan.detections)
+            .with_blocklist_spans(&plan.blocklist_spans)
+            .with_fully_redacted(sentinel.is_some());
+        let output = match sentinel {
+            Some(sentinel) => Cow::Owned(sentinel.to_string()),
+            None => self.redact_planned(body, &plan),
+        };
+        (output, report)
+    }
+
+    /// Report entries for detections that will be redacted or generalized
+    fn report_entries(&self, detections: &[Detection]) -> Vec<ReportEntry> {
+        detections
+            .iter()
+            .map(|d| {
+                let ruled = self.policy.rules().evaluate(d, &self.policy).is_some();
+                let disposition = match self.generalize(d).filter(|_| !ruled) {
+                    Some((level, _)) => Disposition::Generalized(level),
+                    None => Disposition::Redacted,
+                };
+                ReportEntry::new(d, disposition)
+            })
+            .collect()
+    }
+
+    /// Redact everything `reader` yields to `writer`, without holding the
+    /// whole input in memory.
+    ///
+    /// Input is redacted in windows overlapping by the policy's
+    /// `stream_overlap` bytes, so PII straddling a read boundary is found
+    /// and never split (see [`WindowConfig`](crate::stream::WindowConfig)).
+    /// Output is written, and `writer` flushed, as each window completes.
+    /// The output equals `redact` on the whole input as long as no PII span
+    /// is longer than the overlap.
+    ///
+    /// The input must be UTF-8; invalid input fails with
+    /// [`io::ErrorKind::InvalidData`](std::io::ErrorKind::InvalidData) after
+    /// the preceding output was written. The returned report covers the
+    /// whole stream, with offsets into it.
+    pub fn redact_io<R: Read, W: Write>(
+        &self,
+        mut reader: R,
+        mut writer: W,
+    ) -> io::Result<RedactionReport> {
+        let overlap = self.policy.stream_overlap();
+        let mut windower =
+            Windower::with_report(WindowConfig::adaptive(4 * 1024, 64 * 1024, overlap));
+        let mut buf = vec![0; 8 * 1024];
+        // Bytes of a UTF-8 character split across reads
+        let mut pending = 0;
+        loop {
+            let n = match reader.read(&mut buf[pending..]) {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            let filled = pending + n;
+            let valid = match std::str::from_utf8(&buf[..filled]) {
+                Ok(text) => text,
+                Err(e) if e.error_len().is_none() => {
+                    // Safe: `valid_up_to` is a character boundary
+                    std::str::from_utf8(&buf[..e.valid_up_to()]).expect("valid prefix")
+                }
+                Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+            };
+            let consumed = valid.len();
+            let output = windower.push(self, valid);
+            if !output.is_empty() {
+                writer.write_all(output.as_bytes())?;
+                writer.flush()?;
+            }
+            buf.copy_within(consumed..filled, 0);
+            pending = filled - consumed;
+        }
+        if pending > 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "stream ends inside a UTF-8 character",
+            ));
+        }
+        writer.write_all(windower.finish(self).as_bytes())?;
+        writer.flush()?;
+        Ok(windower.into_report())
+    }
+
+    /// Redact PII and time each registered detector.
+    ///
+    /// The output and logging are `redact`'s. Returns one
+    /// [`DetectorProfile`] per detector, in registration order. Timing is
+    /// opt-in: `redact` and the other entry points never read the clock.
+    pub fn redact_profiled<'a>(&self, text: &'a str) -> (Cow<'a, str>, Vec<DetectorProfile>) {
+        let (_, body) = self.input_body(text);
+        let mut profile = Vec::new();
+        if body.is_empty() {
+            // Nothing to redact or log, but every detector still gets an entry
+            self.detect(body, false, Some(&mut profile));
+            return (Cow::Borrowed(body), profile);
+        }
+        let plan = self.build_plan(body, false, Some(&mut profile));
+        (self.redact_or_sentinel(body, &plan), profile)
+    }
+
+    /// Redact `text` as `redact` does, except that detections and
+    /// blocklist hits overlapping any of `keep` are left as they are.
+    /// Detectors still scan all of `text`, so kept spans give context.
+    pub(crate) fn redact_except<'a>(&self, text: &'a str, keep: &[(usize, usize)]) -> Cow<'a, str> {
+        let mut plan = self.plan(text);
+        plan.detections
+            .retain(|d| !overlaps_any(keep, d.start, d.end));
+        plan.blocklist_spans
+            .retain(|&(start, end)| !overlaps_any(keep, start, end));
+        self.redact_planned(text, &plan)
+    }
+
+    /// Redact all of `value` as a single `pii_type` value, without running
+    /// detectors. Rules, generalization, and the mode apply as in `redact`.
+    pub(crate) fn redact_as(&self, value: &str, pii_type: PiiType) -> String {
+        let plan = RedactionPlan {
+            detections: vec![Detection {
+                pii_type,
+                confidence: crate::detector::Confidence::High,
+                start: 0,
+                end: value.len(),
+                original: value.to_string(),
+                metadata: None,
+            }],
+            ..RedactionPlan::default()
+        };
+        self.redact_planned(value, &plan).into_owned()
+    }
 
-        // Step 5: If nothing to redact, return original
-        if filtered_detections.is_empty() && blocklist_spans.is_empty() {
+    /// Apply a plan to `text`, logging the request
+    fn redact_planned<'a>(&self, text: &'a str, plan: &RedactionPlan) -> Cow<'a, str> {
+        // If nothing to redact, return original
+        if plan.is_empty() {
             self.audit_logger.log(AuditEvent::RequestProcessed {
                 had_pii: false,
                 detection_count: 0,
@@ -136,116 +907,914 @@ impl Redactor {
             return Cow::Borrowed(text);
         }
 
-        // Step 6: Apply all redactions in one pass over the original text
-        let mut result = String::with_capacity(text.len());
-        let mut last_idx = 0;
-        let mut pii_counter: usize = 0;
+        let result = self.apply(text, plan);
+        // Modes can render a value unchanged (e.g. a reveal covering all of it)
+        let redacted = result != text;
 
-        // Merge PII detections and blocklist spans into sorted list
-        let mut all_spans: Vec<(usize, usize, RedactionKind<'_>)> = Vec::new();
+        // Log the request processed event
+        self.audit_logger.log(AuditEvent::RequestProcessed {
+            had_pii: !plan.detections.is_empty(),
+            detection_count: plan.detections.len(),
+            redacted,
+        });
 
-        for d in &filtered_detections {
-            all_spans.push((d.start, d.end, RedactionKind::Pii(d)));
+        if redacted {
+            Cow::Owned(result)
+        } else {
+            Cow::Borrowed(text)
         }
-        for &(start, end) in &blocklist_spans {
-            all_spans.push((start, end, RedactionKind::Blocklist));
+    }
+
+    /// De-identify `text` for release, recording each replacement.
+    ///
+    /// Every PII detection is replaced with a pseudonym token numbered per
+    /// type, like `[REDACTED_EMAIL_1]`; repeats of a value in the document
+    /// get the same token. The mode, rule actions, and generalization are
+    /// not applied, but `Keep` rules, the allowlist, and validation are.
+    /// Blocklist terms are masked as in `redact` and not recorded, since
+    /// they have no PII type.
+    ///
+    /// The [`Manifest`], tagged with `doc_id`, maps each token to a digest
+    /// of its original value from the policy hasher; it never holds the
+    /// value itself.
+    pub fn deidentify(&self, text: &str, doc_id: &str) -> (String, Manifest) {
+        let (offset, body) = self.input_body(text);
+        if body.is_empty() {
+            return (body.to_string(), Manifest::new(doc_id, Vec::new()));
         }
 
-        all_spans.sort_by_key(|&(start, _, _)| start);
+        let plan = self.plan(body);
+        let mut tokens: HashMap<(PiiType, Vec<u8>), String> = HashMap::new();
+        let mut counters: HashMap<PiiType, usize> = HashMap::new();
+        let mut entries = Vec::with_capacity(plan.detections.len());
+        let output = self.splice_plan(body, &plan, |d| {
+            let digest = self.policy.hash(d.original.as_bytes());
+            let original_hash: String = digest.iter().map(|b| format!("{b:02x}")).collect();
+            let replacement = tokens
+                .entry((d.pii_type, digest))
+                .or_insert_with(|| {
+                    let n = counters.entry(d.pii_type).or_default();
+                    *n += 1;
+                    manifest::pseudonym(d.pii_type, *n)
+                })
+                .clone();
+            entries.push(ManifestEntry {
+                pii_type: d.pii_type,
+                start: d.start + offset,
+                end: d.end + offset,
+                original_hash,
+                replacement: replacement.clone(),
+            });
+            replacement
+        });
+        (output.into_owned(), Manifest::new(doc_id, entries))
+    }
 
-        for (start, end, kind) in all_spans {
-            if start > last_idx {
-                result.push_str(&text[last_idx..start]);
-            }
+    /// Redact `text` reversibly: every PII detection is replaced with an
+    /// opaque token like `⟪tok_0001⟫`, and the returned [`TokenVault`] maps
+    /// each token back to its value, so [`TokenVault::restore`] rebuilds the
+    /// input. Repeats of a value share a token.
+    ///
+    /// The mode, rule actions, and generalization are not applied, but
+    /// `Keep` rules, the allowlist, and validation are. Blocklist terms are
+    /// masked as in `redact` and can't be restored. The vault holds the
+    /// plaintext and zeroizes it on drop; keep it out of the pipeline the
+    /// redacted text is sent through.
+    pub fn redact_with_vault<'a>(&self, text: &'a str) -> (Cow<'a, str>, TokenVault) {
+        let (_, body) = self.input_body(text);
+        let mut vault = TokenVault::default();
+        if body.is_empty() {
+            return (Cow::Borrowed(body), vault);
+        }
 
-            match kind {
-                RedactionKind::Pii(detection) => {
-                    let redacted = if self.policy.mode() == RedactionMode::Tokenize {
-                        // Tokenize mode: replace with sequential tokens
-                        let token = format!("[[PII_{}]]", pii_counter);
-                        pii_counter += 1;
-                        token
-                    } else {
-                        self.redact_structured(&detection.original, detection.pii_type)
-                    };
-                    self.audit_logger
-                        .log(AuditEvent::from_detection(detection, &redacted));
-                    result.push_str(&redacted);
-                }
-                RedactionKind::Blocklist => {
-                    result.push_str(&"█".repeat(end - start));
-                }
-            }
+        let plan = self.plan(body);
+        let output = self.splice_plan(body, &plan, |d| vault.token_for(&d.original));
+        (output, vault)
+    }
 
-            last_idx = end;
+    /// Replace each planned detection in `body` with `replacement(d)` and
+    /// mask blocklist hits as `redact` does, recording every detection and
+    /// logging the request. For outputs that bypass the mode.
+    fn splice_plan<'a>(
+        &self,
+        body: &'a str,
+        plan: &RedactionPlan,
+        mut replacement: impl FnMut(&Detection) -> String,
+    ) -> Cow<'a, str> {
+        let mut spans: Vec<(usize, usize, String)> =
+            Vec::with_capacity(plan.detections.len() + plan.blocklist_spans.len());
+        for d in &plan.detections {
+            let replacement = replacement(d);
+            self.record(d, &replacement);
+            spans.push((d.start, d.end, replacement));
         }
-
-        if last_idx < text.len() {
-            result.push_str(&text[last_idx..]);
+        for &(start, end) in &plan.blocklist_spans {
+            spans.push((start, end, "█".repeat(body[start..end].chars().count())));
         }
+        spans.sort_by_key(|&(start, _, _)| start);
 
-        // Log the request processed event
         self.audit_logger.log(AuditEvent::RequestProcessed {
-            had_pii: !filtered_detections.is_empty(),
-            detection_count: filtered_detections.len(),
-            redacted: true,
+            had_pii: !plan.detections.is_empty(),
+            detection_count: plan.detections.len(),
+            redacted: !plan.is_empty(),
         });
+        if spans.is_empty() {
+            return Cow::Borrowed(body);
+        }
 
-        Cow::Owned(result)
+        let mut output = String::with_capacity(body.len());
+        let mut last = 0;
+        for (start, end, replacement) in spans {
+            output.push_str(&body[last..start]);
+            output.push_str(&replacement);
+            last = end;
+        }
+        output.push_str(&body[last..]);
+        Cow::Owned(output)
     }
 
-    fn find_allowlist_spans(&self, text: &str) -> Vec<(usize, usize)> {
-        let mut spans = Vec::new();
-        for term in self.policy.allowlist_terms() {
-            for (start, _) in text.match_indices(term.as_str()) {
-                let end = start + term.len();
-                // Only protect whole-word allowlist matches
-                if is_word_boundary_match(text, start, end) {
-                    spans.push((start, end));
-                }
-            }
+    /// Byte ranges `redact` would replace, without producing any output.
+    ///
+    /// Ranges are in original-text coordinates, sorted by start, and already
+    /// filtered by policy (enabled types, validation, allowlist) and resolved
+    /// for overlaps. Blocklist hits carry no PII type and are not included.
+    pub fn redaction_ranges(&self, text: &str) -> Vec<(usize, usize, PiiType)> {
+        let (offset, body) = self.input_body(text);
+        if body.is_empty() {
+            return Vec::new();
         }
-        spans
+        self.plan(body)
+            .detections
+            .iter()
+            .map(|d| (d.start + offset, d.end + offset, d.pii_type))
+            .collect()
     }
 
-    fn redact_structured(&self, original: &str, pii_type: PiiType) -> String {
-        // If a custom placeholder is configured, use it for simple replacement
-        if let Some(custom) = self.policy.custom_placeholder(pii_type) {
-            return custom.to_string();
+    /// Detections `redact` would replace, without rewriting the text.
+    ///
+    /// The full pipeline runs as in `redact` (detectors, validation,
+    /// enabled types, allowlist, rules, overlap resolution), so a value
+    /// `redact` would leave alone is not returned. Blocklist hits carry no
+    /// PII type and are not included. Detections are sorted by start, with
+    /// offsets into `text` as given (a leading BOM included). Nothing is
+    /// logged.
+    pub fn scan(&self, text: &str) -> Vec<Detection> {
+        let (offset, body) = self.input_body(text);
+        if body.is_empty() {
+            return Vec::new();
         }
+        let mut detections = self.plan(body).detections;
+        for d in &mut detections {
+            d.start += offset;
+            d.end += offset;
+        }
+        detections
+    }
 
-        // Apply global redaction mode
-        match self.policy.mode() {
-            RedactionMode::Mask => {
-                // Default: format-preserving structured redaction
-                match pii_type {
-                    PiiType::Email => self.redact_email_structured(original),
-                    PiiType::PhoneNumber => self.redact_phone_structured(original),
-                    PiiType::Ssn => self.redact_ssn_structured(original),
-                    PiiType::CreditCard => self.redact_credit_card_structured(original),
-                    PiiType::IpAddressV4 | PiiType::IpAddressV6 => "█".repeat(original.len()),
-                    PiiType::Iban => self.redact_iban_structured(original),
-                    PiiType::PassportNumber => self.redact_passport_structured(original),
-                    PiiType::NationalId => self.redact_national_id_structured(original),
-                    PiiType::PhysicalAddress => "█".repeat(original.len()),
-                    PiiType::Other(_) => "█".repeat(original.len()),
-                }
-            }
-            RedactionMode::Replace => {
-                // Full replacement with type-specific placeholder
-                pii_type.placeholder().to_string()
-            }
-            RedactionMode::Hash => {
-                // Blake3 hash (first 16 hex chars for readability)
-                let hash = blake3::hash(original.as_bytes());
-                let hex = hash.to_hex();
-                format!("[HASH:{}]", &hex[..16])
-            }
+    /// Detections `redact` would replace, each with its
+    /// [`char_range`](Detection::char_range) in `text`.
+    ///
+    /// As [`scan`](Self::scan), so byte offsets in each `Detection` are into
+    /// `text` as given and both coordinate systems are available.
+    pub fn scan_char_offsets(&self, text: &str) -> Vec<(Detection, (usize, usize))> {
+        // Detections are sorted and disjoint: count chars once, left to right
+        let (mut byte, mut chars) = (0, 0);
+        self.scan(text)
+            .into_iter()
+            .map(|d| {
+                chars += text[byte..d.start].chars().count();
+                let start = chars;
+                chars += text[d.start..d.end].chars().count();
+                byte = d.end;
+                (d, (start, chars))
+            })
+            .collect()
+    }
+
+    /// Number of detections `redact` would replace, per PII type.
+    ///
+    /// Counts use the same filtering as [`redaction_ranges`](Self::redaction_ranges)
+    /// (enabled types, validation, allowlist, overlaps); blocklist hits carry
+    /// no PII type and are not counted. No output is built and nothing is
+    /// logged.
+    ///
+    /// Detectors are run through [`PiiDetector::detect_spans`], so those
+    /// that override it copy no values, unless the policy needs them: with
+    /// rules, validation off, or newlines to normalize, full detections are
+    /// built as in `redact`. Span matches carry their detector's
+    /// [`confidence`](PiiDetector::confidence).
+    pub fn count(&self, text: &str) -> HashMap<PiiType, usize> {
+        let (_, body) = self.input_body(text);
+        let mut counts = HashMap::new();
+        if body.is_empty() {
+            return counts;
+        }
+        let validate = self.policy.requires_validation();
+        let spans_only = validate
+            && self.policy.rules().is_empty()
+            && !(self.policy.normalize_newlines() && body.contains('\r'));
+        let candidates = if spans_only {
+            self.detector.detect_span_candidates(body)
+        } else {
+            self.detect(body, validate, None)
+        };
+        for d in &self.plan_candidates(body, candidates, false).detections {
+            *counts.entry(d.pii_type).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Regulatory bases implicated by `text`: the union of
+    /// [`PiiType::regulatory_basis`] over every type `redact` would act on.
+    ///
+    /// Detection only – no output is built, no values are kept, and nothing
+    /// is logged. Blocklist hits carry no PII type and don't contribute.
+    pub fn regulatory_footprint(&self, text: &str) -> BTreeSet<&'static str> {
+        let (_, body) = self.input_body(text);
+        if body.is_empty() {
+            return BTreeSet::new();
+        }
+        self.plan(body)
+            .detections
+            .iter()
+            .map(|d| d.pii_type.regulatory_basis())
+            .collect()
+    }
+
+    /// Redact one window of a stream.
+    ///
+    /// `text[..context]` was already emitted and is only used as look-behind;
+    /// the returned output covers `text[context..cut]`, where `cut` is as
+    /// close to `limit` as possible without splitting a redaction span.
+    /// A span starting at `context` that crosses `limit` is committed whole.
+    /// With `report`, what the committed part holds is added to it.
+    pub(crate) fn redact_window(
+        &self,
+        text: &str,
+        context: usize,
+        limit: usize,
+        report: Option<&mut StreamReport>,
+    ) -> StreamWindow {
+        let plan = self.build_plan(text, report.is_some(), None);
+
+        let mut cut = limit.min(text.len());
+        while !text.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        let spans = plan
+            .detections
+            .iter()
+            .map(|d| (d.start, d.end))
+            .chain(plan.blocklist_spans.iter().copied());
+        for (start, end) in spans {
+            if start >= context && start < cut && end > cut {
+                cut = if start > context { start } else { end };
+            }
+        }
+
+        // Spans before `context` were handled by the previous window
+        let in_window = |start: usize, end: usize| start >= context && end <= cut;
+        let detections: Vec<Detection> = plan
+            .detections
+            .iter()
+            .filter(|d| in_window(d.start, d.end))
+            .map(|d| {
+                let mut d = d.clone();
+                d.start -= context;
+                d.end -= context;
+                d
+            })
+            .collect();
+        let blocklist_spans = plan
+            .blocklist_spans
+            .iter()
+            .filter(|&&(start, end)| in_window(start, end))
+            .map(|&(start, end)| (start - context, end - context))
+            .collect();
+
+        let window = RedactionPlan {
+            detections,
+            blocklist_spans,
+            skipped: Vec::new(),
+        };
+        if let Some(report) = report {
+            let base = report.offset + context;
+            let skipped = plan
+                .skipped
+                .iter()
+                .filter(|e| e.start >= context && e.start < cut)
+                .cloned()
+                .map(|mut e| {
+                    e.start -= context;
+                    e.end -= context;
+                    e
+                });
+            let mut entries = self.report_entries(&window.detections);
+            entries.extend(skipped);
+            report.entries.extend(entries.into_iter().map(|mut e| {
+                e.start += base;
+                e.end += base;
+                e
+            }));
+            report.blocklist_spans.extend(
+                window
+                    .blocklist_spans
+                    .iter()
+                    .map(|&(start, end)| (start + base, end + base)),
+            );
+            report
+                .distinct
+                .extend(window.detections.iter().map(distinct_key));
+        }
+        StreamWindow {
+            output: self.apply(&text[context..cut], &window),
+            cut,
+            detections: window.detections.len(),
+        }
+    }
+
+    /// Whether `text` is clean: `redact` would leave it unchanged.
+    ///
+    /// Already-redacted output is clean, since the crate's own placeholders
+    /// are never re-redacted. Nothing is logged.
+    pub fn verify(&self, text: &str) -> bool {
+        let (offset, body) = self.input_body(text);
+        offset == 0 && (body.is_empty() || self.plan(body).is_empty())
+    }
+
+    /// Whether `text` contains PII: any detection of an enabled type that
+    /// passes validation and isn't allowlisted or kept by a rule.
+    ///
+    /// Returns at the first hit, without building output, resolving
+    /// overlaps, or running the remaining detectors, so it is much cheaper
+    /// than `redact` for blocking requests. Blocklist terms don't count.
+    /// Nothing is logged.
+    pub fn contains_pii(&self, text: &str) -> bool {
+        let (_, body) = self.input_body(text);
+        if body.is_empty() {
+            return false;
+        }
+
+        let placeholder_spans = self.find_placeholder_spans(body);
+        let allowlist_spans = self.find_allowlist_spans(body);
+        let is_pii = |d: &Detection| {
+            self.policy.is_enabled(d.pii_type)
+                && self.policy.meets_min_confidence(d.pii_type, d.confidence)
+                && !within_any(&placeholder_spans, d.start, d.end)
+                && !overlaps_any(&allowlist_spans, d.start, d.end)
+                && !self.policy.is_allowed_by_predicate(d.pii_type, &d.original)
+                && !self
+                    .policy
+                    .is_suppressed_by_context(d.pii_type, body, d.start)
+                && self.policy.rules().evaluate(d, &self.policy) != Some(RuleAction::Keep)
+        };
+
+        let validate = self.policy.requires_validation();
+        if self.policy.normalize_newlines() && body.contains('\r') {
+            // Offsets need mapping back to `body`; no early exit
+            return self.detect(body, validate, None).iter().any(is_pii);
+        }
+        self.detector.detect_any(body, validate, is_pii)
+    }
+
+    /// The part of `text` that is redacted and emitted, and its offset.
+    /// Only differs from `text` when the policy strips a leading BOM.
+    fn input_body<'a>(&self, text: &'a str) -> (usize, &'a str) {
+        match text.strip_prefix('\u{FEFF}') {
+            Some(body) if self.policy.strip_bom() => (text.len() - body.len(), body),
+            _ => (0, text),
+        }
+    }
+
+    /// Run detectors, honoring newline normalization when configured
+    fn detect(
+        &self,
+        text: &str,
+        validate: bool,
+        profile: Option<&mut Vec<DetectorProfile>>,
+    ) -> Vec<Detection> {
+        let run = |text: &str| -> Result<_, std::convert::Infallible> {
+            Ok(match profile {
+                Some(profile) => {
+                    let (detections, timings) = self.detector.detect_profiled(text, validate);
+                    *profile = timings;
+                    detections
+                }
+                None => self.detector.detect_with_validation(text, validate),
+            })
+        };
+        match self.detect_normalized(text, run) {
+            Ok(detections) => detections,
+            Err(never) => match never {},
+        }
+    }
+
+    /// As `detect`, failing on malformed detector spans instead of
+    /// dropping them
+    fn try_detect(&self, text: &str) -> Result<Vec<Detection>, RedactError> {
+        let validate = self.policy.requires_validation();
+        self.detect_normalized(text, |text| {
+            self.detector.try_detect_with_validation(text, validate)
+        })
+    }
+
+    /// Run `run` on `text`, or on its newline-normalized form with offsets
+    /// mapped back when the policy normalizes newlines
+    fn detect_normalized<E>(
+        &self,
+        text: &str,
+        run: impl FnOnce(&str) -> Result<Vec<Detection>, E>,
+    ) -> Result<Vec<Detection>, E> {
+        if !self.policy.normalize_newlines() || !text.contains('\r') {
+            return run(text);
+        }
+
+        let (normalized, offsets) = normalize_newlines(text);
+        let mut detections = run(&normalized)?;
+        for d in &mut detections {
+            d.start = offsets[d.start];
+            d.end = offsets[d.end];
+            // Re-slice so `original` holds the exact bytes being replaced
+            d.original.zeroize();
+            d.original = text[d.start..d.end].to_string();
+        }
+        Ok(detections)
+    }
+
+    /// Decide what to redact: everything `redact` needs except the output.
+    fn plan(&self, text: &str) -> RedactionPlan {
+        self.build_plan(text, false, None)
+    }
+
+    /// Build a plan; with `report`, also record why candidates were skipped,
+    /// and with `profile`, time each detector.
+    fn build_plan(
+        &self,
+        text: &str,
+        report: bool,
+        profile: Option<&mut Vec<DetectorProfile>>,
+    ) -> RedactionPlan {
+        // Step 2 starts here: run detectors on ORIGINAL text (not modified
+        // by blocklist), passing the policy's validation flag
+        let candidates = self.detect(text, self.policy.requires_validation(), profile);
+        self.plan_candidates(text, candidates, report)
+    }
+
+    /// Build a plan from detector output for `text`
+    fn plan_candidates(
+        &self,
+        text: &str,
+        candidates: Vec<Detection>,
+        report: bool,
+    ) -> RedactionPlan {
+        let candidates = merge_adjacent(text, &self.policy, candidates);
+
+        // Step 1: Find allowlist spans on ORIGINAL text (before any modification).
+        // Placeholders from an earlier pass are protected too, so redaction
+        // is idempotent, but only what lies wholly inside one: a detection
+        // that merely touches a placeholder (say, a `█` typed next to an
+        // email) is still redacted.
+        let placeholder_spans = self.find_placeholder_spans(text);
+        let allowlist_spans = self.find_allowlist_spans(text);
+
+        // Step 2: Drop disabled PII types and detections overlapping an
+        // allowlisted span or allowed by predicate
+        let validate = self.policy.requires_validation();
+        let mut skipped = Vec::new();
+        if report && validate {
+            // Candidates that only match with validation off failed it
+            for d in self.detect(text, false, None) {
+                if candidates
+                    .iter()
+                    .any(|c| d.start < c.end && d.end > c.start)
+                    || within_any(&placeholder_spans, d.start, d.end)
+                {
+                    continue;
+                }
+                let disposition = if self.policy.is_enabled(d.pii_type) {
+                    Disposition::SkippedValidationFailed
+                } else {
+                    Disposition::SkippedDisabledType
+                };
+                skipped.push(ReportEntry::new(&d, disposition));
+            }
+        }
+
+        let mut detections: Vec<Detection> = Vec::new();
+        for d in candidates {
+            // Placeholders from an earlier pass are not real candidates
+            if within_any(&placeholder_spans, d.start, d.end) {
+                continue;
+            }
+            let disposition = if !self.policy.is_enabled(d.pii_type) {
+                Some(Disposition::SkippedDisabledType)
+            } else if !self.policy.meets_min_confidence(d.pii_type, d.confidence) {
+                Some(Disposition::SkippedLowConfidence)
+            } else if overlaps_any(&allowlist_spans, d.start, d.end)
+                || self
+                    .policy
+                    .is_allowed_by_predicate(d.pii_type, &text[d.start..d.end])
+                || self
+                    .policy
+                    .is_suppressed_by_context(d.pii_type, text, d.start)
+            {
+                Some(Disposition::SkippedAllowlist)
+            } else {
+                None
+            };
+            if let Some(disposition) = disposition {
+                if report {
+                    skipped.push(ReportEntry::new(&d, disposition));
+                }
+                continue;
+            }
+            if self.policy.rules().evaluate(&d, &self.policy) == Some(RuleAction::Keep) {
+                continue;
+            }
+            detections.push(d);
+        }
+
+        // Step 2b: Optionally keep the first occurrence of each value. Values
+        // are tracked by hash so no extra plaintext copies are held. Kept
+        // spans are exempt from the blocklist too, or a term matching the
+        // value would mask it anyway.
+        let mut kept_spans: Vec<(usize, usize)> = Vec::new();
+        if self.policy.keep_first_occurrence() {
+            let mut seen: HashSet<(PiiType, [u8; 32])> = HashSet::new();
+            detections.retain(|d| {
+                let digest = *blake3::hash(&text.as_bytes()[d.start..d.end]).as_bytes();
+                // Retain (redact) only values that were seen before
+                let first = seen.insert((d.pii_type, digest));
+                if first {
+                    kept_spans.push((d.start, d.end));
+                }
+                !first
+            });
+        }
+
+        // Step 2c: Redact at most the configured number per type, counting
+        // in document order; the rest are left as they are
+        let mut counts: HashMap<PiiType, usize> = HashMap::new();
+        detections.retain(|d| {
+            let Some(max) = self.policy.max_redactions(d.pii_type) else {
+                return true;
+            };
+            let count = counts.entry(d.pii_type).or_default();
+            *count += 1;
+            if *count <= max {
+                return true;
+            }
+            if report {
+                skipped.push(ReportEntry::new(d, Disposition::SkippedBudget));
+            }
+            false
+        });
+
+        // Step 3: Find blocklist spans on ORIGINAL text, filter by allowlist
+        // unless the blocklist takes precedence. A term on both lists never
+        // shields itself, so the blocklist wins ties. Hits are clipped to the
+        // bytes outside PII detections, which their own redaction already
+        // covers, and outside earlier placeholders.
+        let protected = match self.policy.list_precedence() {
+            ListPrecedence::AllowlistWins => {
+                self.allowlist_spans_where(text, |term| !self.policy.is_blocklist_term(term))
+            }
+            ListPrecedence::BlocklistWins => Vec::new(),
+        };
+        let protected = &protected;
+        let mut covered: Vec<(usize, usize)> = detections
+            .iter()
+            .map(|d| (d.start, d.end))
+            .chain(kept_spans)
+            .chain(placeholder_spans)
+            .collect();
+        covered.sort_unstable();
+        let mut blocklist_spans: Vec<(usize, usize)> = Vec::new();
+        for (start, end) in self.blocklist.find(text) {
+            // Only match whole words — skip partial matches like
+            // "CONFIDENTIAL" inside "CONFIDENTIALITY"
+            if !is_word_boundary_match(text, start, end) || overlaps_any(protected, start, end) {
+                continue;
+            }
+            push_uncovered(&mut blocklist_spans, &covered, start, end);
+        }
+        let max_distance = self.policy.fuzzy_blocklist_distance();
+        if max_distance > 0 {
+            for (start, end) in
+                fuzzy_blocklist_matches(text, self.policy.blocklist_terms(), max_distance)
+            {
+                if !overlaps_any(protected, start, end) {
+                    push_uncovered(&mut blocklist_spans, &covered, start, end);
+                }
+            }
+        }
+        blocklist_spans.sort_unstable();
+        blocklist_spans.dedup_by(|next, prev| {
+            // Merge overlapping hits from different terms into one span
+            if next.0 < prev.1 {
+                prev.1 = prev.1.max(next.1);
+                true
+            } else {
+                false
+            }
+        });
+
+        RedactionPlan {
+            detections,
+            blocklist_spans,
+            skipped,
+        }
+    }
+
+    /// Build the redacted output for a plan in one pass over the original text
+    fn apply(&self, text: &str, plan: &RedactionPlan) -> String {
+        self.apply_in_mode(text, plan, self.policy.mode(), true)
+    }
+
+    /// `apply` with `mode` in place of the policy's; `record` logs each
+    /// redacted detection
+    fn apply_in_mode(
+        &self,
+        text: &str,
+        plan: &RedactionPlan,
+        mode: RedactionMode,
+        record: bool,
+    ) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut last_idx = 0;
+        let mut pii_counter: usize = 0;
+
+        // Merge PII detections and blocklist spans into sorted list
+        let mut all_spans: Vec<(usize, usize, RedactionKind<'_>)> =
+            Vec::with_capacity(plan.detections.len() + plan.blocklist_spans.len());
+        for d in &plan.detections {
+            all_spans.push((d.start, d.end, RedactionKind::Pii(d)));
+        }
+        for &(start, end) in &plan.blocklist_spans {
+            all_spans.push((start, end, RedactionKind::Blocklist));
+        }
+        all_spans.sort_by_key(|&(start, _, _)| start);
+
+        for (start, end, kind) in all_spans {
+            // Detector spans were checked when planned; still, an overlapping
+            // or unsliceable span is skipped rather than allowed to panic
+            if start < last_idx || text.get(start..end).is_none() {
+                continue;
+            }
+            if start > last_idx {
+                result.push_str(&text[last_idx..start]);
+            }
+
+            match kind {
+                RedactionKind::Pii(detection) => {
+                    let action = self.policy.rules().evaluate(detection, &self.policy);
+                    let mode = self.policy.type_mode(detection.pii_type).unwrap_or(mode);
+                    let redacted = if let Some(action) = action {
+                        self.redact_with_action(&detection.original, detection.pii_type, action)
+                    } else if let Some((_, generalized)) = self.generalize(detection) {
+                        generalized
+                    } else if mode == RedactionMode::Tokenize {
+                        // Tokenize mode: replace with sequential tokens
+                        let token = format!("[[PII_{}]]", pii_counter);
+                        pii_counter += 1;
+                        token
+                    } else {
+                        self.redact_structured(detection, mode)
+                    };
+                    if record {
+                        self.record(detection, &redacted);
+                    }
+                    result.push_str(&redacted);
+                }
+                RedactionKind::Blocklist => {
+                    // One mask character per visible character, so multi-byte
+                    // terms don't render wider than the text they replace
+                    result.push_str(&"█".repeat(text[start..end].chars().count()));
+                }
+            }
+
+            last_idx = end;
+        }
+
+        if last_idx < text.len() {
+            result.push_str(&text[last_idx..]);
+        }
+
+        result
+    }
+
+    fn find_placeholder_spans(&self, text: &str) -> Vec<(usize, usize)> {
+        let mut spans: Vec<(usize, usize)> = placeholder_pattern()
+            .find_iter(text)
+            .map(|m| (m.start(), m.end()))
+            .collect();
+        // Custom placeholders can be any text
+        for custom in self.policy.custom_placeholders() {
+            for (start, _) in text.match_indices(custom) {
+                spans.push((start, start + custom.len()));
+            }
+        }
+        spans
+    }
+
+    fn find_allowlist_spans(&self, text: &str) -> Vec<(usize, usize)> {
+        self.allowlist_spans_where(text, |_| true)
+    }
+
+    /// Spans of the allowlist terms accepted by `include`
+    fn allowlist_spans_where(
+        &self,
+        text: &str,
+        include: impl Fn(&str) -> bool,
+    ) -> Vec<(usize, usize)> {
+        let whole_words = self.policy.allowlist_word_boundaries();
+        let mut spans = Vec::new();
+        for term in self.policy.allowlist_terms() {
+            if !include(term) {
+                continue;
+            }
+            for (start, _) in text.match_indices(term.as_str()) {
+                let end = start + term.len();
+                if !whole_words || is_word_boundary_match(text, start, end) {
+                    spans.push((start, end));
+                }
+            }
+        }
+        spans
+    }
+
+    /// The policy's generalization of `detection`, if its type has one and
+    /// the value can be generalized. Rule actions take precedence.
+    fn generalize(&self, detection: &Detection) -> Option<(Generalization, String)> {
+        let level = self.policy.generalization().get(detection.pii_type)?;
+        level.apply(&detection.original).map(|value| (level, value))
+    }
+
+    /// Render a detection matched by a policy rule
+    fn redact_with_action(&self, original: &str, pii_type: PiiType, action: RuleAction) -> String {
+        match action {
+            RuleAction::Mask => self.redact_with_mode(original, pii_type, RedactionMode::Mask),
+            RuleAction::Label => self.policy.placeholder_for(pii_type).to_string(),
+            RuleAction::Pseudonymize => {
+                self.redact_with_mode(original, pii_type, RedactionMode::Hash)
+            }
+            // Kept detections are dropped in `plan`
+            RuleAction::Keep => original.to_string(),
+        }
+    }
+
+    fn redact_structured(&self, detection: &Detection, mode: RedactionMode) -> String {
+        let (original, pii_type) = (detection.original.as_str(), detection.pii_type);
+        // A placeholder function sees the value itself, so it goes first
+        if let Some(rendered) = self.policy.dynamic_placeholder(pii_type, original) {
+            return rendered;
+        }
+        // If a custom placeholder is configured, use it for simple replacement
+        if let Some(custom) = self.policy.custom_placeholder(pii_type) {
+            return custom.to_string();
+        }
+        // A value of unknown format can't follow its type's structured mask
+        if detection.is_opaque() {
+            match mode {
+                RedactionMode::Mask => return "█".repeat(original.chars().count()),
+                RedactionMode::FormatPreserving { mask_char } => {
+                    return original
+                        .chars()
+                        .map(|c| if c.is_alphanumeric() { mask_char } else { c })
+                        .collect();
+                }
+                _ => {}
+            }
+        }
+
+        self.redact_with_mode(original, pii_type, mode)
+    }
+
+    fn redact_with_mode(&self, original: &str, pii_type: PiiType, mode: RedactionMode) -> String {
+        match mode {
+            RedactionMode::Mask => {
+                // Default: format-preserving structured redaction
+                match pii_type {
+                    PiiType::Email => self.redact_email_structured(original),
+                    PiiType::PhoneNumber => self.redact_phone_structured(original),
+                    PiiType::Ssn => self.redact_ssn_structured(original),
+                    PiiType::CreditCard => self.redact_credit_card_structured(original),
+                    PiiType::IpAddressV4 | PiiType::IpAddressV6 => "█".repeat(original.len()),
+                    PiiType::Iban => self.redact_iban_structured(original),
+                    PiiType::PassportNumber | PiiType::UsPassport | PiiType::DriversLicense => {
+                        self.redact_passport_structured(original)
+                    }
+                    PiiType::NationalId => self.redact_national_id_structured(original),
+                    PiiType::PhysicalAddress | PiiType::Secret => "█".repeat(original.len()),
+                    PiiType::PartiallyMasked => self.redact_partial_structured(original),
+                    PiiType::MedicareBeneficiaryId => self.redact_mbi_structured(original),
+                    PiiType::AustralianAbn | PiiType::AustralianAcn => {
+                        // Digits masked, grouping spaces kept (as for phones)
+                        self.redact_phone_structured(original)
+                    }
+                    PiiType::VatNumber => self.redact_vat_structured(original),
+                    // Numbers masked; units and hemisphere kept
+                    PiiType::GpsCoordinate => original
+                        .chars()
+                        .map(|c| {
+                            if c.is_ascii_digit() || c == '.' {
+                                '█'
+                            } else {
+                                c
+                            }
+                        })
+                        .collect(),
+                    PiiType::DateOfBirth => original
+                        .chars()
+                        .map(|c| if c.is_alphanumeric() { '█' } else { c })
+                        .collect(),
+                    PiiType::BankAccountRouting => {
+                        let keep_from = original.chars().count().saturating_sub(2);
+                        original
+                            .chars()
+                            .enumerate()
+                            .map(|(i, c)| if i < keep_from { '█' } else { c })
+                            .collect()
+                    }
+                    PiiType::MacAddress => original
+                        .chars()
+                        .map(|c| if c.is_ascii_hexdigit() { '█' } else { c })
+                        .collect(),
+                    PiiType::SocialHandle => original
+                        .chars()
+                        .map(|c| if c == '@' { c } else { '█' })
+                        .collect(),
+                    // Timestamps are generalized, not masked (k-anonymity style)
+                    PiiType::Timestamp => {
+                        timestamp::generalize(original, self.policy.timestamp_granularity())
+                    }
+                    PiiType::Other(_) => "█".repeat(original.len()),
+                }
+            }
+            RedactionMode::Replace => {
+                // Full replacement with type-specific placeholder
+                self.policy.placeholder_for(pii_type).to_string()
+            }
+            RedactionMode::Hash => {
+                // Policy digest (first 8 bytes as hex for readability)
+                let digest = self.policy.hash(original.as_bytes());
+                let hex: String = digest.iter().take(8).map(|b| format!("{b:02x}")).collect();
+                format!("[HASH:{}]", hex)
+            }
             RedactionMode::Tokenize => {
                 // Tokenize with sequential numbers - handled at redact() level
                 // This fallback should not be reached
                 format!("[PII:{}]", original.len())
             }
+            RedactionMode::FixedLengthMask(len) => "█".repeat(len.max(1)),
+            RedactionMode::PartialReveal { lead, keep_last } => {
+                let visible = original.chars().filter(|c| !c.is_whitespace()).count();
+                let tail_from = visible.saturating_sub(keep_last);
+                let mut word_pos = 0;
+                let mut seen = 0;
+                original
+                    .chars()
+                    .map(|c| {
+                        if c.is_whitespace() {
+                            word_pos = 0;
+                            return c;
+                        }
+                        word_pos += 1;
+                        seen += 1;
+                        if word_pos <= lead || seen > tail_from {
+                            c
+                        } else {
+                            '█'
+                        }
+                    })
+                    .collect()
+            }
+            RedactionMode::FormatPreserving { mask_char } => {
+                let masked = self.redact_with_mode(original, pii_type, RedactionMode::Mask);
+                let keep_class = |c: char| if c.is_alphanumeric() { mask_char } else { c };
+                if masked.chars().count() == original.chars().count() {
+                    // Follow the structured mask, restoring any separators
+                    // it blanked out
+                    original
+                        .chars()
+                        .zip(masked.chars())
+                        .map(|(o, m)| if m == '█' { keep_class(o) } else { m })
+                        .collect()
+                } else {
+                    // Reshaped (e.g. a generalized timestamp): mask in place
+                    original.chars().map(keep_class).collect()
+                }
+            }
+            RedactionMode::Remove => String::new(),
+            RedactionMode::Encrypt => match self.policy.encryption_key(pii_type) {
+                Some(key) => encryption::encrypt(key, pii_type, original),
+                // Never leak a value for want of a key
+                None => self.policy.placeholder_for(pii_type).to_string(),
+            },
+            RedactionMode::Pseudonymize { len } => match self.policy.pseudonym_key() {
+                Some(key) => pseudonym::pseudonymize(key, pii_type, original, len),
+                None => self.policy.placeholder_for(pii_type).to_string(),
+            },
         }
     }
 
@@ -254,17 +1823,28 @@ impl Redactor {
             let (local, domain) = email.split_at(at_idx);
             let domain = &domain[1..];
 
-            let local_redacted: String = local
-                .chars()
-                .map(|c| if c == '.' { '.' } else { '█' })
-                .collect();
+            let target = self.policy.email_mask_target();
+            let local_redacted: String = if target == EmailMaskTarget::DomainOnly {
+                local.to_string()
+            } else {
+                local
+                    .chars()
+                    .map(|c| if c == '.' { '.' } else { '█' })
+                    .collect()
+            };
 
             let parts: Vec<&str> = domain.split('.').collect();
-            if parts.len() >= 2 {
+            if target == EmailMaskTarget::LocalOnly {
+                format!("{}@{}", local_redacted, domain)
+            } else if target == EmailMaskTarget::DomainOnly {
+                format!("{}@{}", local_redacted, "█".repeat(domain.chars().count()))
+            } else if parts.len() >= 2 {
                 let tld = parts.last().unwrap();
                 let main = parts[..parts.len() - 1].join(".");
                 let main_redacted = "█".repeat(main.len());
                 format!("{}@{}.{}", local_redacted, main_redacted, tld)
+            } else if self.policy.tldless_domain() == TldlessDomain::KeepHost {
+                format!("{}@{}", local_redacted, domain)
             } else {
                 format!("{}@{}", local_redacted, "█".repeat(domain.len()))
             }
@@ -280,6 +1860,34 @@ impl Redactor {
             .collect()
     }
 
+    /// Mask the residual digits along with the mask characters
+    fn redact_partial_structured(&self, value: &str) -> String {
+        value
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || detectors::partial_mask::is_mask_char(c) {
+                    '█'
+                } else {
+                    c
+                }
+            })
+            .collect()
+    }
+
+    /// Keep the country prefix, mask the rest: `GB 980 7806 84` → `GB ███ ████ ██`
+    fn redact_vat_structured(&self, vat: &str) -> String {
+        vat.chars()
+            .enumerate()
+            .map(|(i, c)| {
+                if i >= 2 && c.is_alphanumeric() {
+                    '█'
+                } else {
+                    c
+                }
+            })
+            .collect()
+    }
+
     fn redact_ssn_structured(&self, ssn: &str) -> String {
         ssn.chars()
             .map(|c| if c.is_ascii_digit() { '█' } else { c })
@@ -310,36 +1918,20 @@ impl Redactor {
     }
 
     fn redact_iban_structured(&self, iban: &str) -> String {
-        // Show country code and last 4 chars, redact middle
-        let cleaned: String = iban
-            .chars()
-            .filter(|c| !c.is_whitespace() && *c != '-')
-            .collect();
-        if cleaned.len() < 8 {
-            return "█".repeat(iban.len());
-        }
-
-        let country = &cleaned[0..2];
-        let last4 = &cleaned[cleaned.len() - 4..];
-        let middle_len = cleaned.len() - 6; // country(2) + check(2) + last4(4)
-
-        let mut result = String::new();
-        result.push_str(country);
-        result.push_str(&"█".repeat(middle_len));
-        result.push_str(last4);
-
-        // Preserve original spacing if any
-        if iban.contains(' ') {
-            result
-                .chars()
-                .collect::<Vec<_>>()
-                .chunks(4)
-                .map(|c| c.iter().collect::<String>())
-                .collect::<Vec<_>>()
-                .join(" ")
-        } else {
-            result
-        }
+        // Keep the country code and the grouping, mask everything else
+        let mut kept = 0;
+        iban.chars()
+            .map(|c| {
+                if !c.is_ascii_alphanumeric() {
+                    c
+                } else if kept < 2 {
+                    kept += 1;
+                    c
+                } else {
+                    '█'
+                }
+            })
+            .collect()
     }
 
     fn redact_passport_structured(&self, passport: &str) -> String {
@@ -359,6 +1951,14 @@ impl Redactor {
         result
     }
 
+    fn redact_mbi_structured(&self, mbi: &str) -> String {
+        // Show the leading digit, redact the rest (dashes preserved)
+        mbi.chars()
+            .enumerate()
+            .map(|(i, c)| if i == 0 || c == '-' { c } else { '█' })
+            .collect()
+    }
+
     fn redact_national_id_structured(&self, id: &str) -> String {
         // Show last 4 digits, redact rest
         let digits: Vec<char> = id.chars().filter(|c| c.is_ascii_digit()).collect();
@@ -384,16 +1984,300 @@ impl Redactor {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::detector::PiiDetector;
-    use crate::types::PiiType;
+/// Error from [`RedactorBuilder::build`] and [`Redactor::from_registry`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RedactorBuildError {
+    /// A type is enabled but has neither a built-in nor a custom detector
+    MissingDetector(PiiType),
+}
 
-    // Minimal email detector
-    struct SimpleEmailDetector;
-    impl PiiDetector for SimpleEmailDetector {
-        fn pii_type(&self) -> PiiType {
+impl std::fmt::Display for RedactorBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingDetector(pii_type) => {
+                write!(f, "{pii_type:?} is enabled but no detector reports it")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RedactorBuildError {}
+
+/// Error from [`Redactor::try_redact`]: a detector reported a span that
+/// can't be sliced from the text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RedactError {
+    /// `start > end`, or `end` is past the end of the text
+    OutOfBounds {
+        detector: String,
+        pii_type: PiiType,
+        start: usize,
+        end: usize,
+        text_len: usize,
+    },
+    /// `start` or `end` falls inside a multi-byte UTF-8 character
+    NotCharBoundary {
+        detector: String,
+        pii_type: PiiType,
+        start: usize,
+        end: usize,
+    },
+}
+
+impl RedactError {
+    /// Classify `detection`'s span, which must not slice `text`
+    pub(crate) fn invalid_span(detector: &str, detection: &Detection, text: &str) -> Self {
+        let (start, end) = (detection.start, detection.end);
+        if start > end || end > text.len() {
+            Self::OutOfBounds {
+                detector: detector.to_string(),
+                pii_type: detection.pii_type,
+                start,
+                end,
+                text_len: text.len(),
+            }
+        } else {
+            Self::NotCharBoundary {
+                detector: detector.to_string(),
+                pii_type: detection.pii_type,
+                start,
+                end,
+            }
+        }
+    }
+
+    /// Name of the detector that reported the span
+    pub fn detector(&self) -> &str {
+        match self {
+            Self::OutOfBounds { detector, .. } | Self::NotCharBoundary { detector, .. } => detector,
+        }
+    }
+
+    /// Type of the offending detection
+    pub fn pii_type(&self) -> PiiType {
+        match self {
+            Self::OutOfBounds { pii_type, .. } | Self::NotCharBoundary { pii_type, .. } => {
+                *pii_type
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for RedactError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OutOfBounds {
+                detector,
+                pii_type,
+                start,
+                end,
+                text_len,
+            } => write!(
+                f,
+                "{detector} reported a {pii_type:?} span {start}..{end} outside text of {text_len} bytes"
+            ),
+            Self::NotCharBoundary {
+                detector,
+                pii_type,
+                start,
+                end,
+            } => write!(
+                f,
+                "{detector} reported a {pii_type:?} span {start}..{end} that splits a UTF-8 character"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RedactError {}
+
+/// Fluent builder that keeps policy and detectors in sync.
+///
+/// Starts with no types enabled. Enabling a type registers its built-in
+/// detector; adding a custom detector enables its type. `build` fails if an
+/// enabled type still has no detector.
+///
+/// # Example
+///
+/// ```rust
+/// use auvura_core::policy::RedactionMode;
+/// use auvura_core::redactor::Redactor;
+/// use auvura_core::types::PiiType;
+///
+/// let redactor = Redactor::builder()
+///     .enable(PiiType::Email)
+///     .enable(PiiType::Ssn)
+///     .with_mode(RedactionMode::Replace)
+///     .build()
+///     .unwrap();
+/// assert_eq!(redactor.redact("ssn 123-45-6789"), "ssn [REDACTED_SSN]");
+/// ```
+pub struct RedactorBuilder {
+    policy: PolicyBuilder,
+    detectors: Vec<Box<dyn PiiDetector>>,
+    audit_logger: Arc<dyn AuditLogger>,
+    sink: Option<Arc<dyn RedactionSink>>,
+    on_invalid_span: Option<InvalidSpanHandler>,
+}
+
+impl RedactorBuilder {
+    fn new() -> Self {
+        Self {
+            policy: RedactionPolicy::builder().disable_all(),
+            detectors: Vec::new(),
+            audit_logger: Arc::new(NoopAuditLogger),
+            sink: None,
+            on_invalid_span: None,
+        }
+    }
+
+    /// Enable a type; its built-in detector is registered on `build`
+    pub fn enable(mut self, pii_type: PiiType) -> Self {
+        self.policy = self.policy.enable(pii_type);
+        self
+    }
+
+    /// Add a custom detector and enable the type it reports. A custom
+    /// detector replaces the built-in one for that type.
+    pub fn with_detector(mut self, detector: impl PiiDetector + 'static) -> Self {
+        self.policy = self.policy.enable(detector.pii_type());
+        self.detectors.push(Box::new(detector));
+        self
+    }
+
+    /// Set the global redaction mode
+    pub fn with_mode(mut self, mode: RedactionMode) -> Self {
+        self.policy = self.policy.with_mode(mode);
+        self
+    }
+
+    /// Adjust any other policy setting, e.g. allowlists or rules. Types
+    /// enabled here get built-in detectors too.
+    pub fn policy(mut self, configure: impl FnOnce(PolicyBuilder) -> PolicyBuilder) -> Self {
+        self.policy = configure(self.policy);
+        self
+    }
+
+    /// Log detections and requests to `audit_logger`
+    pub fn with_audit_logger(mut self, audit_logger: impl AuditLogger + 'static) -> Self {
+        self.audit_logger = Arc::new(audit_logger);
+        self
+    }
+
+    /// Push a [`RedactionEvent`] to `sink` for every redacted PII value
+    pub fn with_sink(mut self, sink: Arc<dyn RedactionSink>) -> Self {
+        self.sink = Some(sink);
+        self
+    }
+
+    /// See [`Redactor::on_invalid_span`]
+    pub fn on_invalid_span(
+        mut self,
+        handler: impl Fn(&RedactError) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_invalid_span = Some(Arc::new(handler));
+        self
+    }
+
+    /// Build the redactor, registering a built-in detector for every
+    /// enabled type that has no custom one
+    pub fn build(self) -> Result<Redactor, RedactorBuildError> {
+        let policy = self.policy.build();
+        let mut detectors = self.detectors;
+
+        let mut enabled: Vec<PiiType> = policy.enabled_types().collect();
+        enabled.sort_by_key(|pii_type| format!("{pii_type:?}"));
+        for pii_type in enabled {
+            if detectors.iter().any(|d| d.pii_type() == pii_type) {
+                continue;
+            }
+            let detector = detectors::default_detector(pii_type)
+                .ok_or(RedactorBuildError::MissingDetector(pii_type))?;
+            detectors.push(detector);
+        }
+
+        let mut detector = MultiDetector::new(detectors);
+        if let Some(handler) = self.on_invalid_span {
+            detector.set_invalid_span_handler(handler);
+        }
+        Ok(Redactor {
+            detector,
+            blocklist: BlocklistMatcher::new(&policy),
+            policy,
+            audit_logger: self.audit_logger,
+            sink: self.sink,
+        })
+    }
+}
+
+/// Cheaply cloneable handle to a `Redactor` shared across threads.
+///
+/// Building a `Redactor` compiles detector patterns and the anchor automaton,
+/// so services should build one up front and clone this handle per request
+/// instead. Cloning only bumps an `Arc` reference count.
+///
+/// # Example
+///
+/// ```rust
+/// use auvura_core::policy::RedactionPolicy;
+/// use auvura_core::redactor::{Redactor, SharedRedactor};
+///
+/// let shared = SharedRedactor::new(Redactor::new(vec![], RedactionPolicy::default()));
+/// let handle = shared.clone();
+/// std::thread::spawn(move || handle.redact("hello").into_owned())
+///     .join()
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct SharedRedactor(Arc<Redactor>);
+
+impl SharedRedactor {
+    /// Wrap a redactor for sharing
+    pub fn new(redactor: Redactor) -> Self {
+        Self(Arc::new(redactor))
+    }
+
+    /// Redact PII from text (passthrough to [`Redactor::redact`])
+    pub fn redact<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        self.0.redact(text)
+    }
+
+    /// Get the underlying `Arc<Redactor>`
+    pub fn as_arc(&self) -> &Arc<Redactor> {
+        &self.0
+    }
+}
+
+impl From<Redactor> for SharedRedactor {
+    fn from(redactor: Redactor) -> Self {
+        Self::new(redactor)
+    }
+}
+
+impl From<Arc<Redactor>> for SharedRedactor {
+    fn from(redactor: Arc<Redactor>) -> Self {
+        Self(redactor)
+    }
+}
+
+impl std::ops::Deref for SharedRedactor {
+    type Target = Redactor;
+
+    fn deref(&self) -> &Redactor {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detector::{PiiDetector, Span};
+    use crate::types::PiiType;
+
+    // Minimal email detector
+    struct SimpleEmailDetector;
+    impl PiiDetector for SimpleEmailDetector {
+        fn pii_type(&self) -> PiiType {
             PiiType::Email
         }
 
@@ -418,6 +2302,7 @@ mod tests {
                         start: word_start,
                         end: word_end,
                         original: text[word_start..word_end].to_string(),
+                        metadata: None,
                     });
                 }
             }
@@ -451,6 +2336,58 @@ mod tests {
         assert_eq!(result, "Contact ████.███@███████.com for help");
     }
 
+    #[test]
+    fn test_tldless_email_domain_handling() {
+        let redact = |handling: Option<TldlessDomain>, input: &str| {
+            let mut builder = RedactionPolicy::builder();
+            if let Some(handling) = handling {
+                builder = builder.tldless_domain(handling);
+            }
+            Redactor::new(vec![Box::new(SimpleEmailDetector)], builder.build())
+                .redact(input)
+                .into_owned()
+        };
+
+        // Masking the whole domain is the default
+        assert_eq!(redact(None, "to john@localhost"), "to ████@█████████");
+        assert_eq!(
+            redact(Some(TldlessDomain::Mask), "to john@localhost"),
+            "to ████@█████████"
+        );
+        assert_eq!(
+            redact(Some(TldlessDomain::KeepHost), "to john@localhost"),
+            "to ████@localhost"
+        );
+        // Domains with a TLD are unaffected
+        assert_eq!(
+            redact(Some(TldlessDomain::KeepHost), "to john@example.com"),
+            "to ████@███████.com"
+        );
+    }
+
+    #[test]
+    fn test_email_mask_target() {
+        let redact = |target: EmailMaskTarget, input: &str| {
+            let policy = RedactionPolicy::builder().email_mask_target(target).build();
+            Redactor::new(vec![Box::new(SimpleEmailDetector)], policy)
+                .redact(input)
+                .into_owned()
+        };
+
+        assert_eq!(
+            redact(EmailMaskTarget::DomainOnly, "from john.doe@corp.co.uk"),
+            "from john.doe@██████████"
+        );
+        assert_eq!(
+            redact(EmailMaskTarget::LocalOnly, "from john.doe@example.com"),
+            "from ████.███@example.com"
+        );
+        assert_eq!(
+            redact(EmailMaskTarget::Both, "from john.doe@example.com"),
+            "from ████.███@███████.com"
+        );
+    }
+
     #[test]
     fn test_allowlist_prevents_redaction() {
         let detector = SimpleEmailDetector;
@@ -498,6 +2435,95 @@ mod tests {
         assert!(!result.contains("example"));
     }
 
+    #[test]
+    fn test_iban_mask_keeps_only_country_code() {
+        use crate::detectors::iban::IbanDetector;
+        let redactor = Redactor::new(
+            vec![Box::new(IbanDetector::new())],
+            RedactionPolicy::default(),
+        );
+        assert_eq!(
+            redactor.redact("IBAN DE89 3704 0044 0532 0130 00, thanks"),
+            "IBAN DE██ ████ ████ ████ ████ ██, thanks"
+        );
+        assert_eq!(
+            redactor.redact("DE89370400440532013000"),
+            "DE████████████████████"
+        );
+        // Wrong checksum: not an IBAN
+        assert_eq!(
+            redactor.redact("DE89370400440532013001"),
+            "DE89370400440532013001"
+        );
+    }
+
+    #[test]
+    fn test_update_policy_applies_new_allowlist() {
+        let mut redactor = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::builder()
+                .with_allowlist(vec!["ops@corp.com"])
+                .build(),
+        );
+        let input = "ops@corp.com and help@corp.com";
+        assert_eq!(redactor.redact(input), "ops@corp.com and ████@████.com");
+
+        redactor.update_policy(
+            RedactionPolicy::builder()
+                .with_allowlist(vec!["help@corp.com"])
+                .with_blocklist(vec!["and"])
+                .build(),
+        );
+        assert_eq!(redactor.redact(input), "███@████.com ███ help@corp.com");
+    }
+
+    #[test]
+    fn test_allowlist_blocklist_overlap_precedence() {
+        let redact = |precedence: Option<ListPrecedence>| {
+            let mut builder = RedactionPolicy::builder()
+                .with_allowlist(vec!["support@example.com"])
+                .with_blocklist(vec!["example.com"]);
+            if let Some(precedence) = precedence {
+                builder = builder.list_precedence(precedence);
+            }
+            Redactor::new(vec![Box::new(SimpleEmailDetector)], builder.build())
+                .redact("Write support@example.com, not sales@example.com, see example.com")
+                .into_owned()
+        };
+
+        // By default the allowlisted term is protected from the broader
+        // blocklist term; blocklist hits elsewhere are still masked
+        let expected = "Write support@example.com, not █████@███████.com, see ███████████";
+        assert_eq!(redact(None), expected);
+        assert_eq!(redact(Some(ListPrecedence::AllowlistWins)), expected);
+
+        // Blocklist precedence masks the hit inside the allowlisted term,
+        // which is still exempt from PII detection
+        assert_eq!(
+            redact(Some(ListPrecedence::BlocklistWins)),
+            "Write support@███████████, not █████@███████.com, see ███████████"
+        );
+    }
+
+    #[test]
+    fn test_term_on_both_lists_is_blocked() {
+        for precedence in [ListPrecedence::AllowlistWins, ListPrecedence::BlocklistWins] {
+            let policy = RedactionPolicy::builder()
+                .with_allowlist(vec!["Falcon", "Paris"])
+                .with_blocklist(vec!["Falcon"])
+                .list_precedence(precedence)
+                .build();
+            let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+            let input = "Falcon lands in Paris, mail falcon@corp.com, Falcon again";
+            // Offsets stay anchored to the original text on every hit
+            assert_eq!(
+                redactor.redact(input),
+                "██████ lands in Paris, mail ██████@████.com, ██████ again",
+                "{precedence:?}"
+            );
+        }
+    }
+
     #[test]
     fn test_blocklist_and_pii_both_redacted() {
         let detector = SimpleEmailDetector;
@@ -577,6 +2603,7 @@ mod tests {
                                 start,
                                 end,
                                 original: text[start..end].to_string(),
+                                metadata: None,
                             });
                         }
                     }
@@ -613,6 +2640,7 @@ mod tests {
                         start,
                         end: start + 19, // length of "4111 1111 1111 1111"
                         original: "4111 1111 1111 1111".to_string(),
+                        metadata: None,
                     }];
                 }
                 vec![]
@@ -654,6 +2682,7 @@ mod tests {
                         start,
                         end: start + 16,
                         original: candidate.to_string(),
+                        metadata: None,
                     }];
                 }
                 vec![]
@@ -695,6 +2724,7 @@ mod tests {
                         start,
                         end: start + 16,
                         original: candidate.to_string(),
+                        metadata: None,
                     }];
                 }
                 vec![]
@@ -712,6 +2742,70 @@ mod tests {
         assert_ne!(result, input);
     }
 
+    #[test]
+    fn test_strict_validation_calls_validate_on_unchecked_matches() {
+        // Matches any 4x4 digit group without checking it, but knows how
+        // to validate a candidate
+        struct UncheckedCardDetector;
+        impl PiiDetector for UncheckedCardDetector {
+            fn pii_type(&self) -> PiiType {
+                PiiType::CreditCard
+            }
+            fn detect(&self, text: &str) -> Vec<Detection> {
+                regex::Regex::new(r"\d{4}(?: \d{4}){3}")
+                    .unwrap()
+                    .find_iter(text)
+                    .map(|m| Detection {
+                        pii_type: PiiType::CreditCard,
+                        confidence: crate::detector::Confidence::Medium,
+                        start: m.start(),
+                        end: m.end(),
+                        original: m.as_str().to_string(),
+                        metadata: None,
+                    })
+                    .collect()
+            }
+            fn validate(&self, candidate: &str) -> bool {
+                crate::detector::luhn_valid(candidate)
+            }
+        }
+
+        let input = "good 4111 1111 1111 1111 bad 4111 1111 1111 1112";
+        let strict = Redactor::new(
+            vec![Box::new(UncheckedCardDetector)],
+            RedactionPolicy::pci_dss(),
+        );
+        assert_eq!(
+            strict.redact(input),
+            "good ████ ████ ████ 1111 bad 4111 1111 1111 1112"
+        );
+
+        let lax = Redactor::new(
+            vec![Box::new(UncheckedCardDetector)],
+            PolicyBuilder::default()
+                .enable(PiiType::CreditCard)
+                .strict_validation(false)
+                .build(),
+        );
+        assert_eq!(
+            lax.redact(input),
+            "good ████ ████ ████ 1111 bad ████ ████ ████ 1112"
+        );
+    }
+
+    #[test]
+    fn test_pci_dss_redacts_only_luhn_valid_cards() {
+        use crate::detectors::credit_card::CreditCardDetector;
+        let redactor = Redactor::new(
+            vec![Box::new(CreditCardDetector::new())],
+            RedactionPolicy::pci_dss(),
+        );
+        assert_eq!(
+            redactor.redact("4111 1111 1111 1111 and 4111 1111 1111 1112"),
+            "████ ████ ████ 1111 and 4111 1111 1111 1112"
+        );
+    }
+
     #[test]
     fn test_custom_placeholder_overrides_structured_redaction() {
         let detector = SimpleEmailDetector;
@@ -739,6 +2833,26 @@ mod tests {
         assert_eq!(result, "Email: ***EMAIL***");
     }
 
+    #[test]
+    fn test_placeholder_fn_gives_repeats_one_token() {
+        let policy = RedactionPolicy::builder()
+            .with_placeholder(PiiType::Email, "[EMAIL]")
+            .with_placeholder_fn(
+                PiiType::Email,
+                crate::policy::hashed_placeholder(PiiType::Email),
+            )
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+
+        let result = redactor.redact("a@x.com, b@x.com, a@x.com");
+        let tokens: Vec<&str> = result.split(", ").collect();
+        assert_eq!(tokens.len(), 3);
+        assert!(tokens[0].starts_with("[EMAIL_") && tokens[0].len() == "[EMAIL_]".len() + 8);
+        assert_eq!(tokens[0], tokens[2]);
+        assert_ne!(tokens[0], tokens[1]);
+        assert!(!result.contains("a@x.com"));
+    }
+
     #[test]
     fn test_structured_redaction_used_when_no_custom_placeholder() {
         let detector = SimpleEmailDetector;
@@ -801,7 +2915,7 @@ mod tests {
     fn test_allowlist_word_boundary_prevents_redaction() {
         let detector = SimpleEmailDetector;
         let policy = RedactionPolicy::builder()
-            .with_blocklist(vec!["APPLE"])
+            .with_blocklist(vec!["APPLE pie", "PINEAPPLE"])
             .with_allowlist(vec!["APPLE"])
             .build();
         let redactor = Redactor::new(vec![Box::new(detector)], policy);
@@ -814,7 +2928,87 @@ mod tests {
         // But "APPLE" inside "PINEAPPLE" should NOT be protected
         let input2 = "I like PINEAPPLE pie";
         let result2 = redactor.redact(input2);
-        assert_eq!(result2, "I like PINEAPPLE pie");
+        assert_eq!(result2, "I like █████████ pie");
+    }
+
+    #[test]
+    fn test_blocklist_mask_width_counts_characters() {
+        let detector = SimpleEmailDetector;
+        let policy = RedactionPolicy::builder()
+            .with_blocklist(vec!["Café"])
+            .build();
+        let redactor = Redactor::new(vec![Box::new(detector)], policy);
+
+        // "Café" is 5 bytes but 4 visible characters
+        let input = "Meet at Café tomorrow";
+        let result = redactor.redact(input);
+        assert_eq!(result, "Meet at ████ tomorrow");
+        assert_eq!(result.matches('█').count(), "Café".chars().count());
+    }
+
+    #[test]
+    fn test_blocklist_masks_each_accented_occurrence_by_characters() {
+        let policy = RedactionPolicy::builder()
+            .with_blocklist(vec!["Zoë Müller-Ødegård"])
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+
+        // 18 characters, 22 bytes; every occurrence is masked in place
+        let input = "Zoë Müller-Ødegård met Zoë Müller-Ødegård.";
+        let expected = format!("{0} met {0}.", "█".repeat(18));
+        assert_eq!(redactor.redact(input), expected);
+        let (deidentified, _) = redactor.deidentify(input, "doc");
+        assert_eq!(deidentified, expected);
+    }
+
+    #[test]
+    fn test_fuzzy_blocklist_matches_typos() {
+        let policy = |distance| {
+            RedactionPolicy::builder()
+                .with_blocklist(vec!["John"])
+                .fuzzy_blocklist(distance)
+                .build()
+        };
+        let input = "Ask Jonh or Jane, cc John";
+
+        // Exact matching only by default
+        let exact = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy(0));
+        assert_eq!(exact.redact(input), "Ask Jonh or Jane, cc ████");
+
+        let fuzzy = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy(1));
+        assert_eq!(fuzzy.redact(input), "Ask ████ or Jane, cc ████");
+        assert_eq!(fuzzy.redact("Jon, Johnn, Johan"), "███, █████, █████");
+        // Words whose length differs by more than the distance never match
+        assert_eq!(fuzzy.redact("Jo Johnny"), "Jo Johnny");
+    }
+
+    #[test]
+    fn test_fuzzy_blocklist_is_bounded() {
+        let policy = RedactionPolicy::builder()
+            .with_blocklist(vec!["Bob", "John Smith"])
+            .fuzzy_blocklist(1)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+        // Short and multi-word terms only match exactly
+        assert_eq!(
+            redactor.redact("Rob met Jon Smith and Bob"),
+            "Rob met Jon Smith and ███"
+        );
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        let d = |a: &str, b: &str| {
+            edit_distance(
+                &a.chars().collect::<Vec<_>>(),
+                &b.chars().collect::<Vec<_>>(),
+            )
+        };
+        assert_eq!(d("John", "John"), 0);
+        assert_eq!(d("John", "Jonh"), 1);
+        assert_eq!(d("John", "Jon"), 1);
+        assert_eq!(d("John", "Jane"), 3);
+        assert_eq!(d("", "abc"), 3);
     }
 
     #[test]
@@ -832,6 +3026,355 @@ mod tests {
         assert_eq!(result, "███ ██████ info: TOPSECRET is not matched");
     }
 
+    #[test]
+    fn test_encrypt_mode_reveals_only_keyed_types() {
+        use crate::detectors::{email::EmailDetector, ssn::SSNDetector};
+        use crate::encryption::{reveal, Key};
+
+        let email_key = Key::from_bytes([1; 32]);
+        let ssn_key = Key::from_bytes([2; 32]);
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::Encrypt)
+            .with_encryption_key(PiiType::Email, email_key.clone())
+            .with_encryption_key(PiiType::Ssn, ssn_key.clone())
+            .build();
+        let redactor = Redactor::new(
+            vec![Box::new(EmailDetector::new()), Box::new(SSNDetector::new())],
+            policy,
+        );
+
+        let input = "jane@example.com has SSN 123-45-6789";
+        let encrypted = redactor.redact(input);
+        assert!(!encrypted.contains("jane") && !encrypted.contains("6789"));
+
+        // A viewer holding only the email key sees emails, not SSNs
+        let support = HashMap::from([(PiiType::Email, email_key.clone())]);
+        let revealed = reveal(&encrypted, &support);
+        assert!(revealed.starts_with("jane@example.com has SSN [ENC:SSN:"));
+        assert!(!revealed.contains("6789"));
+
+        // The SSN key alone doesn't open emails; both keys restore everything
+        let hr = HashMap::from([(PiiType::Ssn, ssn_key.clone())]);
+        assert!(reveal(&encrypted, &hr).ends_with("has SSN 123-45-6789"));
+        assert!(reveal(&encrypted, &hr).starts_with("[ENC:EMAIL:"));
+        let both = HashMap::from([(PiiType::Email, email_key), (PiiType::Ssn, ssn_key)]);
+        assert_eq!(reveal(&encrypted, &both), input);
+    }
+
+    #[test]
+    fn test_encrypt_mode_is_idempotent() {
+        use crate::encryption::{reveal, Key};
+
+        let key = Key::from_bytes([3; 32]);
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::Encrypt)
+            .with_encryption_key(PiiType::Email, key.clone())
+            .build();
+        let registry = DetectorRegistry::with_defaults(policy.enabled_types());
+        let redactor = Redactor::from_registry(registry, policy).unwrap();
+        let keys = HashMap::from([(PiiType::Email, key)]);
+
+        // Ciphertext hex is full of digit runs that other detectors would
+        // otherwise match on a second pass
+        for i in 0..2000 {
+            let input = format!("from user{i}@example.com");
+            let encrypted = redactor.redact(&input).into_owned();
+            assert!(encrypted.starts_with("from [ENC:EMAIL:"), "{encrypted}");
+            assert_eq!(redactor.redact(&encrypted), encrypted);
+            assert_eq!(reveal(&encrypted, &keys), input);
+        }
+    }
+
+    #[test]
+    fn test_encrypt_mode_without_key_uses_placeholder() {
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::Encrypt)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+        assert_eq!(redactor.redact("mail a@b.com"), "mail [REDACTED_EMAIL]");
+    }
+
+    #[test]
+    fn test_pseudonymize_mode_is_deterministic_per_key() {
+        use crate::pseudonym::PseudonymKey;
+
+        let redactor_with = |key: &[u8]| {
+            let policy = RedactionPolicy::builder()
+                .with_mode(RedactionMode::Pseudonymize { len: 10 })
+                .with_pseudonym_key(PseudonymKey::from_bytes(key))
+                .build();
+            Redactor::new(vec![Box::new(SimpleEmailDetector)], policy)
+        };
+        let redactor = redactor_with(b"key one");
+        let output = redactor.redact("from a@b.com to a@b.com");
+        let token = &output["from ".len().."from ".len() + "EMAIL_".len() + 10];
+        assert!(token.starts_with("EMAIL_"));
+        assert_eq!(output, format!("from {token} to {token}"));
+        assert_eq!(redactor.redact("cc a@b.com"), format!("cc {token}"));
+
+        let rekeyed = redactor_with(b"key two").redact("from a@b.com to a@b.com");
+        assert_ne!(rekeyed, output);
+
+        let keyless = RedactionPolicy::builder()
+            .with_mode(RedactionMode::Pseudonymize { len: 10 })
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], keyless);
+        assert_eq!(redactor.redact("mail a@b.com"), "mail [REDACTED_EMAIL]");
+    }
+
+    #[test]
+    fn test_max_redactions_per_type_keeps_later_detections() {
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::Replace)
+            .max_redactions_per_type(PiiType::Email, 1)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+        let input = "a@b.com, c@d.com, e@f.com";
+        assert_eq!(redactor.redact(input), "[REDACTED_EMAIL], c@d.com, e@f.com");
+
+        let report = redactor.redact_with_report(input).1;
+        let budget: Vec<_> = report
+            .entries()
+            .iter()
+            .filter(|e| e.disposition == Disposition::SkippedBudget)
+            .collect();
+        assert_eq!(budget.len(), 2);
+    }
+
+    #[test]
+    fn test_allowlist_term_does_not_protect_substring() {
+        let redactor_with = |whole_words: bool| {
+            let policy = RedactionPolicy::builder()
+                .with_mode(RedactionMode::Replace)
+                .with_allowlist(vec!["cat"])
+                .allowlist_word_boundaries(whole_words)
+                .build();
+            Redactor::new(vec![Box::new(SimpleEmailDetector)], policy)
+        };
+        let input = "ask locate@b.com";
+        assert_eq!(redactor_with(true).redact(input), "ask [REDACTED_EMAIL]");
+        assert_eq!(redactor_with(true).redact("cat@b.com"), "cat@b.com");
+        assert!(RedactionPolicy::default().allowlist_word_boundaries());
+
+        // Opting out protects every occurrence
+        assert_eq!(redactor_with(false).redact(input), input);
+    }
+
+    #[test]
+    fn test_report_records_fully_redacted_sentinel() {
+        use crate::policy::FULLY_REDACTED_SENTINEL;
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::Replace)
+            .fully_redacted_below(0.2)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+
+        let (output, report) = redactor.redact_with_report("jane@example.com");
+        assert_eq!(output, redactor.redact("jane@example.com"));
+        assert_eq!(output, FULLY_REDACTED_SENTINEL);
+        assert!(report.fully_redacted());
+        assert_eq!(report.total_redacted(), 1);
+
+        let (output, report) = redactor.redact_with_report("write to jane@example.com");
+        assert_eq!(output, "write to [REDACTED_EMAIL]");
+        assert!(!report.fully_redacted());
+    }
+
+    #[test]
+    fn test_fully_redacted_sentinel_below_ratio() {
+        use crate::policy::FULLY_REDACTED_SENTINEL;
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::Replace)
+            .fully_redacted_below(0.2)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+
+        // The whole message is one email
+        assert_eq!(
+            redactor.redact("  jane@example.com\n"),
+            FULLY_REDACTED_SENTINEL
+        );
+        assert_eq!(
+            redactor.redact_multi("jane@example.com", &[RedactionMode::Mask]),
+            vec![FULLY_REDACTED_SENTINEL]
+        );
+        // Enough context survives, or nothing was redacted
+        assert_eq!(
+            redactor.redact("write to jane@example.com"),
+            "write to [REDACTED_EMAIL]"
+        );
+        assert_eq!(redactor.redact("no pii"), "no pii");
+
+        // Off by default
+        let default = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::default(),
+        );
+        assert_eq!(default.redact("a@b.com"), "█@█.com");
+    }
+
+    #[test]
+    fn test_case_insensitive_blocklist() {
+        let redactor = |case_insensitive| {
+            let policy = RedactionPolicy::builder()
+                .with_blocklist(vec!["CONFIDENTIAL", "Ñandú", "Kelvin"])
+                .case_insensitive_blocklist(case_insensitive)
+                .build();
+            Redactor::new(vec![Box::new(SimpleEmailDetector)], policy)
+        };
+
+        let input = "confidential: Confidential, not confidentiality";
+        assert_eq!(redactor(false).redact(input), input);
+        assert_eq!(
+            redactor(true).redact(input),
+            "████████████: ████████████, not confidentiality"
+        );
+
+        // Unicode terms mask the matched characters
+        assert_eq!(redactor(true).redact("el ñANDÚ corre"), "el █████ corre");
+        // The Kelvin sign folds to `k` but is three bytes long: the masked
+        // span is the match, not the term's length
+        assert_eq!(redactor(true).redact("\u{212A}elvin scale"), "██████ scale");
+    }
+
+    /// Reader returning at most `step` bytes per call
+    struct ChunkedReader<'a> {
+        data: &'a [u8],
+        step: usize,
+    }
+
+    impl std::io::Read for ChunkedReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = self.step.min(buf.len()).min(self.data.len());
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_redact_io_email_split_across_reads() {
+        let redactor = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::default(),
+        );
+        // 8-byte reads split the email into three pieces
+        let input = "Reach: jane@example.com, thanks";
+        let reader = ChunkedReader {
+            data: input.as_bytes(),
+            step: 8,
+        };
+        let mut output = Vec::new();
+        let report = redactor.redact_io(reader, &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Reach: ████@███████.com, thanks"
+        );
+        assert_eq!(report.redacted_count(PiiType::Email), 1);
+        let entry = &report.entries()[0];
+        assert_eq!(&input[entry.start..entry.end], "jane@example.com");
+    }
+
+    #[test]
+    fn test_redact_io_matches_redact_over_many_windows() {
+        let redactor = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::builder()
+                .with_blocklist(vec!["Zoë"])
+                .stream_overlap(64)
+                .build(),
+        );
+        let mut input = String::new();
+        for i in 0..2000 {
+            input.push_str(&format!(
+                "línea {i}: Zoë wrote to user{}@example.com\n",
+                i % 7
+            ));
+        }
+        let reader = ChunkedReader {
+            data: input.as_bytes(),
+            step: 8,
+        };
+        let mut output = Vec::new();
+        let report = redactor.redact_io(reader, &mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), redactor.redact(&input));
+
+        assert_eq!(report.redacted_count(PiiType::Email), 2000);
+        assert_eq!(report.distinct_count(PiiType::Email), 7);
+        assert_eq!(report.blocklist_hits(), 2000);
+        assert!(report
+            .entries()
+            .iter()
+            .all(|e| input[e.start..e.end].ends_with("@example.com")));
+    }
+
+    #[test]
+    fn test_redact_io_rejects_invalid_utf8() {
+        let redactor = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::default(),
+        );
+        let mut output = Vec::new();
+        let err = redactor
+            .redact_io(&b"ok \xff then"[..], &mut output)
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        let err = redactor
+            .redact_io(&b"cut \xc3"[..], &mut output)
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_blocklist_automaton_matches_per_term_scan() {
+        let terms = ["Zephyr", "Café", "Q3 numbers", "Kelvin"];
+        let inputs = [
+            "no terms here, mail a@b.com",
+            "Project Zephyr and Café meet on Q3 numbers",
+            "lowercase zephyr, café, q3 NUMBERS, kelvin",
+            "ZEPHYR at CAFÉ, \u{212A}elvin",
+            "",
+        ];
+        for case_insensitive in [false, true] {
+            let policy = RedactionPolicy::builder()
+                .with_blocklist(terms.to_vec())
+                .case_insensitive_blocklist(case_insensitive)
+                .build();
+            let matcher = BlocklistMatcher::new(&policy);
+            for input in inputs {
+                let mut hits = matcher.find(input);
+                hits.sort_unstable();
+                // One scan per term, as before the automaton
+                let mut expected: Vec<(usize, usize)> = terms
+                    .iter()
+                    .map(|term| {
+                        let prefix = if case_insensitive { "(?i)" } else { "" };
+                        Regex::new(&format!("{prefix}{}", regex::escape(term))).unwrap()
+                    })
+                    .flat_map(|pattern| {
+                        pattern
+                            .find_iter(input)
+                            .map(|m| (m.start(), m.end()))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect();
+                expected.sort_unstable();
+                assert_eq!(
+                    hits, expected,
+                    "{input:?}, case-insensitive: {case_insensitive}"
+                );
+            }
+        }
+
+        // The fuzzy pass still runs when no exact term occurs
+        let policy = RedactionPolicy::builder()
+            .with_blocklist(terms.to_vec())
+            .fuzzy_blocklist(1)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+        assert_eq!(redactor.redact("Meet at Cafe"), "Meet at ████");
+    }
+
     #[test]
     fn test_redaction_mode_replace() {
         let detector = SimpleEmailDetector;
@@ -911,4 +3454,1914 @@ mod tests {
         // Custom placeholder overrides the default
         assert_eq!(result, "Contact [MAIL]");
     }
+
+    #[test]
+    fn test_redactor_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Redactor>();
+        assert_send_sync::<SharedRedactor>();
+    }
+
+    #[test]
+    fn test_shared_redactor_concurrent_redaction() {
+        let shared = SharedRedactor::new(Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::default(),
+        ));
+
+        std::thread::scope(|scope| {
+            for t in 0..8 {
+                let handle = shared.clone();
+                scope.spawn(move || {
+                    for i in 0..200 {
+                        let input = format!("thread {} msg {} from user{}@example.com", t, i, i);
+                        let result = handle.redact(&input);
+                        assert!(!result.contains("@example.com"));
+                        assert!(result.starts_with(&format!("thread {} msg {} from ", t, i)));
+                        assert!(result.ends_with("@███████.com"));
+                    }
+                });
+            }
+        });
+
+        assert_eq!(Arc::strong_count(shared.as_arc()), 1);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_redact_parallel_matches_redact() {
+        let redactor = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::default(),
+        );
+        let owned: Vec<String> = (0..64)
+            .map(|i| match i % 3 {
+                0 => format!("msg {i} from user{i}@example.com"),
+                _ => format!("msg {i} is clean"),
+            })
+            .collect();
+        let inputs: Vec<&str> = owned.iter().map(String::as_str).collect();
+
+        let outputs = redactor.redact_parallel(&inputs);
+        assert_eq!(outputs.len(), inputs.len());
+        for (input, output) in inputs.iter().zip(&outputs) {
+            assert_eq!(*output, redactor.redact(input));
+        }
+        assert!(matches!(outputs[1], Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_redaction_ranges_match_redacted_spans() {
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::Replace)
+            .with_allowlist(vec!["support@example.com"])
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+
+        let input = "Mail alice@example.com, support@example.com or bob@test.org";
+        let ranges = redactor.redaction_ranges(input);
+        assert_eq!(
+            ranges,
+            vec![(5, 22, PiiType::Email), (47, 59, PiiType::Email)]
+        );
+
+        // Re-applying the ranges by hand reproduces redact() exactly
+        let mut expected = String::new();
+        let mut last = 0;
+        for &(start, end, pii_type) in &ranges {
+            expected.push_str(&input[last..start]);
+            expected.push_str(pii_type.placeholder());
+            last = end;
+        }
+        expected.push_str(&input[last..]);
+        assert_eq!(redactor.redact(input), expected);
+    }
+
+    #[test]
+    fn test_context_suppressor_drops_detection_after_keyword() {
+        use crate::detectors::ssn::SSNDetector;
+
+        let policy = RedactionPolicy::builder()
+            .with_context_suppressor(PiiType::Ssn, "Invoice", 4)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SSNDetector::new())], policy);
+
+        assert_eq!(redactor.redact("SSN: 123-45-6789"), "SSN: ███-██-████");
+        let invoice = "Invoice 123-45-6789";
+        assert_eq!(redactor.redact(invoice), invoice);
+        assert_eq!(
+            redactor.redact("invoice #123-45-6789"),
+            "invoice #123-45-6789"
+        );
+        assert!(!redactor.contains_pii(invoice));
+        // Outside the window, or not the whole keyword, it is redacted
+        assert_eq!(
+            redactor.redact("Invoice sent, SSN 123-45-6789"),
+            "Invoice sent, SSN ███-██-████"
+        );
+        assert_eq!(
+            redactor.redact("Invoices 123-45-6789"),
+            "Invoices ███-██-████"
+        );
+
+        let (_, report) = redactor.redact_with_report(invoice);
+        assert_eq!(report.allowed_count(), 1);
+    }
+
+    #[test]
+    fn test_count_matches_redaction_ranges() {
+        let policy = RedactionPolicy::builder()
+            .with_allowlist(vec!["support@example.com"])
+            .with_blocklist(vec!["SECRET"])
+            .build();
+        let redactor = Redactor::new(
+            vec![
+                Box::new(SimpleEmailDetector),
+                Box::new(detectors::ssn::SSNDetector::new()),
+            ],
+            policy,
+        );
+
+        let input = "SECRET a@b.com, support@example.com, c@d.org, SSN 123-45-6789";
+        let mut expected: HashMap<PiiType, usize> = HashMap::new();
+        for (_, _, pii_type) in redactor.redaction_ranges(input) {
+            *expected.entry(pii_type).or_default() += 1;
+        }
+        let counts = redactor.count(input);
+        assert_eq!(counts, expected);
+        assert_eq!(counts[&PiiType::Email], 2);
+        assert_eq!(counts[&PiiType::Ssn], 1);
+        assert!(redactor.count("nothing here").is_empty());
+    }
+
+    /// Emails as `SimpleEmailDetector`, found through `detect_spans`;
+    /// counts calls to `detect`, which copies values
+    struct SpanEmailDetector(Arc<std::sync::atomic::AtomicUsize>);
+    impl PiiDetector for SpanEmailDetector {
+        fn pii_type(&self) -> PiiType {
+            PiiType::Email
+        }
+
+        fn detect(&self, text: &str) -> Vec<Detection> {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            SimpleEmailDetector.detect(text)
+        }
+
+        fn detect_spans(&self, text: &str) -> Vec<Span> {
+            SimpleEmailDetector
+                .detect(text)
+                .iter()
+                .map(Detection::span)
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_count_uses_detect_spans() {
+        use crate::detectors::{credit_card::CreditCardDetector, ip::Ipv4Detector};
+        use std::sync::atomic::Ordering;
+
+        let detect_calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let redactor = Redactor::new(
+            vec![
+                Box::new(SpanEmailDetector(Arc::clone(&detect_calls))),
+                Box::new(detectors::ssn::SSNDetector::new()),
+                Box::new(CreditCardDetector::new()),
+                Box::new(Ipv4Detector::new()),
+            ],
+            RedactionPolicy::builder()
+                .with_allowlist(vec!["support@example.com", "10.0.0.1"])
+                .build(),
+        );
+
+        let input = "a@b.com, support@example.com, c@d.org from 10.0.0.1 and \
+                     192.168.1.7, SSN 123-45-6789, 987-65-4321, card 4111111111111111";
+        let counts = redactor.count(input);
+        assert_eq!(detect_calls.load(Ordering::SeqCst), 0);
+
+        // Same as filtering the full detections `redact` works from
+        let mut expected: HashMap<PiiType, usize> = HashMap::new();
+        for (_, _, pii_type) in redactor.redaction_ranges(input) {
+            *expected.entry(pii_type).or_default() += 1;
+        }
+        assert!(detect_calls.load(Ordering::SeqCst) > 0);
+        assert_eq!(counts, expected);
+        assert_eq!(counts[&PiiType::Email], 2);
+        assert_eq!(counts[&PiiType::IpAddressV4], 1);
+        assert_eq!(counts[&PiiType::Ssn], 1);
+        assert_eq!(counts[&PiiType::CreditCard], 1);
+    }
+
+    /// Emails as `SimpleEmailDetector`; records the length of each text it scans
+    struct ScanLogDetector(Arc<std::sync::Mutex<Vec<usize>>>);
+    impl PiiDetector for ScanLogDetector {
+        fn pii_type(&self) -> PiiType {
+            PiiType::Email
+        }
+
+        fn detect(&self, text: &str) -> Vec<Detection> {
+            self.0.lock().unwrap().push(text.len());
+            SimpleEmailDetector.detect(text)
+        }
+    }
+
+    #[test]
+    fn test_redact_incremental_reuses_prior_detections_on_append() {
+        let scanned = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let redactor = Redactor::new(
+            vec![Box::new(ScanLogDetector(Arc::clone(&scanned)))],
+            RedactionPolicy::default(),
+        );
+
+        let first = format!("Contact a@b.com {}", "lorem ipsum ".repeat(30));
+        let (output, prior) = redactor.redact_incremental(&first, 0..first.len(), &[]);
+        assert_eq!(output, redactor.redact(&first));
+        assert_eq!(prior.len(), 1);
+        scanned.lock().unwrap().clear();
+
+        let appended = format!("{first}or c@d.org");
+        let dirty = first.len()..appended.len();
+        let (output, detections) = redactor.redact_incremental(&appended, dirty, &prior);
+        // Only the tail was rescanned; the first email came from `prior`
+        let tail_scans = scanned.lock().unwrap().clone();
+        assert_eq!(tail_scans.len(), 1);
+        assert!(tail_scans[0] < appended.len() / 2);
+        assert_eq!(detections.len(), 2);
+        assert_eq!(detections[0], prior[0]);
+        assert_eq!(detections[1].original, "c@d.org");
+        assert_eq!(output, redactor.redact(&appended));
+    }
+
+    #[test]
+    fn test_redact_incremental_rescans_shifted_text() {
+        let redactor = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::default(),
+        );
+        let padding = "lorem ipsum ".repeat(30);
+        let before = format!("Hi {padding}mail a@b.com");
+        let (_, prior) = redactor.redact_incremental(&before, 0..before.len(), &[]);
+
+        // Insert at the front: the email moves past the rescanned window
+        let after = format!("Hello, hi {padding}mail a@b.com");
+        let (output, detections) = redactor.redact_incremental(&after, 0..10, &prior);
+        assert_eq!(output, redactor.redact(&after));
+        assert_eq!(detections.len(), 1);
+        assert_eq!(&after[detections[0].start..detections[0].end], "a@b.com");
+    }
+
+    #[test]
+    fn test_redact_incremental_drops_priors_past_shrunk_text() {
+        let redactor = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::default(),
+        );
+        let before = "hello jane@example.com";
+        let (_, prior) = redactor.redact_incremental(before, 0..before.len(), &[]);
+        assert_eq!((prior[0].start, prior[0].end), (6, 22));
+
+        let (output, detections) = redactor.redact_incremental("hi", 0..2, &prior);
+        assert_eq!(output, "hi");
+        assert!(detections.is_empty());
+
+        // A prior starting inside a stripped BOM is dropped, not shifted below 0
+        let redactor = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::builder().strip_bom(true).build(),
+        );
+        let text = "\u{feff}a@b.com";
+        let stale = Detection {
+            start: 0,
+            end: 7,
+            original: "a@b.com".to_string(),
+            pii_type: PiiType::Email,
+            confidence: crate::detector::Confidence::Medium,
+            metadata: None,
+        };
+        let (output, detections) = redactor.redact_incremental(text, 3..text.len(), &[stale]);
+        assert_eq!(output, redactor.redact(text));
+        assert_eq!(detections.len(), 1);
+        assert_eq!((detections[0].start, detections[0].end), (3, 10));
+    }
+
+    #[test]
+    fn test_merge_adjacent_joins_split_phone_number() {
+        use crate::detector::FnDetector;
+
+        // Reports `555 123` and `4567` as two phone numbers
+        let split = FnDetector::new(PiiType::PhoneNumber, |text| {
+            let pattern = Regex::new(r"\b\d{3} \d{3}\b|\b\d{4}\b").unwrap();
+            pattern
+                .find_iter(text)
+                .map(|m| Detection {
+                    pii_type: PiiType::PhoneNumber,
+                    confidence: crate::detector::Confidence::Medium,
+                    start: m.start(),
+                    end: m.end(),
+                    original: m.as_str().to_string(),
+                    metadata: None,
+                })
+                .collect()
+        });
+        let policy = RedactionPolicy::builder()
+            .merge_adjacent(PiiType::PhoneNumber, 1)
+            .with_mode(RedactionMode::Replace)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(split)], policy);
+
+        let (output, report) = redactor.redact_with_report("call 555 123 4567 now");
+        assert_eq!(output, "call [REDACTED_PHONE] now");
+        assert_eq!(report.entries().len(), 1);
+        assert_eq!(
+            (report.entries()[0].start, report.entries()[0].end),
+            (5, 17)
+        );
+        // A wider gap, or one holding a word, keeps them apart
+        assert_eq!(
+            redactor.redact("call 555 123  4567"),
+            "call [REDACTED_PHONE]  [REDACTED_PHONE]"
+        );
+        assert_eq!(
+            redactor.redact("555 123 x 4567"),
+            "[REDACTED_PHONE] x [REDACTED_PHONE]"
+        );
+    }
+
+    #[test]
+    fn test_scan_char_offsets_count_multibyte_chars() {
+        let redactor = Redactor::new(
+            vec![
+                Box::new(SimpleEmailDetector),
+                Box::new(detectors::ssn::SSNDetector::new()),
+            ],
+            RedactionPolicy::default(),
+        );
+        // 👋 is 4 bytes and 1 char; é is 2 bytes and 1 char
+        let input = "👋 a@b.com, café SSN 123-45-6789";
+        let scanned = redactor.scan_char_offsets(input);
+        assert_eq!(scanned.len(), 2);
+
+        let (email, chars) = &scanned[0];
+        assert_eq!((email.start, email.end), (5, 12));
+        assert_eq!(*chars, (2, 9));
+        assert_eq!(email.char_range(input), (2, 9));
+        let (ssn, chars) = &scanned[1];
+        assert_eq!((ssn.start, ssn.end), (24, 35));
+        assert_eq!(*chars, (20, 31));
+        let highlighted: String = input
+            .chars()
+            .skip(chars.0)
+            .take(chars.1 - chars.0)
+            .collect();
+        assert_eq!(highlighted, "123-45-6789");
+
+        // Offsets stay in the caller's coordinates past a BOM
+        let with_bom = format!("\u{FEFF}{input}");
+        let (email, chars) = &redactor.scan_char_offsets(&with_bom)[0];
+        assert_eq!((email.start, *chars), (8, (3, 10)));
+    }
+
+    #[test]
+    fn test_redaction_ranges_exclude_blocklist() {
+        let policy = RedactionPolicy::builder()
+            .with_blocklist(vec!["SECRET"])
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+
+        let input = "SECRET: a@b.com";
+        assert_eq!(
+            redactor.redaction_ranges(input),
+            vec![(8, 15, PiiType::Email)]
+        );
+        assert!(redactor.redaction_ranges("no pii").is_empty());
+    }
+
+    #[test]
+    fn test_blocklist_inside_pii_is_not_applied_twice() {
+        let policy = RedactionPolicy::builder()
+            .with_blocklist(vec!["example"])
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+
+        let result = redactor.redact("Email: john@example.com");
+        assert_eq!(result, "Email: ████@███████.com");
+    }
+
+    #[test]
+    fn test_blocklist_hit_partly_inside_pii_masks_the_rest() {
+        let policy = RedactionPolicy::builder()
+            .with_blocklist(vec!["agent falcon"])
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+
+        let result = redactor.redact("to agent falcon@example.com");
+        assert_eq!(result, "to ████████████@███████.com");
+    }
+
+    #[test]
+    fn test_touching_a_placeholder_does_not_shield_pii() {
+        let policy = RedactionPolicy::builder()
+            .enable(PiiType::Other("REF"))
+            .build();
+        let redactor = Redactor::new(vec![Box::new(RefLineDetector)], policy);
+        // A stray `█` at the start of the value is no earlier redaction
+        let redacted = redactor.redact("Ref: █secret\n");
+        assert!(redacted.starts_with("Ref: █") && !redacted.contains("secret"));
+        assert!(redactor.contains_pii("Ref: █secret\n"));
+        // A value that is all mask is
+        assert_eq!(redactor.redact("Ref: ███\n"), "Ref: ███\n");
+
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::Replace)
+            .with_placeholder(PiiType::Email, "anon")
+            .with_blocklist(vec!["Falcon"])
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+        assert_eq!(redactor.redact("from anonjane@example.com"), "from anon");
+        assert!(!redactor.verify("from anonjane@example.com"));
+        assert_eq!(
+            redactor.redact("from anon about Falcon"),
+            "from anon about ██████"
+        );
+    }
+
+    // Matches "Ref: <value>" up to the end of the line, like a naive
+    // line-oriented detector that only knows about '\n'
+    struct RefLineDetector;
+    impl PiiDetector for RefLineDetector {
+        fn pii_type(&self) -> PiiType {
+            PiiType::Other("REF")
+        }
+        fn detect(&self, text: &str) -> Vec<Detection> {
+            text.match_indices("Ref: ")
+                .map(|(i, m)| {
+                    let start = i + m.len();
+                    let end = text[start..].find('\n').map_or(text.len(), |p| start + p);
+                    Detection {
+                        pii_type: PiiType::Other("REF"),
+                        confidence: crate::detector::Confidence::Low,
+                        start,
+                        end,
+                        original: text[start..end].to_string(),
+                        metadata: None,
+                    }
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_normalize_newlines_maps_offsets_back() {
+        let (normalized, offsets) = normalize_newlines("a\r\nb\rc\n");
+        assert_eq!(normalized, "a\nb\nc\n");
+        assert_eq!(offsets, vec![0, 1, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_normalize_newlines_keeps_crlf_in_output() {
+        let policy = RedactionPolicy::builder()
+            .enable(PiiType::Other("REF"))
+            .build();
+        let input = "Ref: AB12\r\nRef: CD34\r\n";
+
+        // Without normalization the detector swallows the '\r'
+        let redactor = Redactor::new(vec![Box::new(RefLineDetector)], policy.clone());
+        assert_eq!(redactor.redact(input), "Ref: █████\nRef: █████\n");
+
+        let policy = RedactionPolicy::builder()
+            .enable(PiiType::Other("REF"))
+            .normalize_newlines(true)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(RefLineDetector)], policy);
+        assert_eq!(redactor.redact(input), "Ref: ████\r\nRef: ████\r\n");
+        assert_eq!(
+            redactor.redaction_ranges(input),
+            vec![
+                (5, 9, PiiType::Other("REF")),
+                (16, 20, PiiType::Other("REF"))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_strip_bom() {
+        let input = "\u{FEFF}Mail a@b.com";
+
+        let redactor = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::default(),
+        );
+        assert_eq!(redactor.redact(input), "\u{FEFF}Mail █@█.com");
+
+        let policy = RedactionPolicy::builder().strip_bom(true).build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+        assert_eq!(redactor.redact(input), "Mail █@█.com");
+        assert_eq!(redactor.redact("\u{FEFF}no pii"), "no pii");
+        // Ranges stay in original coordinates (BOM is 3 bytes)
+        assert_eq!(
+            redactor.redaction_ranges(input),
+            vec![(8, 15, PiiType::Email)]
+        );
+    }
+
+    #[test]
+    fn test_keep_first_occurrence() {
+        let policy = RedactionPolicy::builder()
+            .keep_first_occurrence(true)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+
+        let input = "To a@b.com, cc a@b.com and x@y.org, bcc a@b.com";
+        let result = redactor.redact(input);
+        assert_eq!(result, "To a@b.com, cc █@█.com and x@y.org, bcc █@█.com");
+    }
+
+    #[test]
+    fn test_keep_first_occurrence_is_exempt_from_blocklist() {
+        let policy = RedactionPolicy::builder()
+            .keep_first_occurrence(true)
+            .with_blocklist(vec!["a@b.com"])
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+        assert_eq!(
+            redactor.redact("To a@b.com, cc a@b.com"),
+            "To a@b.com, cc █@█.com"
+        );
+    }
+
+    #[test]
+    fn test_keep_first_occurrence_disabled_by_default() {
+        let redactor = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::default(),
+        );
+        assert_eq!(redactor.redact("a@b.com a@b.com"), "█@█.com █@█.com");
+    }
+
+    #[test]
+    fn test_rules_label_external_keep_internal_email() {
+        use crate::detectors::email::EmailDetector;
+        use crate::rules::{Condition, Rule, RuleSet};
+
+        let rules = RuleSet::new()
+            .rule(Rule::new(
+                Condition::All(vec![
+                    Condition::Type(PiiType::Email),
+                    Condition::EmailDomain(vec!["corp.example".to_string()]),
+                ]),
+                RuleAction::Keep,
+            ))
+            .rule(Rule::new(
+                Condition::Type(PiiType::Email),
+                RuleAction::Label,
+            ));
+        let policy = RedactionPolicy::builder().with_rules(rules).build();
+        let redactor = Redactor::new(vec![Box::new(EmailDetector::new())], policy);
+
+        let input = "From jane@corp.example to bob@gmail.com and ops@eu.corp.example";
+        assert_eq!(
+            redactor.redact(input),
+            "From jane@corp.example to [REDACTED_EMAIL] and ops@eu.corp.example"
+        );
+        assert_eq!(redactor.redaction_ranges(input).len(), 1);
+    }
+
+    #[test]
+    fn test_rules_fall_back_to_policy_mode() {
+        use crate::rules::{Condition, Rule, RuleSet};
+
+        let rules =
+            RuleSet::new().rule(Rule::new(Condition::Type(PiiType::Ssn), RuleAction::Label));
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::Tokenize)
+            .with_rules(rules)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+        assert_eq!(redactor.redact("a@b.com"), "[[PII_0]]");
+    }
+
+    #[test]
+    fn test_rules_mask_and_pseudonymize_actions() {
+        use crate::rules::{Condition, Rule, RuleSet};
+
+        let rules = RuleSet::new()
+            .rule(Rule::new(Condition::Blocklisted, RuleAction::Mask))
+            .rule(Rule::new(Condition::Always, RuleAction::Pseudonymize));
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::Replace)
+            .with_blocklist(vec!["vip"])
+            .with_rules(rules)
+            .with_hash_key([5; 32])
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+
+        let result = redactor.redact("vip@b.com x@y.org");
+        let hash = blake3::keyed_hash(&[5; 32], b"x@y.org").to_hex();
+        assert_eq!(result, format!("███@█.com [HASH:{}]", &hash[..16]));
+    }
+
+    #[test]
+    fn test_placeholders_pass_through_unchanged() {
+        let policy = RedactionPolicy::builder()
+            .with_blocklist(vec!["REDACTED_EMAIL", "HASH"])
+            .with_placeholder(PiiType::PhoneNumber, "<phone>")
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+
+        let input = "to [REDACTED_EMAIL] or ████@███████.com, id [HASH:0123456789abcdef] \
+                     [[PII_3]] <phone>";
+        let result = redactor.redact(input);
+        assert!(matches!(result, Cow::Borrowed(_)));
+        assert!(redactor.verify(input));
+    }
+
+    #[test]
+    fn test_verify_reports_pii() {
+        let redactor = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::default(),
+        );
+        assert!(!redactor.verify("mail a@b.com"));
+        assert!(redactor.verify("no pii here"));
+        assert!(redactor.verify(""));
+    }
+
+    /// Reports nothing; counts how often it was run
+    struct CountingDetector(Arc<std::sync::atomic::AtomicUsize>);
+    impl PiiDetector for CountingDetector {
+        fn pii_type(&self) -> PiiType {
+            PiiType::Email
+        }
+
+        fn detect(&self, _text: &str) -> Vec<Detection> {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn test_contains_pii_stops_at_first_hit() {
+        use crate::detectors::credit_card::CreditCardDetector;
+        use std::sync::atomic::Ordering;
+
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let redactor = Redactor::new(
+            vec![
+                Box::new(CreditCardDetector::new()),
+                Box::new(CountingDetector(Arc::clone(&calls))),
+            ],
+            RedactionPolicy::default(),
+        );
+
+        let body = r#"{"amount": 12, "card": "4111 1111 1111 1111"}"#;
+        assert!(redactor.contains_pii(body));
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        assert!(!redactor.contains_pii(r#"{"amount": 12, "note": "thanks"}"#));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(!redactor.contains_pii(""));
+    }
+
+    #[test]
+    fn test_redact_with_vault_round_trips() {
+        use crate::detectors::{email::EmailDetector, ssn::SSNDetector};
+
+        let redactor = Redactor::new(
+            vec![Box::new(EmailDetector::new()), Box::new(SSNDetector::new())],
+            RedactionPolicy::default(),
+        );
+        let input = "From a@b.com to c@d.org, cc a@b.com: SSN 123-45-6789";
+        let (redacted, vault) = redactor.redact_with_vault(input);
+        assert_eq!(
+            redacted,
+            "From ⟪tok_0001⟫ to ⟪tok_0002⟫, cc ⟪tok_0001⟫: SSN ⟪tok_0003⟫"
+        );
+        assert_eq!(vault.len(), 3);
+        assert_eq!(vault.restore(&redacted), input);
+
+        // Tokens are left alone by a second pass
+        assert!(matches!(redactor.redact(&redacted), Cow::Borrowed(_)));
+
+        let (clean, vault) = redactor.redact_with_vault("nothing here");
+        assert!(matches!(clean, Cow::Borrowed(_)));
+        assert!(vault.is_empty());
+    }
+
+    #[test]
+    fn test_deidentify_manifest_matches_redactions() {
+        use crate::detectors::{email::EmailDetector, ssn::SSNDetector};
+
+        let redactor = Redactor::new(
+            vec![Box::new(EmailDetector::new()), Box::new(SSNDetector::new())],
+            RedactionPolicy::builder()
+                .with_blocklist(vec!["Acme"])
+                .build(),
+        );
+        let input = "jane@example.com (SSN 123-45-6789) wrote to bob@corp.org \
+                     at Acme; cc jane@example.com";
+        let (output, manifest) = redactor.deidentify(input, "study-42/doc-7");
+
+        assert_eq!(
+            output,
+            "[REDACTED_EMAIL_1] (SSN [REDACTED_SSN_1]) wrote to [REDACTED_EMAIL_2] \
+             at ████; cc [REDACTED_EMAIL_1]"
+        );
+        assert_eq!(manifest.doc_id(), "study-42/doc-7");
+
+        // One entry per PII redaction, at the same spans
+        let ranges = redactor.redaction_ranges(input);
+        assert_eq!(manifest.len(), ranges.len());
+        for (entry, &(start, end, pii_type)) in manifest.entries().iter().zip(&ranges) {
+            assert_eq!(
+                (entry.start, entry.end, entry.pii_type),
+                (start, end, pii_type)
+            );
+            assert!(output.contains(&entry.replacement));
+        }
+
+        // Hashes, not values; repeats share a hash and a token
+        let dump = format!("{manifest:?}");
+        for raw in [
+            "jane@example.com",
+            "123-45-6789",
+            "bob@corp.org",
+            "jane",
+            "6789",
+        ] {
+            assert!(!dump.contains(raw), "manifest leaks {raw}");
+        }
+        let hash: String = RedactionPolicy::default()
+            .hash(b"jane@example.com")
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        assert_ne!(hash, blake3::hash(b"jane@example.com").to_hex().as_str());
+        let hits: Vec<&str> = manifest
+            .lookup(&hash)
+            .map(|e| e.replacement.as_str())
+            .collect();
+        assert_eq!(hits, vec!["[REDACTED_EMAIL_1]", "[REDACTED_EMAIL_1]"]);
+    }
+
+    #[test]
+    fn test_fixed_length_mask_hides_value_length() {
+        let policy = RedactionPolicy::builder()
+            .enable(PiiType::Secret)
+            .with_mode(RedactionMode::FixedLengthMask(8))
+            .build();
+        let redactor = Redactor::new(
+            vec![Box::new(crate::detectors::secret::SecretKeyDetector::new())],
+            policy,
+        );
+
+        let short = redactor.redact("password=abc");
+        let long = redactor.redact("password=correct-horse-battery-staple");
+        assert_eq!(short, "password=████████");
+        assert_eq!(long, short);
+        // Already-masked output is left alone
+        assert_eq!(redactor.redact(&short), short);
+    }
+
+    #[test]
+    fn test_fixed_length_mask_applies_to_structured_types() {
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::FixedLengthMask(0))
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+        assert_eq!(redactor.redact("to jane.doe@example.com"), "to █");
+    }
+
+    #[test]
+    fn test_fixed_length_mask_gives_emails_one_width() {
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::FixedLengthMask(6))
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+        let short = redactor.redact("a@b.io");
+        let long = redactor.redact("jonathan.richardson@subsidiary.example.com");
+        assert_eq!(short, "██████");
+        assert_eq!(short, long);
+    }
+
+    #[test]
+    fn test_partial_reveal_keeps_leading_characters() {
+        let redactor = |lead: usize| {
+            let policy = RedactionPolicy::builder()
+                .with_mode(RedactionMode::PartialReveal { lead, keep_last: 0 })
+                .build();
+            Redactor::new(
+                vec![Box::new(LyingDetector(vec![(8, 16), (21, 28)]))],
+                policy,
+            )
+        };
+        let input = "Patient John Doe, dr Garrido";
+
+        // First character of each word in a name-like token
+        assert_eq!(redactor(1).redact(input), "Patient J███ D██, dr G██████");
+        // First two, and a lead longer than the word reveals all of it
+        assert_eq!(redactor(2).redact(input), "Patient Jo██ Do█, dr Ga█████");
+        assert_eq!(redactor(8).redact(input), input);
+        // Zero reveals nothing
+        assert_eq!(redactor(0).redact(input), "Patient ████ ███, dr ███████");
+    }
+
+    #[test]
+    fn test_partial_reveal_applies_to_structured_types() {
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::PartialReveal {
+                lead: 2,
+                keep_last: 0,
+            })
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+        assert_eq!(
+            redactor.redact("to jane@example.com"),
+            "to ja██████████████"
+        );
+    }
+
+    #[test]
+    fn test_format_preserving_keeps_shape() {
+        use crate::detectors::{
+            credit_card::CreditCardDetector, email::EmailDetector, ip::Ipv4Detector,
+            phone_number::PhoneNumberDetector,
+        };
+
+        let redactor = |mask_char| {
+            let policy = RedactionPolicy::builder()
+                .with_mode(RedactionMode::FormatPreserving { mask_char })
+                .build();
+            Redactor::new(
+                vec![
+                    Box::new(CreditCardDetector::new()),
+                    Box::new(EmailDetector::new()),
+                    Box::new(PhoneNumberDetector::new()),
+                    Box::new(Ipv4Detector::new()),
+                ],
+                policy,
+            )
+        };
+        let x = redactor('X');
+        assert_eq!(x.redact("call (555) 123-4567"), "call (XXX) XXX-XXXX");
+        assert_eq!(
+            x.redact("card 4111 1111 1111 1111"),
+            "card XXXX XXXX XXXX 1111"
+        );
+        assert_eq!(x.redact("mail jane.doe@corp.com"), "mail XXXX.XXX@XXXX.com");
+        // Types `Mask` blanks out entirely still keep their separators
+        assert_eq!(x.redact("host 10.0.0.1"), "host XX.X.X.X");
+        assert_eq!(
+            redactor('#').redact("call (555) 123-4567"),
+            "call (###) ###-####"
+        );
+    }
+
+    #[test]
+    fn test_per_type_modes_mixed_in_one_string() {
+        use crate::detectors::{
+            credit_card::CreditCardDetector, email::EmailDetector,
+            phone_number::PhoneNumberDetector, ssn::SSNDetector,
+        };
+
+        let policy = RedactionPolicy::builder()
+            .with_mode_for(PiiType::Email, RedactionMode::Replace)
+            .with_mode_for(PiiType::Ssn, RedactionMode::Remove)
+            .with_mode_for(
+                PiiType::CreditCard,
+                RedactionMode::PartialReveal {
+                    lead: 0,
+                    keep_last: 4,
+                },
+            )
+            .with_placeholder(PiiType::PhoneNumber, "<phone>")
+            .build();
+        let redactor = Redactor::new(
+            vec![
+                Box::new(EmailDetector::new()),
+                Box::new(SSNDetector::new()),
+                Box::new(CreditCardDetector::new()),
+                Box::new(PhoneNumberDetector::new()),
+            ],
+            policy,
+        );
+        let input =
+            "jane@example.com, ssn 123-45-6789, card 4111 1111 1111 1111, tel +1 202 555 0123";
+        assert_eq!(
+            redactor.redact(input),
+            "[REDACTED_EMAIL], ssn , card ████ ████ ████ 1111, tel <phone>"
+        );
+
+        // Types without an override use the global mode
+        let redactor = Redactor::new(
+            vec![Box::new(EmailDetector::new()), Box::new(SSNDetector::new())],
+            RedactionPolicy::builder()
+                .with_mode(RedactionMode::Replace)
+                .with_mode_for(PiiType::Ssn, RedactionMode::Mask)
+                .build(),
+        );
+        assert_eq!(
+            redactor.redact("a@b.com 123-45-6789"),
+            "[REDACTED_EMAIL] ███-██-████"
+        );
+    }
+
+    #[test]
+    fn test_no_op_modes_keep_borrowed_fast_path() {
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::PartialReveal {
+                lead: 64,
+                keep_last: 0,
+            })
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+        let input = "to jane@example.com";
+        assert!(matches!(redactor.redact(input), Cow::Borrowed(s) if s == input));
+    }
+
+    #[test]
+    fn test_redact_multi_matches_single_mode_outputs() {
+        use crate::detectors::{email::EmailDetector, ssn::SSNDetector};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let redactor_in = |mode: RedactionMode| {
+            Redactor::new(
+                vec![Box::new(EmailDetector::new()), Box::new(SSNDetector::new())],
+                RedactionPolicy::builder().with_mode(mode).build(),
+            )
+        };
+        let input = "jane@example.com filed 123-45-6789";
+        let counter = Arc::new(AtomicUsize::new(0));
+        let redactor = Redactor::new(
+            vec![
+                Box::new(EmailDetector::new()),
+                Box::new(SSNDetector::new()),
+                Box::new(CountingDetector(counter.clone())),
+            ],
+            RedactionPolicy::default(),
+        );
+
+        let outputs = redactor.redact_multi(input, &[RedactionMode::Mask, RedactionMode::Replace]);
+        assert_eq!(
+            outputs,
+            vec![
+                "████@███████.com filed ███-██-████".to_string(),
+                "[REDACTED_EMAIL] filed [REDACTED_SSN]".to_string(),
+            ]
+        );
+        // Detection ran once for both outputs
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        for (mode, output) in [RedactionMode::Mask, RedactionMode::Replace]
+            .into_iter()
+            .zip(&outputs)
+        {
+            assert_eq!(&redactor_in(mode).redact(input), output);
+        }
+
+        assert!(redactor.redact_multi(input, &[]).is_empty());
+        assert_eq!(
+            redactor.redact_multi("clean", &[RedactionMode::Tokenize]),
+            vec!["clean".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_sink_receives_one_event_per_redaction() {
+        use crate::detectors::{email::EmailDetector, ssn::SSNDetector};
+        use std::sync::Mutex;
+
+        #[derive(Default)]
+        struct CollectingSink(Mutex<Vec<RedactionEvent>>);
+        impl RedactionSink for CollectingSink {
+            fn on_detection(&self, event: &RedactionEvent) {
+                self.0.lock().unwrap().push(*event);
+            }
+        }
+
+        let sink = Arc::new(CollectingSink::default());
+        let redactor = Redactor::builder()
+            .with_detector(EmailDetector::new())
+            .with_detector(SSNDetector::new())
+            .with_sink(sink.clone())
+            .build()
+            .unwrap();
+        let input = "jane@example.com, ssn 123-45-6789, again jane@example.com";
+        redactor.redact(input);
+
+        let events = sink.0.lock().unwrap().clone();
+        let spans: Vec<(PiiType, usize, usize)> = events
+            .iter()
+            .map(|e| (e.pii_type, e.start, e.end))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                (PiiType::Email, 0, 16),
+                (PiiType::Ssn, 22, 33),
+                (PiiType::Email, 41, 57),
+            ]
+        );
+        assert_eq!(events[1].basis, PiiType::Ssn.regulatory_basis());
+
+        // Nothing redacted, nothing pushed
+        redactor.redact("no pii here");
+        assert_eq!(sink.0.lock().unwrap().len(), 3);
+
+        // Also available on a directly constructed redactor
+        let sink = Arc::new(CollectingSink::default());
+        let redactor = Redactor::new(
+            vec![Box::new(SSNDetector::new())],
+            RedactionPolicy::default(),
+        )
+        .with_sink(sink.clone());
+        redactor.redact("123-45-6789");
+        assert_eq!(sink.0.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_regulatory_footprint() {
+        use crate::detectors::{credit_card::CreditCardDetector, email::EmailDetector};
+
+        let redactor = Redactor::new(
+            vec![
+                Box::new(EmailDetector::new()),
+                Box::new(CreditCardDetector::new()),
+            ],
+            RedactionPolicy::default(),
+        );
+        let footprint = redactor.regulatory_footprint(
+            "Receipt for jane@example.com, card 4111 1111 1111 1111, cc bob@corp.org",
+        );
+        assert_eq!(
+            footprint.into_iter().collect::<Vec<_>>(),
+            vec![
+                PiiType::Email.regulatory_basis(),
+                PiiType::CreditCard.regulatory_basis(),
+            ]
+        );
+        assert!(redactor.regulatory_footprint("no pii").is_empty());
+
+        // Only types the policy acts on count
+        let card_disabled = Redactor::new(
+            vec![Box::new(CreditCardDetector::new())],
+            RedactionPolicy::builder()
+                .disable(PiiType::CreditCard)
+                .build(),
+        );
+        assert!(card_disabled
+            .regulatory_footprint("card 4111 1111 1111 1111")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_deidentify_clean_text() {
+        let redactor = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::default(),
+        );
+        let (output, manifest) = redactor.deidentify("nothing here", "doc");
+        assert_eq!(output, "nothing here");
+        assert!(manifest.is_empty());
+        // Output is already de-identified: tokens are not re-redacted
+        let (again, manifest) = redactor.deidentify("[REDACTED_EMAIL_1]", "doc");
+        assert_eq!(again, "[REDACTED_EMAIL_1]");
+        assert!(manifest.is_empty());
+    }
+
+    #[test]
+    fn test_contains_pii_respects_allowlist_and_validation() {
+        use crate::detectors::credit_card::CreditCardDetector;
+
+        let card = || -> Vec<Box<dyn PiiDetector>> { vec![Box::new(CreditCardDetector::new())] };
+        let allowing = RedactionPolicy::builder()
+            .with_allowlist(vec!["4111 1111 1111 1111"])
+            .build();
+        assert!(!Redactor::new(card(), allowing).contains_pii("card 4111 1111 1111 1111"));
+
+        // Fails Luhn: only PII when validation is off
+        let invalid = "card 4111 1111 1111 1112";
+        let strict = Redactor::new(card(), RedactionPolicy::default());
+        assert!(!strict.contains_pii(invalid));
+        let lenient = RedactionPolicy::builder().strict_validation(false).build();
+        assert!(Redactor::new(card(), lenient).contains_pii(invalid));
+
+        // Disabled types and earlier placeholders are not PII
+        let disabled = RedactionPolicy::builder()
+            .disable(PiiType::CreditCard)
+            .build();
+        assert!(!Redactor::new(card(), disabled).contains_pii("card 4111 1111 1111 1111"));
+        assert!(!strict.contains_pii("card [REDACTED_CC]"));
+    }
+
+    #[test]
+    fn test_custom_hasher_drives_hash_tokens() {
+        // Trivial test hasher: reversed input bytes
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::Hash)
+            .with_hasher(|value| value.iter().rev().copied().collect())
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+
+        // "a@b.com" reversed is "moc.b@a" -> first 8 bytes as hex
+        assert_eq!(redactor.redact("a@b.com"), "[HASH:6d6f632e624061]");
+        // Tokens from a custom hasher are still recognized as placeholders
+        assert!(redactor.verify("[HASH:6d6f632e624061]"));
+    }
+
+    #[test]
+    fn test_hash_key_changes_tokens() {
+        let keyed = |key| {
+            let policy = RedactionPolicy::builder()
+                .with_mode(RedactionMode::Hash)
+                .with_hash_key(key)
+                .build();
+            Redactor::new(vec![Box::new(SimpleEmailDetector)], policy)
+                .redact("a@b.com")
+                .into_owned()
+        };
+        let default_key = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::builder()
+                .with_mode(RedactionMode::Hash)
+                .build(),
+        )
+        .redact("a@b.com")
+        .into_owned();
+
+        let expected = blake3::keyed_hash(&[7; 32], b"a@b.com").to_hex();
+        assert_eq!(keyed([7; 32]), format!("[HASH:{}]", &expected[..16]));
+        assert_ne!(keyed([7; 32]), keyed([8; 32]));
+        assert_ne!(keyed([7; 32]), default_key);
+        // The default key is random, not a fixed public one
+        let unkeyed = blake3::hash(b"a@b.com").to_hex();
+        assert_ne!(default_key, format!("[HASH:{}]", &unkeyed[..16]));
+    }
+
+    #[test]
+    fn test_report_counts_reformatted_phone_once() {
+        use crate::detectors::phone_number::PhoneNumberDetector;
+
+        let redactor = Redactor::new(
+            vec![Box::new(PhoneNumberDetector::new())],
+            RedactionPolicy::default(),
+        );
+        let (result, report) =
+            redactor.redact_with_report("call 555-867-5309 or (555) 867-5309 today");
+        // Canonicalization affects counting only, not output
+        assert_eq!(result, "call ███-███-████ or (███) ███-████ today");
+        assert_eq!(report.redacted_count(PiiType::PhoneNumber), 2);
+        assert_eq!(report.distinct_count(PiiType::PhoneNumber), 1);
+    }
+
+    #[test]
+    fn test_allow_predicate_passes_domain_emails_through() {
+        use crate::detectors::email::EmailDetector;
+
+        let policy = RedactionPolicy::builder()
+            .with_allow_predicate(|pii_type, value| {
+                pii_type == PiiType::Email && value.ends_with("@mycompany.com")
+            })
+            .build();
+        let redactor = Redactor::new(vec![Box::new(EmailDetector::new())], policy);
+
+        let input = "From jane@mycompany.com to bob@gmail.com";
+        let (result, report) = redactor.redact_with_report(input);
+        assert_eq!(result, "From jane@mycompany.com to ███@█████.com");
+
+        assert_eq!(report.redacted_count(PiiType::Email), 1);
+        assert_eq!(report.allowed_count(), 1);
+        let allowed = &report.entries()[0];
+        assert_eq!(allowed.disposition, Disposition::SkippedAllowlist);
+        assert_eq!(&input[allowed.start..allowed.end], "jane@mycompany.com");
+    }
+
+    #[test]
+    fn test_report_summary_counts_two_emails_one_ssn() {
+        use crate::detectors::{email::EmailDetector, ssn::SSNDetector};
+
+        let policy = RedactionPolicy::builder()
+            .with_allowlist(vec!["help@corp.com"])
+            .with_blocklist(vec!["Project X"])
+            .build();
+        let redactor = Redactor::new(
+            vec![Box::new(EmailDetector::new()), Box::new(SSNDetector::new())],
+            policy,
+        );
+
+        let input = "help@corp.com, jane@example.com, SSN 123-45-6789 re Project X";
+        let (result, report) = redactor.redact_with_report(input);
+        assert_eq!(
+            result,
+            "help@corp.com, ████@███████.com, SSN ███-██-████ re █████████"
+        );
+        assert_eq!(report.redacted_count(PiiType::Email), 1);
+        assert_eq!(report.redacted_count(PiiType::Ssn), 1);
+        assert_eq!(report.allowed_count(), 1);
+        assert!(report.blocklist_applied());
+        let masked = "jane@example.com".len() + "123-45-6789".len() + "Project X".len();
+        assert_eq!(report.bytes_masked(), masked);
+
+        let summary = report.summary();
+        assert_eq!(
+            summary,
+            format!(
+                "auvura-report redacted=2 allowlisted=1 skipped=0 blocklist=1 \
+                 bytes_masked={masked} [REDACTED_EMAIL]=1 [REDACTED_SSN]=1"
+            )
+        );
+        assert!(!summary.contains("jane") && !summary.contains("6789"));
+    }
+
+    #[test]
+    fn test_report_includes_static_allowlist_skips() {
+        let policy = RedactionPolicy::builder()
+            .with_allowlist(vec!["a@b.com"])
+            .strip_bom(true)
+            .build();
+        let redactor = Redactor::new(vec![Box::new(SimpleEmailDetector)], policy);
+
+        let input = "\u{FEFF}a@b.com x@y.org";
+        let (result, report) = redactor.redact_with_report(input);
+        assert_eq!(result, "a@b.com █@█.org");
+        assert_eq!(report.total_redacted(), 1);
+        assert_eq!(report.allowed_count(), 1);
+        // Offsets refer to the input as given, BOM included
+        let redacted = &report.entries()[1];
+        assert_eq!(&input[redacted.start..redacted.end], "x@y.org");
+    }
+
+    #[test]
+    fn test_report_ignores_existing_placeholders() {
+        let redactor = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::default(),
+        );
+        let (result, report) = redactor.redact_with_report("to █@█.com");
+        assert!(matches!(result, Cow::Borrowed(_)));
+        assert!(report.entries().is_empty());
+    }
+
+    #[test]
+    fn test_detector_metadata_survives_into_report() {
+        struct TaggedDetector;
+        impl PiiDetector for TaggedDetector {
+            fn pii_type(&self) -> PiiType {
+                PiiType::Other("ticket")
+            }
+            fn detect(&self, text: &str) -> Vec<Detection> {
+                text.match_indices("TCK-42")
+                    .map(|(start, m)| Detection {
+                        pii_type: PiiType::Other("ticket"),
+                        confidence: self.confidence(),
+                        start,
+                        end: start + m.len(),
+                        original: m.to_string(),
+                        metadata: Some([("rule_id".to_string(), "tickets-v2".to_string())].into()),
+                    })
+                    .collect()
+            }
+        }
+
+        let policy = RedactionPolicy::builder()
+            .enable(PiiType::Other("ticket"))
+            .build();
+        let redactor = Redactor::new(vec![Box::new(TaggedDetector)], policy);
+
+        let (result, report) = redactor.redact_with_report("see TCK-42");
+        // Metadata does not affect output
+        assert_eq!(result, "see ██████");
+
+        let metadata = report.entries()[0].metadata.as_ref().unwrap();
+        assert_eq!(
+            metadata.get("rule_id").map(String::as_str),
+            Some("tickets-v2")
+        );
+    }
+
+    #[test]
+    fn test_report_explains_skip_reasons() {
+        use crate::detectors::{
+            credit_card::CreditCardDetector, email::EmailDetector, ip::Ipv4Detector,
+        };
+
+        let policy = RedactionPolicy::builder()
+            .with_allowlist(vec!["ops@example.com"])
+            .disable(PiiType::IpAddressV4)
+            .strict_validation(true)
+            .build();
+        let redactor = Redactor::new(
+            vec![
+                Box::new(EmailDetector::new()),
+                Box::new(Ipv4Detector::new()),
+                Box::new(CreditCardDetector::new()),
+            ],
+            policy,
+        );
+
+        let input = "ops@example.com paged jane@corp.org from 10.1.2.3 \
+                     re card 4111 1111 1111 1112";
+        let (result, report) = redactor.redact_with_report(input);
+        assert_eq!(
+            result,
+            "ops@example.com paged ████@████.org from 10.1.2.3 \
+             re card 4111 1111 1111 1112"
+        );
+
+        let reasons: Vec<(&str, Disposition)> = report
+            .entries()
+            .iter()
+            .map(|e| (&input[e.start..e.end], e.disposition))
+            .collect();
+        assert_eq!(
+            reasons,
+            vec![
+                ("ops@example.com", Disposition::SkippedAllowlist),
+                ("jane@corp.org", Disposition::Redacted),
+                ("10.1.2.3", Disposition::SkippedDisabledType),
+                ("4111 1111 1111 1112", Disposition::SkippedValidationFailed),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_redact_bytes_skips_invalid_utf8() {
+        use crate::detectors::ssn::SSNDetector;
+
+        let redactor = Redactor::new(
+            vec![Box::new(SSNDetector::new()), Box::new(SimpleEmailDetector)],
+            RedactionPolicy::default(),
+        );
+        let input = b"id 123-45-6789\xff\xfe ok \xc3(";
+        let result = redactor.redact_bytes(input);
+        let masked = redactor.redact("123-45-6789");
+        assert_ne!(masked, "123-45-6789");
+        let mut expected = b"id ".to_vec();
+        expected.extend_from_slice(masked.as_bytes());
+        expected.extend_from_slice(b"\xff\xfe ok \xc3(");
+        assert_eq!(result, expected);
+
+        // Invalid bytes split runs, and untouched input is borrowed
+        assert!(matches!(
+            redactor.redact_bytes(b"a\xff@b.com"),
+            Cow::Owned(ref v) if v.starts_with(b"a\xff")
+        ));
+        assert!(matches!(
+            redactor.redact_bytes(b"no pii \xff here"),
+            Cow::Borrowed(_)
+        ));
+        assert!(redactor.redact_bytes(b"").is_empty());
+    }
+
+    #[test]
+    fn test_min_confidence_skips_weaker_detections() {
+        use crate::detector::Confidence;
+        use crate::detectors::address::AddressDetector;
+
+        let input = "mail jane@corp.org, street address 123 Main Street";
+        let detectors = || -> Vec<Box<dyn PiiDetector>> {
+            vec![
+                Box::new(SimpleEmailDetector),
+                Box::new(AddressDetector::new()),
+            ]
+        };
+        let all = Redactor::new(detectors(), RedactionPolicy::default());
+        assert!(!all.redact(input).contains("Main Street"));
+
+        let policy = RedactionPolicy::builder()
+            .min_confidence(PiiType::PhysicalAddress, Confidence::Medium)
+            .min_confidence(PiiType::Email, Confidence::High)
+            .build();
+        let redactor = Redactor::new(detectors(), policy);
+        let (result, report) = redactor.redact_with_report(input);
+        assert!(!result.contains("jane"));
+        assert!(result.ends_with("123 Main Street"));
+        let address = report
+            .entries()
+            .iter()
+            .find(|e| e.pii_type == PiiType::PhysicalAddress)
+            .unwrap();
+        assert_eq!(address.disposition, Disposition::SkippedLowConfidence);
+        assert!(!redactor.contains_pii("street address 123 Main Street"));
+    }
+
+    #[test]
+    fn test_report_validation_failures_only_under_strict_mode() {
+        use crate::detectors::credit_card::CreditCardDetector;
+
+        let input = "card 4111 1111 1111 1112";
+        for strict in [true, false] {
+            let policy = RedactionPolicy::builder().strict_validation(strict).build();
+            let redactor = Redactor::new(vec![Box::new(CreditCardDetector::new())], policy);
+            let (_, report) = redactor.redact_with_report(input);
+            let expected = if strict {
+                Disposition::SkippedValidationFailed
+            } else {
+                Disposition::Redacted
+            };
+            assert_eq!(report.entries().len(), 1);
+            assert_eq!(report.entries()[0].disposition, expected);
+        }
+    }
+
+    fn quasi_identifier_redactor(policy: RedactionPolicy) -> Redactor {
+        use crate::detectors::custom_regex::{CustomRegexConfig, CustomRegexDetector};
+
+        let detector = |name: &str, pattern: &str| -> Box<dyn PiiDetector> {
+            Box::new(
+                CustomRegexDetector::from_config(&CustomRegexConfig {
+                    name: name.to_string(),
+                    pattern: pattern.to_string(),
+                    placeholder: "[REDACTED]".to_string(),
+                    confidence: "high".to_string(),
+                    flags: None,
+                    group: None,
+                    context_keywords: Vec::new(),
+                })
+                .unwrap(),
+            )
+        };
+        Redactor::new(
+            vec![
+                detector("ZIP", r"\b\d{5}\b"),
+                detector("DOB", r"\b\d{4}-\d{2}-\d{2}\b"),
+                Box::new(SimpleEmailDetector),
+            ],
+            policy,
+        )
+    }
+
+    #[test]
+    fn test_generalizes_zip_and_dob() {
+        use crate::generalization::GeneralizationPolicy;
+
+        let policy = RedactionPolicy::builder()
+            .enable(PiiType::Other("ZIP"))
+            .enable(PiiType::Other("DOB"))
+            .with_generalization(
+                GeneralizationPolicy::new()
+                    .generalize(PiiType::Other("ZIP"), Generalization::KeepLeadingDigits(3))
+                    .generalize(PiiType::Other("DOB"), Generalization::Year),
+            )
+            .build();
+        let redactor = quasi_identifier_redactor(policy);
+
+        let input = "zip 94107 dob 1985-03-14 mail a@b.com";
+        let (result, report) = redactor.redact_with_report(input);
+        assert_eq!(result, "zip 941** dob 1985 mail █@█.com");
+
+        let applied: Vec<(&str, Generalization)> = report
+            .generalizations()
+            .map(|(e, level)| (&input[e.start..e.end], level))
+            .collect();
+        assert_eq!(
+            applied,
+            vec![
+                ("94107", Generalization::KeepLeadingDigits(3)),
+                ("1985-03-14", Generalization::Year),
+            ]
+        );
+        assert_eq!(report.total_redacted(), 1);
+    }
+
+    #[test]
+    fn test_rules_take_precedence_over_generalization() {
+        use crate::generalization::GeneralizationPolicy;
+        use crate::rules::{Condition, Rule, RuleSet};
+
+        let policy = RedactionPolicy::builder()
+            .enable(PiiType::Other("DOB"))
+            .with_mode(RedactionMode::Replace)
+            .with_generalization(
+                GeneralizationPolicy::new().generalize(PiiType::Other("DOB"), Generalization::Year),
+            )
+            .with_rules(RuleSet::new().rule(Rule::new(
+                Condition::Type(PiiType::Other("DOB")),
+                RuleAction::Mask,
+            )))
+            .build();
+        let redactor = quasi_identifier_redactor(policy);
+
+        let (result, report) = redactor.redact_with_report("dob 1985-03-14");
+        assert_eq!(result, "dob ██████████");
+        assert_eq!(report.generalizations().count(), 0);
+        assert_eq!(report.total_redacted(), 1);
+    }
+
+    /// Reports fixed spans with a fabricated `original`
+    struct LyingDetector(Vec<(usize, usize)>);
+
+    impl PiiDetector for LyingDetector {
+        fn pii_type(&self) -> PiiType {
+            PiiType::Email
+        }
+
+        fn detect(&self, _text: &str) -> Vec<Detection> {
+            self.0
+                .iter()
+                .map(|&(start, end)| Detection {
+                    pii_type: PiiType::Email,
+                    confidence: self.confidence(),
+                    start,
+                    end,
+                    original: "someone.much.longer@example.com".to_string(),
+                    metadata: None,
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_mismatched_original_is_resliced_from_text() {
+        let input = "mail a@b.com now";
+        let redactor = Redactor::new(
+            vec![Box::new(LyingDetector(vec![(5, 12)]))],
+            RedactionPolicy::default(),
+        );
+
+        let result = redactor.redact(input);
+        // The mask is built from the span's real bytes, not the stale value
+        assert_eq!(result, "mail █@█.com now");
+        assert_eq!(result.chars().count(), input.chars().count());
+        assert_eq!(
+            redactor.redaction_ranges(input),
+            vec![(5, 12, PiiType::Email)]
+        );
+    }
+
+    #[test]
+    fn test_invalid_detector_spans_are_dropped() {
+        let input = "naïve a@b.com";
+        let redactor = Redactor::new(
+            // Out of bounds, reversed, empty, and inside the multi-byte `ï`
+            vec![Box::new(LyingDetector(vec![
+                (7, 99),
+                (9, 8),
+                (4, 4),
+                (3, 5),
+            ]))],
+            RedactionPolicy::default(),
+        );
+        assert_eq!(redactor.redact(input), input);
+    }
+
+    #[test]
+    fn test_try_redact_reports_invalid_spans() {
+        let input = "naïve a@b.com";
+        let try_redact = |spans| {
+            Redactor::new(
+                vec![Box::new(LyingDetector(spans))],
+                RedactionPolicy::default(),
+            )
+            .try_redact(input)
+            .map(Cow::into_owned)
+        };
+
+        let out_of_bounds = try_redact(vec![(7, 99)]).unwrap_err();
+        assert_eq!(
+            out_of_bounds,
+            RedactError::OutOfBounds {
+                detector: "LyingDetector".to_string(),
+                pii_type: PiiType::Email,
+                start: 7,
+                end: 99,
+                text_len: 14,
+            }
+        );
+        assert_eq!(
+            out_of_bounds.to_string(),
+            "LyingDetector reported a Email span 7..99 outside text of 14 bytes"
+        );
+        assert!(matches!(
+            try_redact(vec![(9, 8)]),
+            Err(RedactError::OutOfBounds { .. })
+        ));
+
+        // Inside the two-byte `ï`
+        let split = try_redact(vec![(3, 5)]).unwrap_err();
+        assert!(matches!(
+            split,
+            RedactError::NotCharBoundary {
+                start: 3,
+                end: 5,
+                ..
+            }
+        ));
+        assert_eq!(
+            (split.detector(), split.pii_type()),
+            ("LyingDetector", PiiType::Email)
+        );
+
+        // Valid spans redact exactly as `redact` does
+        assert_eq!(try_redact(vec![(7, 14)]).unwrap(), "naïve █@█.com");
+        let redactor = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::default(),
+        );
+        assert_eq!(redactor.try_redact(input).unwrap(), redactor.redact(input));
+    }
+
+    #[test]
+    fn test_invalid_span_handler_reports_char_offsets() {
+        use std::sync::Mutex;
+
+        // A detector counting chars: `a@b.com` is chars 6..13, bytes 7..14
+        let input = "naïve a@b.com";
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&reported);
+        let redactor = Redactor::new(
+            vec![Box::new(LyingDetector(vec![(6, 13), (3, 5)]))],
+            RedactionPolicy::default(),
+        )
+        .on_invalid_span(move |err| log.lock().unwrap().push(err.clone()));
+
+        // (6, 13) happens to slice, one byte early; only the split `ï` is
+        // reported
+        assert_eq!(redactor.redact(input), "naïve██@█.com");
+        let reported = reported.lock().unwrap();
+        assert_eq!(reported.len(), 1);
+        assert!(matches!(
+            reported[0],
+            RedactError::NotCharBoundary {
+                start: 3,
+                end: 5,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_random_detector_offsets_never_panic() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let input = "héllo 👋 wörld 🎉 a@b.com 日本語 \u{2066}x@y.org\u{2069} 👨‍👩‍👧";
+        let mut seed: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut next = move |bound: usize| {
+            // xorshift64
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed % bound as u64) as usize
+        };
+        for _ in 0..500 {
+            let spans: Vec<(usize, usize)> = (0..1 + next(4))
+                .map(|_| (next(input.len() + 4), next(input.len() + 4)))
+                .collect();
+            let invalid = spans
+                .iter()
+                .filter(|&&(start, end)| input.get(start..end).is_none())
+                .count();
+            let reported = Arc::new(AtomicUsize::new(0));
+            let count = Arc::clone(&reported);
+            let redactor = Redactor::new(
+                vec![Box::new(LyingDetector(spans.clone()))],
+                RedactionPolicy::builder()
+                    .with_blocklist(vec!["wörld"])
+                    .build(),
+            )
+            .on_invalid_span(move |_| {
+                count.fetch_add(1, Ordering::Relaxed);
+            });
+
+            let redacted = redactor.redact(input);
+            assert_eq!(reported.load(Ordering::Relaxed), invalid, "{spans:?}");
+            assert!(!redacted.contains("wörld"), "{spans:?}");
+            assert_eq!(redactor.try_redact(input).is_err(), invalid > 0);
+            let _ = redactor.redact_with_report(input);
+            let _ = redactor.scan_char_offsets(input);
+            let _ = redactor.redact_incremental(input, 0..input.len(), &[]);
+        }
+    }
+
+    #[test]
+    fn test_bidi_controls_trimmed_from_span_edges() {
+        // `\u{2066}a@b.com\u{2069}`: the detector's span includes both isolates
+        let input = "mail \u{2066}a@b.com\u{2069} now";
+        let redactor = Redactor::new(
+            vec![Box::new(LyingDetector(vec![(5, 18), (5, 8)]))],
+            RedactionPolicy::default(),
+        );
+        assert_eq!(redactor.redact(input), "mail \u{2066}█@█.com\u{2069} now");
+        assert_eq!(
+            redactor.redaction_ranges(input),
+            vec![(8, 15, PiiType::Email)]
+        );
+    }
+
+    #[test]
+    fn test_builder_registers_builtin_and_custom_detectors() {
+        use crate::detectors::custom_regex::{CustomRegexConfig, CustomRegexDetector};
+
+        let employee_id = CustomRegexDetector::from_config(&CustomRegexConfig {
+            name: "EMP".to_string(),
+            pattern: r"\bEMP-\d{6}\b".to_string(),
+            placeholder: "[EMP]".to_string(),
+            confidence: "high".to_string(),
+            flags: None,
+            group: None,
+            context_keywords: Vec::new(),
+        })
+        .unwrap();
+        let redactor = Redactor::builder()
+            .enable(PiiType::Email)
+            .enable(PiiType::Ssn)
+            .with_detector(employee_id)
+            .with_mode(RedactionMode::Replace)
+            .build()
+            .unwrap();
+
+        let (result, report) =
+            redactor.redact_with_report("jane@example.com EMP-123456 ssn 123-45-6789");
+        assert_eq!(
+            result,
+            "[REDACTED_EMAIL] [REDACTED_OTHER] ssn [REDACTED_SSN]"
+        );
+        assert_eq!(report.redacted_count(PiiType::Email), 1);
+        assert_eq!(report.redacted_count(PiiType::Other("EMP")), 1);
+        assert_eq!(report.redacted_count(PiiType::Ssn), 1);
+        // Types that weren't enabled stay off
+        assert_eq!(
+            redactor.redact("4111 1111 1111 1111"),
+            "4111 1111 1111 1111"
+        );
+    }
+
+    #[test]
+    fn test_builder_rejects_enabled_type_without_detector() {
+        let err = Redactor::builder()
+            .enable(PiiType::Email)
+            .policy(|p| p.enable(PiiType::Other("PERSON")))
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(
+            err,
+            RedactorBuildError::MissingDetector(PiiType::Other("PERSON"))
+        );
+        assert_eq!(
+            err.to_string(),
+            "Other(\"PERSON\") is enabled but no detector reports it"
+        );
+    }
+
+    #[test]
+    fn test_from_registry_skips_disabled_types() {
+        use crate::detectors::ssn::SSNDetector;
+        use std::sync::atomic::Ordering;
+
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let registry = DetectorRegistry::new()
+            .register(CountingDetector(Arc::clone(&calls)))
+            .register(SSNDetector::new());
+        let policy = RedactionPolicy::builder()
+            .disable_all()
+            .enable(PiiType::Ssn)
+            .build();
+        let redactor = Redactor::from_registry(registry, policy).unwrap();
+
+        assert_eq!(
+            redactor.redact("a@b.com 123-45-6789"),
+            "a@b.com ███-██-████"
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_from_registry_rejects_enabled_type_without_detector() {
+        let registry = DetectorRegistry::with_defaults([PiiType::Email]);
+        let policy = RedactionPolicy::builder()
+            .disable_all()
+            .enable(PiiType::Email)
+            .enable(PiiType::Iban)
+            .build();
+        let err = Redactor::from_registry(registry, policy).err();
+        assert_eq!(
+            err,
+            Some(RedactorBuildError::MissingDetector(PiiType::Iban))
+        );
+    }
+
+    #[test]
+    fn test_builder_policy_closure_types_get_detectors() {
+        let redactor = Redactor::builder()
+            .policy(|p| p.enable(PiiType::Ssn).with_allowlist(vec!["000-00-0000"]))
+            .build()
+            .unwrap();
+        assert_eq!(redactor.redact("123-45-6789"), "███-██-████");
+    }
+
+    #[test]
+    fn test_profile_has_entry_per_detector() {
+        use crate::detectors::{email::EmailDetector, ssn::SSNDetector};
+
+        let redactor = Redactor::new(
+            vec![
+                Box::new(EmailDetector::new()),
+                Box::new(SSNDetector::new()),
+                Box::new(SimpleEmailDetector),
+            ],
+            RedactionPolicy::default(),
+        );
+        let input = "a@b.com and 123-45-6789";
+        let (result, profile) = redactor.redact_profiled(input);
+        assert_eq!(result, redactor.redact(input));
+
+        let names: Vec<&str> = profile.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["EmailDetector", "SSNDetector", "SimpleEmailDetector"]
+        );
+        let counts: Vec<usize> = profile.iter().map(|p| p.detections).collect();
+        assert_eq!(counts, vec![1, 1, 1]);
+
+        // Detectors are profiled even when nothing is found
+        let (result, profile) = redactor.redact_profiled("");
+        assert_eq!(result, redactor.redact(""));
+        assert_eq!(profile.len(), 3);
+        assert!(profile.iter().all(|p| p.detections == 0));
+
+        // An all-PII input gets the fully-redacted sentinel, as from `redact`
+        let redactor = Redactor::new(
+            vec![Box::new(EmailDetector::new())],
+            RedactionPolicy::builder().fully_redacted_below(0.2).build(),
+        );
+        let (result, _) = redactor.redact_profiled("a@b.com");
+        assert_eq!(result, redactor.redact("a@b.com"));
+        assert_eq!(result, "[DOCUMENT FULLY REDACTED]");
+    }
+
+    #[test]
+    fn test_redact_range_only_touches_selection() {
+        let redactor = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::default(),
+        );
+        let input = "first a@b.com then c@d.com end";
+        let second = input.find("c@d.com").unwrap();
+
+        let result = redactor.redact_range(input, second..second + "c@d.com".len());
+        assert_eq!(result, "first a@b.com then █@█.com end");
+
+        // Nothing detected in the selection: zero-copy
+        assert!(matches!(
+            redactor.redact_range(input, 0..5),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn test_redact_range_clips_detections_to_selection() {
+        let redactor = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::default(),
+        );
+        // Selection covers only "a@b" of "a@b.com": the detection can't
+        // reach past the range
+        let input = "x a@b.com";
+        assert_eq!(redactor.redact_range(input, 2..5), "x █@█.com");
+    }
+
+    #[test]
+    fn test_redact_range_ignores_invalid_ranges() {
+        let redactor = Redactor::new(
+            vec![Box::new(SimpleEmailDetector)],
+            RedactionPolicy::default(),
+        );
+        let input = "é a@b.com";
+        for (start, end) in [(1, 4), (5, 3), (3, 100)] {
+            let result = redactor.redact_range(input, start..end);
+            assert!(matches!(result, Cow::Borrowed(text) if text == input));
+        }
+    }
+
+    #[test]
+    fn test_scan_returns_what_redact_rewrites() {
+        let policy = RedactionPolicy::builder()
+            .with_mode(RedactionMode::Replace)
+            .with_allowlist(vec!["support@example.com"])
+            .build();
+        let redactor = Redactor::new(
+            vec![
+                Box::new(SimpleEmailDetector),
+                Box::new(detectors::ssn::SSNDetector::new()),
+            ],
+            policy,
+        );
+
+        let input = "a@b.com, support@example.com, SSN 123-45-6789";
+        let detections = redactor.scan(input);
+        let spans: Vec<(usize, usize, PiiType)> = detections
+            .iter()
+            .map(|d| (d.start, d.end, d.pii_type))
+            .collect();
+        assert_eq!(spans, vec![(0, 7, PiiType::Email), (34, 45, PiiType::Ssn)]);
+        assert_eq!(detections[0].original, "a@b.com");
+
+        // Rewriting exactly the scanned spans gives `redact`'s output
+        let mut expected = input.to_string();
+        for &(start, end, pii_type) in spans.iter().rev() {
+            expected.replace_range(start..end, pii_type.placeholder());
+        }
+        assert_eq!(redactor.redact(input), expected);
+        assert!(expected.contains("support@example.com"));
+        assert!(redactor.scan("nothing here").is_empty());
+    }
 }
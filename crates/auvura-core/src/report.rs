@@ -0,0 +1,419 @@
+//! Per-request redaction report
+//!
+//! [`RedactionReport`] records every candidate detection a redaction saw and
+//! what happened to it. Entries carry type, position, and confidence only –
+//! never the matched text – so a report is safe to log.
+//!
+//! Distinct-value counts are computed from hashes of canonicalized values
+//! (`(555) 867-5309` and `555-867-5309` are one phone number); neither the
+//! hashes nor the canonical forms are kept in the report.
+
+use crate::{
+    detector::{Confidence, Detection, DetectionMetadata},
+    generalization::Generalization,
+    types::PiiType,
+};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use zeroize::Zeroize;
+
+/// What the redactor did with a candidate detection, and why.
+///
+/// Variants are a stable set of reason codes: they can be matched on and
+/// logged by name (see [`Disposition::code`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Disposition {
+    /// The detection was redacted
+    Redacted,
+    /// The detection was coarsened by the policy's generalization instead
+    /// of redacted; carries the level applied
+    Generalized(Generalization),
+    /// The detection was allowed through by an allowlist term, a context
+    /// suppressor, or the policy's allow predicate
+    SkippedAllowlist,
+    /// A detector found it, but its PII type is not enabled by the policy
+    SkippedDisabledType,
+    /// The pattern matched but the value failed strict validation
+    /// (checksum or structural check)
+    SkippedValidationFailed,
+    /// The detection's confidence was below the policy's `min_confidence`
+    SkippedLowConfidence,
+    /// The type's `max_redactions_per_type` limit was already reached
+    SkippedBudget,
+}
+
+impl Disposition {
+    /// Stable snake_case code, e.g. `"skipped_validation_failed"`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Redacted => "redacted",
+            Self::Generalized(_) => "generalized",
+            Self::SkippedAllowlist => "skipped_allowlist",
+            Self::SkippedDisabledType => "skipped_disabled_type",
+            Self::SkippedValidationFailed => "skipped_validation_failed",
+            Self::SkippedLowConfidence => "skipped_low_confidence",
+            Self::SkippedBudget => "skipped_budget",
+        }
+    }
+}
+
+/// One candidate detection, without its original value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportEntry {
+    pub pii_type: PiiType,
+    pub confidence: Confidence,
+    /// UTF-8 byte offset in the input text
+    pub start: usize,
+    /// UTF-8 byte offset in the input text
+    pub end: usize,
+    pub disposition: Disposition,
+    /// Tags the detector attached to the detection, if any
+    pub metadata: Option<DetectionMetadata>,
+}
+
+impl ReportEntry {
+    pub(crate) fn new(detection: &Detection, disposition: Disposition) -> Self {
+        Self {
+            pii_type: detection.pii_type,
+            confidence: detection.confidence,
+            start: detection.start,
+            end: detection.end,
+            disposition,
+            metadata: detection.metadata.clone(),
+        }
+    }
+}
+
+/// Summary of one `Redactor::redact_with_report` call
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RedactionReport {
+    entries: Vec<ReportEntry>,
+    distinct: HashMap<PiiType, usize>,
+    blocklist_hits: usize,
+    blocklist_bytes: usize,
+    fully_redacted: bool,
+}
+
+impl RedactionReport {
+    pub(crate) fn new(mut entries: Vec<ReportEntry>) -> Self {
+        entries.sort_by_key(|e| (e.start, e.end));
+        Self {
+            entries,
+            ..Self::default()
+        }
+    }
+
+    /// Record the blocklist hits masked alongside the detections
+    pub(crate) fn with_blocklist_spans(mut self, spans: &[(usize, usize)]) -> Self {
+        self.blocklist_hits = spans.len();
+        self.blocklist_bytes = spans.iter().map(|(start, end)| end - start).sum();
+        self
+    }
+
+    /// Record that the output was replaced by the fully-redacted sentinel
+    pub(crate) fn with_fully_redacted(mut self, fully_redacted: bool) -> Self {
+        self.fully_redacted = fully_redacted;
+        self
+    }
+
+    /// Record distinct-value counts for the redacted `detections`
+    pub(crate) fn with_distinct_values(self, detections: &[Detection]) -> Self {
+        let seen: HashSet<DistinctKey> = detections.iter().map(distinct_key).collect();
+        self.with_distinct_keys(&seen)
+    }
+
+    /// Record distinct-value counts from keys collected with [`distinct_key`]
+    pub(crate) fn with_distinct_keys(mut self, seen: &HashSet<DistinctKey>) -> Self {
+        self.distinct.clear();
+        for (pii_type, _) in seen {
+            *self.distinct.entry(*pii_type).or_default() += 1;
+        }
+        self
+    }
+
+    /// All candidate detections, sorted by position
+    pub fn entries(&self) -> &[ReportEntry] {
+        &self.entries
+    }
+
+    /// Number of redacted detections of `pii_type`
+    pub fn redacted_count(&self, pii_type: PiiType) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| e.pii_type == pii_type && e.disposition == Disposition::Redacted)
+            .count()
+    }
+
+    /// Total number of redacted detections
+    pub fn total_redacted(&self) -> usize {
+        self.count(Disposition::Redacted)
+    }
+
+    /// Number of detections allowed through by allowlist or predicate
+    pub fn allowed_count(&self) -> usize {
+        self.count(Disposition::SkippedAllowlist)
+    }
+
+    /// Generalized detections and the level applied to each
+    pub fn generalizations(&self) -> impl Iterator<Item = (&ReportEntry, Generalization)> {
+        self.entries.iter().filter_map(|e| match e.disposition {
+            Disposition::Generalized(level) => Some((e, level)),
+            _ => None,
+        })
+    }
+
+    /// Number of distinct redacted values of `pii_type`, after
+    /// canonicalization (so reformatted repeats count once)
+    pub fn distinct_count(&self, pii_type: PiiType) -> usize {
+        self.distinct.get(&pii_type).copied().unwrap_or(0)
+    }
+
+    /// Number of distinct redacted values across all types
+    pub fn total_distinct(&self) -> usize {
+        self.distinct.values().sum()
+    }
+
+    /// Number of entries with the given disposition
+    pub fn count(&self, disposition: Disposition) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| e.disposition == disposition)
+            .count()
+    }
+
+    /// Number of blocklist terms masked
+    pub fn blocklist_hits(&self) -> usize {
+        self.blocklist_hits
+    }
+
+    /// Whether the blocklist masked anything
+    pub fn blocklist_applied(&self) -> bool {
+        self.blocklist_hits > 0
+    }
+
+    /// Whether so little of the input survived that the output is the
+    /// policy's fully-redacted sentinel
+    pub fn fully_redacted(&self) -> bool {
+        self.fully_redacted
+    }
+
+    /// Input bytes replaced by redacted detections and blocklist hits
+    pub fn bytes_masked(&self) -> usize {
+        let redacted: usize = self
+            .entries
+            .iter()
+            .filter(|e| e.disposition == Disposition::Redacted)
+            .map(|e| e.end - e.start)
+            .sum();
+        redacted + self.blocklist_bytes
+    }
+
+    /// One-line audit string with stable `key=value` fields, e.g.
+    ///
+    /// ```text
+    /// auvura-report redacted=2 allowlisted=1 skipped=0 blocklist=0 bytes_masked=27 [REDACTED_EMAIL]=1 [REDACTED_SSN]=1
+    /// ```
+    ///
+    /// Per-type counts of redacted detections follow, keyed by the type's
+    /// default placeholder and sorted by it.
+    pub fn summary(&self) -> String {
+        let allowlisted = self.allowed_count();
+        let skipped = self
+            .entries
+            .iter()
+            .filter(|e| e.disposition.code().starts_with("skipped_"))
+            .count()
+            - allowlisted;
+        let mut line = format!(
+            "auvura-report redacted={} allowlisted={allowlisted} skipped={skipped} blocklist={} bytes_masked={}",
+            self.total_redacted(),
+            self.blocklist_hits,
+            self.bytes_masked(),
+        );
+        let mut per_type: Vec<(&'static str, usize)> = Vec::new();
+        for e in &self.entries {
+            if e.disposition != Disposition::Redacted {
+                continue;
+            }
+            let name = e.pii_type.placeholder();
+            match per_type.iter_mut().find(|(n, _)| *n == name) {
+                Some((_, count)) => *count += 1,
+                None => per_type.push((name, 1)),
+            }
+        }
+        per_type.sort_unstable();
+        for (name, count) in per_type {
+            line.push_str(&format!(" {name}={count}"));
+        }
+        line
+    }
+}
+
+/// Type and hash of a canonicalized value, for distinct counting
+pub(crate) type DistinctKey = (PiiType, [u8; 32]);
+
+/// Distinct-counting key for a detection; the value itself isn't kept
+pub(crate) fn distinct_key(detection: &Detection) -> DistinctKey {
+    let mut canonical = canonical_value(detection.pii_type, &detection.original);
+    let hash = *blake3::hash(canonical.as_bytes()).as_bytes();
+    if let Cow::Owned(value) = &mut canonical {
+        value.zeroize();
+    }
+    (detection.pii_type, hash)
+}
+
+/// Canonical form of a value for distinct counting only – never used for
+/// redaction output
+fn canonical_value(pii_type: PiiType, value: &str) -> Cow<'_, str> {
+    match pii_type {
+        PiiType::PhoneNumber
+        | PiiType::CreditCard
+        | PiiType::Ssn
+        | PiiType::AustralianAbn
+        | PiiType::AustralianAcn => value.chars().filter(char::is_ascii_digit).collect(),
+        PiiType::Iban | PiiType::MedicareBeneficiaryId => value
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .map(|c| c.to_ascii_uppercase())
+            .collect(),
+        PiiType::Email | PiiType::IpAddressV6 | PiiType::SocialHandle => {
+            Cow::Owned(value.to_lowercase())
+        }
+        _ => Cow::Borrowed(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(pii_type: PiiType, start: usize, disposition: Disposition) -> ReportEntry {
+        ReportEntry {
+            pii_type,
+            confidence: Confidence::High,
+            start,
+            end: start + 5,
+            disposition,
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn test_entries_sorted_by_position() {
+        let report = RedactionReport::new(vec![
+            entry(PiiType::Ssn, 20, Disposition::Redacted),
+            entry(PiiType::Email, 3, Disposition::SkippedAllowlist),
+        ]);
+        assert_eq!(report.entries()[0].start, 3);
+        assert_eq!(report.entries()[1].start, 20);
+    }
+
+    #[test]
+    fn test_counts() {
+        let report = RedactionReport::new(vec![
+            entry(PiiType::Email, 0, Disposition::Redacted),
+            entry(PiiType::Email, 10, Disposition::SkippedAllowlist),
+            entry(PiiType::Email, 20, Disposition::Redacted),
+            entry(PiiType::Ssn, 30, Disposition::Redacted),
+        ]);
+        assert_eq!(report.redacted_count(PiiType::Email), 2);
+        assert_eq!(report.redacted_count(PiiType::Ssn), 1);
+        assert_eq!(report.redacted_count(PiiType::Iban), 0);
+        assert_eq!(report.total_redacted(), 3);
+        assert_eq!(report.allowed_count(), 1);
+    }
+
+    #[test]
+    fn test_disposition_codes_are_distinct() {
+        let all = [
+            Disposition::Redacted,
+            Disposition::Generalized(Generalization::Year),
+            Disposition::SkippedAllowlist,
+            Disposition::SkippedDisabledType,
+            Disposition::SkippedValidationFailed,
+            Disposition::SkippedLowConfidence,
+            Disposition::SkippedBudget,
+        ];
+        let codes: std::collections::HashSet<_> = all.iter().map(Disposition::code).collect();
+        assert_eq!(codes.len(), all.len());
+        assert_eq!(
+            Disposition::SkippedDisabledType.code(),
+            "skipped_disabled_type"
+        );
+    }
+
+    fn detection(pii_type: PiiType, original: &str) -> Detection {
+        Detection {
+            pii_type,
+            confidence: Confidence::High,
+            start: 0,
+            end: original.len(),
+            original: original.to_string(),
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn test_distinct_counts_canonicalize_formatting() {
+        let report = RedactionReport::default().with_distinct_values(&[
+            detection(PiiType::PhoneNumber, "555-867-5309"),
+            detection(PiiType::PhoneNumber, "(555) 867-5309"),
+            detection(PiiType::PhoneNumber, "555-867-0000"),
+            detection(PiiType::Email, "Jane@Example.com"),
+            detection(PiiType::Email, "jane@example.com"),
+            detection(PiiType::CreditCard, "4111 1111 1111 1111"),
+            detection(PiiType::CreditCard, "4111-1111-1111-1111"),
+        ]);
+        assert_eq!(report.distinct_count(PiiType::PhoneNumber), 2);
+        assert_eq!(report.distinct_count(PiiType::Email), 1);
+        assert_eq!(report.distinct_count(PiiType::CreditCard), 1);
+        assert_eq!(report.distinct_count(PiiType::Ssn), 0);
+        assert_eq!(report.total_distinct(), 4);
+    }
+
+    #[test]
+    fn test_canonicalization_is_per_type() {
+        // Same digits under different types are different values
+        let report = RedactionReport::default().with_distinct_values(&[
+            detection(PiiType::PhoneNumber, "123-45-6789"),
+            detection(PiiType::Ssn, "123-45-6789"),
+        ]);
+        assert_eq!(report.total_distinct(), 2);
+        // Types without a canonical form compare exactly
+        assert_eq!(
+            canonical_value(PiiType::PhysicalAddress, "1 Main St"),
+            "1 Main St"
+        );
+    }
+
+    #[test]
+    fn test_empty_report() {
+        let report = RedactionReport::default();
+        assert!(report.entries().is_empty());
+        assert_eq!(report.total_redacted(), 0);
+        assert!(!report.blocklist_applied());
+        assert_eq!(
+            report.summary(),
+            "auvura-report redacted=0 allowlisted=0 skipped=0 blocklist=0 bytes_masked=0"
+        );
+    }
+
+    #[test]
+    fn test_summary_and_bytes_masked() {
+        let report = RedactionReport::new(vec![
+            entry(PiiType::Ssn, 0, Disposition::Redacted),
+            entry(PiiType::Email, 10, Disposition::Redacted),
+            entry(PiiType::Email, 20, Disposition::SkippedAllowlist),
+            entry(PiiType::Iban, 30, Disposition::SkippedValidationFailed),
+            entry(PiiType::Email, 40, Disposition::Redacted),
+        ])
+        .with_blocklist_spans(&[(50, 56)]);
+        assert!(report.blocklist_applied());
+        assert_eq!(report.bytes_masked(), 3 * 5 + 6);
+        assert_eq!(
+            report.summary(),
+            "auvura-report redacted=3 allowlisted=1 skipped=1 blocklist=1 bytes_masked=21 \
+             [REDACTED_EMAIL]=2 [REDACTED_SSN]=1"
+        );
+    }
+}
@@ -0,0 +1,249 @@
+//! Declarative redaction rules
+//!
+//! A [`RuleSet`] is an ordered list of [`Rule`]s evaluated per detection.
+//! The first rule whose [`Condition`] matches decides the [`RuleAction`];
+//! detections no rule matches fall back to the policy's global
+//! [`RedactionMode`](crate::policy::RedactionMode).
+//!
+//! ```rust
+//! use auvura_core::policy::RedactionPolicy;
+//! use auvura_core::rules::{Condition, Rule, RuleAction, RuleSet};
+//! use auvura_core::types::PiiType;
+//!
+//! // Keep internal addresses, label everything else
+//! let rules = RuleSet::new()
+//!     .rule(Rule::new(
+//!         Condition::All(vec![
+//!             Condition::Type(PiiType::Email),
+//!             Condition::EmailDomain(vec!["corp.example".to_string()]),
+//!         ]),
+//!         RuleAction::Keep,
+//!     ))
+//!     .rule(Rule::new(Condition::Type(PiiType::Email), RuleAction::Label));
+//!
+//! let policy = RedactionPolicy::builder().with_rules(rules).build();
+//! ```
+
+use crate::{
+    detector::{Confidence, Detection},
+    policy::RedactionPolicy,
+    types::PiiType,
+};
+
+/// What to do with a detection matched by a rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuleAction {
+    /// Format-preserving mask (as `RedactionMode::Mask`)
+    Mask,
+    /// Replace with the type's placeholder, e.g. `[REDACTED_EMAIL]`
+    Label,
+    /// Replace with a stable hash of the value (as `RedactionMode::Hash`)
+    Pseudonymize,
+    /// Leave the value unredacted
+    Keep,
+}
+
+/// Predicate over a single detection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Condition {
+    /// Always matches (use as a final catch-all rule)
+    Always,
+    /// Detection has this PII type
+    Type(PiiType),
+    /// Detection is an email whose domain equals, or is a subdomain of, one
+    /// of these domains (ASCII case-insensitive)
+    EmailDomain(Vec<String>),
+    /// Detection confidence is at least this level
+    MinConfidence(Confidence),
+    /// Detected value contains a term from the policy's blocklist
+    Blocklisted,
+    /// Every inner condition matches
+    All(Vec<Condition>),
+    /// At least one inner condition matches
+    Any(Vec<Condition>),
+    /// The inner condition does not match
+    Not(Box<Condition>),
+}
+
+impl Condition {
+    /// Evaluate this condition for `detection` under `policy`
+    pub fn matches(&self, detection: &Detection, policy: &RedactionPolicy) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Type(pii_type) => detection.pii_type == *pii_type,
+            Self::EmailDomain(domains) => {
+                detection.pii_type == PiiType::Email
+                    && email_domain(&detection.original)
+                        .is_some_and(|domain| domains.iter().any(|d| domain_matches(domain, d)))
+            }
+            Self::MinConfidence(min) => detection.confidence >= *min,
+            Self::Blocklisted => policy.is_blocked(&detection.original),
+            Self::All(conditions) => conditions.iter().all(|c| c.matches(detection, policy)),
+            Self::Any(conditions) => conditions.iter().any(|c| c.matches(detection, policy)),
+            Self::Not(condition) => !condition.matches(detection, policy),
+        }
+    }
+}
+
+/// A condition paired with the action taken when it matches
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    pub condition: Condition,
+    pub action: RuleAction,
+}
+
+impl Rule {
+    pub fn new(condition: Condition, action: RuleAction) -> Self {
+        Self { condition, action }
+    }
+}
+
+/// Ordered rule list – the first matching rule wins
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleSet {
+    rules: Vec<Rule>,
+}
+
+impl RuleSet {
+    /// Create an empty rule set (every detection uses the policy mode)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a rule; rules are evaluated in insertion order
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Action of the first rule matching `detection`, if any
+    pub fn evaluate(&self, detection: &Detection, policy: &RedactionPolicy) -> Option<RuleAction> {
+        self.rules
+            .iter()
+            .find(|rule| rule.condition.matches(detection, policy))
+            .map(|rule| rule.action)
+    }
+}
+
+/// Domain part of an email address
+fn email_domain(email: &str) -> Option<&str> {
+    email.rsplit_once('@').map(|(_, domain)| domain)
+}
+
+/// `domain` is `expected` or one of its subdomains
+fn domain_matches(domain: &str, expected: &str) -> bool {
+    if domain.eq_ignore_ascii_case(expected) {
+        return true;
+    }
+    domain.len() > expected.len()
+        && domain.is_char_boundary(domain.len() - expected.len())
+        && domain[domain.len() - expected.len()..].eq_ignore_ascii_case(expected)
+        && domain.as_bytes()[domain.len() - expected.len() - 1] == b'.'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detection(pii_type: PiiType, original: &str, confidence: Confidence) -> Detection {
+        Detection {
+            pii_type,
+            confidence,
+            start: 0,
+            end: original.len(),
+            original: original.to_string(),
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn test_first_matching_rule_wins() {
+        let policy = RedactionPolicy::default();
+        let rules = RuleSet::new()
+            .rule(Rule::new(
+                Condition::Type(PiiType::Email),
+                RuleAction::Label,
+            ))
+            .rule(Rule::new(Condition::Always, RuleAction::Keep));
+
+        let email = detection(PiiType::Email, "a@b.com", Confidence::Medium);
+        let phone = detection(PiiType::PhoneNumber, "555-1234", Confidence::Medium);
+        assert_eq!(rules.evaluate(&email, &policy), Some(RuleAction::Label));
+        assert_eq!(rules.evaluate(&phone, &policy), Some(RuleAction::Keep));
+    }
+
+    #[test]
+    fn test_no_match_returns_none() {
+        let policy = RedactionPolicy::default();
+        let rules = RuleSet::new().rule(Rule::new(Condition::Type(PiiType::Ssn), RuleAction::Mask));
+        let email = detection(PiiType::Email, "a@b.com", Confidence::Medium);
+        assert_eq!(rules.evaluate(&email, &policy), None);
+        assert_eq!(RuleSet::new().evaluate(&email, &policy), None);
+    }
+
+    #[test]
+    fn test_email_domain_condition() {
+        let policy = RedactionPolicy::default();
+        let internal = Condition::EmailDomain(vec!["corp.example".to_string()]);
+
+        let cases = [
+            ("jane@corp.example", true),
+            ("jane@EU.Corp.Example", true),
+            ("jane@notcorp.example", false),
+            ("jane@corp.example.evil", false),
+        ];
+        for (email, expected) in cases {
+            let d = detection(PiiType::Email, email, Confidence::Medium);
+            assert_eq!(internal.matches(&d, &policy), expected, "{email}");
+        }
+
+        // Only applies to email detections
+        let other = detection(PiiType::Other("x"), "jane@corp.example", Confidence::Medium);
+        assert!(!internal.matches(&other, &policy));
+    }
+
+    #[test]
+    fn test_confidence_and_blocklist_conditions() {
+        let policy = RedactionPolicy::builder()
+            .with_blocklist(vec!["ACME"])
+            .build();
+
+        let low = detection(PiiType::PhysicalAddress, "1 ACME Way", Confidence::Low);
+        let high = detection(PiiType::Ssn, "123-45-6789", Confidence::High);
+
+        let confident = Condition::MinConfidence(Confidence::Medium);
+        assert!(!confident.matches(&low, &policy));
+        assert!(confident.matches(&high, &policy));
+
+        assert!(Condition::Blocklisted.matches(&low, &policy));
+        assert!(!Condition::Blocklisted.matches(&high, &policy));
+    }
+
+    #[test]
+    fn test_combinators() {
+        let policy = RedactionPolicy::default();
+        let d = detection(PiiType::Email, "a@b.com", Confidence::High);
+
+        assert!(Condition::All(vec![
+            Condition::Type(PiiType::Email),
+            Condition::MinConfidence(Confidence::High),
+        ])
+        .matches(&d, &policy));
+        assert!(Condition::Any(vec![
+            Condition::Type(PiiType::Ssn),
+            Condition::Type(PiiType::Email),
+        ])
+        .matches(&d, &policy));
+        assert!(!Condition::Not(Box::new(Condition::Always)).matches(&d, &policy));
+        assert!(Condition::All(vec![]).matches(&d, &policy));
+        assert!(!Condition::Any(vec![]).matches(&d, &policy));
+    }
+}
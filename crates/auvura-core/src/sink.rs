@@ -0,0 +1,85 @@
+//! Push-based observability for redactions.
+//!
+//! A [`RedactionSink`] attached to a `Redactor` is called once per redacted
+//! PII value, so integrations can feed metrics, logs, or a message queue
+//! without inspecting return values. Events describe where and what kind of
+//! PII was redacted; they never carry the raw value or its redacted form.
+//!
+//! # Example
+//!
+//! ```rust
+//! use auvura_core::sink::{RedactionEvent, RedactionSink};
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! #[derive(Default)]
+//! struct Counter(AtomicUsize);
+//!
+//! impl RedactionSink for Counter {
+//!     fn on_detection(&self, _event: &RedactionEvent) {
+//!         self.0.fetch_add(1, Ordering::Relaxed);
+//!     }
+//! }
+//! ```
+
+use crate::detector::{Confidence, Detection};
+use crate::types::PiiType;
+
+/// One redacted PII value, without the value itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RedactionEvent {
+    /// PII type that was redacted.
+    pub pii_type: PiiType,
+    /// Confidence of the detection.
+    pub confidence: Confidence,
+    /// Byte offset start in the redacted text.
+    pub start: usize,
+    /// Byte offset end in the redacted text.
+    pub end: usize,
+    /// Regulatory basis for redacting this type (e.g., "GDPR Article 4(1)").
+    pub basis: &'static str,
+}
+
+impl RedactionEvent {
+    /// Describe a detection, dropping its original value.
+    pub fn from_detection(detection: &Detection) -> Self {
+        Self {
+            pii_type: detection.pii_type,
+            confidence: detection.confidence,
+            start: detection.start,
+            end: detection.end,
+            basis: detection.pii_type.regulatory_basis(),
+        }
+    }
+}
+
+/// Receiver for redaction events.
+///
+/// Implement this trait to push events to your observability stack. It is
+/// called synchronously on the redacting thread, so slow sinks should hand
+/// events off (e.g. to a channel) rather than block.
+pub trait RedactionSink: Send + Sync {
+    /// Called once for every PII value that is redacted.
+    fn on_detection(&self, event: &RedactionEvent);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_from_detection_omits_value() {
+        let detection = Detection {
+            pii_type: PiiType::Ssn,
+            confidence: Confidence::High,
+            start: 4,
+            end: 15,
+            original: "123-45-6789".to_string(),
+            metadata: None,
+        };
+        let event = RedactionEvent::from_detection(&detection);
+        assert_eq!(event.pii_type, PiiType::Ssn);
+        assert_eq!((event.start, event.end), (4, 15));
+        assert_eq!(event.basis, PiiType::Ssn.regulatory_basis());
+        assert!(!format!("{event:?}").contains("6789"));
+    }
+}
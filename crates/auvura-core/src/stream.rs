@@ -29,7 +29,8 @@
 //! # }
 //! ```
 
-use crate::redactor::Redactor;
+use crate::redactor::{Redactor, StreamReport};
+use crate::report::RedactionReport;
 use futures_core::Stream;
 use pin_project_lite::pin_project;
 use std::pin::Pin;
@@ -76,6 +77,167 @@ impl StreamingRedactor {
             done: false,
         }
     }
+
+    /// Wrap an input stream, redacting in overlapping windows.
+    ///
+    /// Unlike [`redact_stream`](Self::redact_stream), output is not aligned
+    /// to whitespace: each yielded chunk is committed once `config.overlap`
+    /// bytes of lookahead are buffered, so PII containing spaces (phone
+    /// numbers, card numbers) is never split. With an adaptive config the
+    /// chunk size follows detection density; the output is the same.
+    pub fn redact_stream_windowed<S, E>(
+        self,
+        stream: S,
+        config: WindowConfig,
+    ) -> WindowedRedactStream<S>
+    where
+        S: Stream<Item = Result<String, E>>,
+    {
+        WindowedRedactStream {
+            inner: stream,
+            redactor: self.redactor,
+            windower: Windower::new(config),
+            done: false,
+        }
+    }
+}
+
+/// Chunking strategy for [`StreamingRedactor::redact_stream_windowed`].
+///
+/// Input is redacted in windows of `chunk_size` new bytes plus `overlap`
+/// bytes of lookahead. Output never depends on the chunk size as long as
+/// `overlap` is at least the longest PII span (and look-behind context) the
+/// detectors produce; only throughput and memory use change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowConfig {
+    min_chunk: usize,
+    max_chunk: usize,
+    overlap: usize,
+}
+
+impl WindowConfig {
+    /// Detections per KiB above which an adaptive window shrinks
+    const DENSE_PER_KIB: usize = 4;
+
+    /// Always commit `chunk_size` bytes per window.
+    pub fn fixed(chunk_size: usize, overlap: usize) -> Self {
+        Self::adaptive(chunk_size, chunk_size, overlap)
+    }
+
+    /// Start at `min_chunk` and tune the chunk size between the bounds:
+    /// windows without PII double it, dense windows halve it.
+    pub fn adaptive(min_chunk: usize, max_chunk: usize, overlap: usize) -> Self {
+        let min_chunk = min_chunk.max(1);
+        Self {
+            min_chunk,
+            max_chunk: max_chunk.max(min_chunk),
+            overlap,
+        }
+    }
+
+    /// Lookahead carried between windows, in bytes. Must be at least the
+    /// longest PII span the detectors produce, or a value straddling a
+    /// window edge can be missed.
+    pub fn overlap(&self) -> usize {
+        self.overlap
+    }
+
+    fn next_chunk(&self, chunk: usize, committed: usize, detections: usize) -> usize {
+        if detections == 0 {
+            (chunk * 2).min(self.max_chunk)
+        } else if detections * 1024 / committed.max(1) > Self::DENSE_PER_KIB {
+            (chunk / 2).max(self.min_chunk)
+        } else {
+            chunk
+        }
+    }
+}
+
+impl Default for WindowConfig {
+    /// 4-64 KiB adaptive chunks with a 256-byte overlap
+    fn default() -> Self {
+        Self::adaptive(4 * 1024, 64 * 1024, 256)
+    }
+}
+
+/// Incremental windowed redaction shared by the streaming front ends.
+///
+/// The buffer holds up to `overlap` bytes of already-emitted text as
+/// look-behind context, followed by pending text.
+pub(crate) struct Windower {
+    config: WindowConfig,
+    chunk: usize,
+    buffer: String,
+    context: usize,
+    report: Option<StreamReport>,
+}
+
+impl Windower {
+    pub(crate) fn new(config: WindowConfig) -> Self {
+        Self {
+            config,
+            chunk: config.min_chunk,
+            buffer: String::new(),
+            context: 0,
+            report: None,
+        }
+    }
+
+    /// A windower that also reports what it redacts
+    pub(crate) fn with_report(config: WindowConfig) -> Self {
+        Self {
+            report: Some(StreamReport::default()),
+            ..Self::new(config)
+        }
+    }
+
+    /// Report over everything redacted so far (empty without `with_report`)
+    pub(crate) fn into_report(self) -> RedactionReport {
+        self.report
+            .map(StreamReport::into_report)
+            .unwrap_or_default()
+    }
+
+    /// Buffer `input` and return whatever output is ready (possibly empty)
+    pub(crate) fn push(&mut self, redactor: &Redactor, input: &str) -> String {
+        self.buffer.push_str(input);
+        let mut output = String::new();
+        while self.buffer.len() - self.context >= self.chunk + self.config.overlap {
+            let limit = self.buffer.len() - self.config.overlap;
+            let window =
+                redactor.redact_window(&self.buffer, self.context, limit, self.report.as_mut());
+            self.chunk =
+                self.config
+                    .next_chunk(self.chunk, window.cut - self.context, window.detections);
+            output.push_str(&window.output);
+            self.commit(window.cut);
+        }
+        output
+    }
+
+    /// Redact and return everything still pending
+    pub(crate) fn finish(&mut self, redactor: &Redactor) -> String {
+        let len = self.buffer.len();
+        if len == self.context {
+            return String::new();
+        }
+        let window = redactor.redact_window(&self.buffer, self.context, len, self.report.as_mut());
+        self.commit(window.cut);
+        window.output
+    }
+
+    /// Drop text that is no longer needed as look-behind context
+    fn commit(&mut self, cut: usize) {
+        let mut keep_from = cut.saturating_sub(self.config.overlap);
+        while !self.buffer.is_char_boundary(keep_from) {
+            keep_from += 1;
+        }
+        self.buffer.drain(..keep_from);
+        self.context = cut - keep_from;
+        if let Some(report) = &mut self.report {
+            report.offset += keep_from;
+        }
+    }
 }
 
 /// Convenience extension trait for [`Redactor`].
@@ -204,6 +366,55 @@ where
     }
 }
 
+pin_project! {
+    /// A stream that redacts PII from an inner stream in overlapping windows.
+    ///
+    /// Created by [`StreamingRedactor::redact_stream_windowed`].
+    pub struct WindowedRedactStream<S> {
+        #[pin]
+        inner: S,
+        redactor: Redactor,
+        windower: Windower,
+        done: bool,
+    }
+}
+
+impl<S, E> Stream for WindowedRedactStream<S>
+where
+    S: Stream<Item = Result<String, E>>,
+{
+    type Item = Result<String, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(chunk))) => {
+                    let output = this.windower.push(this.redactor, &chunk);
+                    if !output.is_empty() {
+                        return Poll::Ready(Some(Ok(output)));
+                    }
+                }
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => {
+                    *this.done = true;
+                    let output = this.windower.finish(this.redactor);
+                    return if output.is_empty() {
+                        Poll::Ready(None)
+                    } else {
+                        Poll::Ready(Some(Ok(output)))
+                    };
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -441,4 +652,89 @@ mod tests {
         assert!(output.contains("SSN:"));
         assert!(!output.contains("123-45-6789"));
     }
+
+    /// Synthetic log with PII of varying density, including multi-byte text
+    fn synthetic_log() -> String {
+        let mut log = String::new();
+        for i in 0..300 {
+            match i % 7 {
+                0 => log.push_str(&format!("user{i}@example.com logged in\n")),
+                1 => log.push_str("SSN 123-45-6789 on file; café ✓\n"),
+                2 => log.push_str("call (555) 123-4567 or 4111 1111 1111 1111\n"),
+                _ => log.push_str("nothing to see here, just a quiet log line\n"),
+            }
+            if i % 50 == 0 {
+                log.push_str(&"quiet ".repeat(200));
+            }
+        }
+        log
+    }
+
+    async fn collect_windowed(input: &str, config: WindowConfig) -> String {
+        // Split into 7-byte chunks (on char boundaries)
+        let mut chunks: Vec<Result<String, std::io::Error>> = Vec::new();
+        let mut current = String::new();
+        for c in input.chars() {
+            current.push(c);
+            if current.len() >= 7 {
+                chunks.push(Ok(std::mem::take(&mut current)));
+            }
+        }
+        chunks.push(Ok(current));
+
+        StreamingRedactor::new(test_redactor())
+            .redact_stream_windowed(stream::iter(chunks), config)
+            .map(|chunk| chunk.unwrap())
+            .collect::<Vec<_>>()
+            .await
+            .concat()
+    }
+
+    #[tokio::test]
+    async fn test_windowed_matches_full_text_for_any_chunking() {
+        let input = synthetic_log();
+        let expected = test_redactor().redact(&input).into_owned();
+
+        let fixed = collect_windowed(&input, WindowConfig::fixed(64, 64)).await;
+        assert_eq!(fixed, expected);
+
+        let adaptive = collect_windowed(&input, WindowConfig::adaptive(16, 4096, 64)).await;
+        assert_eq!(adaptive, expected);
+
+        let default = collect_windowed(&input, WindowConfig::default()).await;
+        assert_eq!(default, expected);
+    }
+
+    #[tokio::test]
+    async fn test_windowed_never_splits_pii_at_window_edge() {
+        // With chunk size 1 every byte is a potential cut point
+        let input = "a 4111 1111 1111 1111 b 123-45-6789 c";
+        let output = collect_windowed(input, WindowConfig::fixed(1, 32)).await;
+        assert_eq!(output, test_redactor().redact(input));
+    }
+
+    #[tokio::test]
+    async fn test_windowed_empty_stream() {
+        let sr = StreamingRedactor::new(test_redactor());
+        let chunks: Vec<Result<String, std::io::Error>> = vec![];
+        let results: Vec<_> = sr
+            .redact_stream_windowed(stream::iter(chunks), WindowConfig::default())
+            .collect()
+            .await;
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_adaptive_chunk_size_tracks_density() {
+        let config = WindowConfig::adaptive(16, 256, 8);
+        assert_eq!(config.next_chunk(16, 16, 0), 32);
+        assert_eq!(config.next_chunk(256, 256, 0), 256);
+        assert_eq!(config.next_chunk(64, 64, 3), 32);
+        assert_eq!(config.next_chunk(16, 16, 3), 16);
+        assert_eq!(config.next_chunk(4096, 4096, 1), 4096);
+
+        let fixed = WindowConfig::fixed(100, 10);
+        assert_eq!(fixed.next_chunk(100, 100, 0), 100);
+        assert_eq!(fixed.next_chunk(100, 100, 50), 100);
+    }
 }
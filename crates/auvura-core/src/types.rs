@@ -1,3 +1,7 @@
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+
 /// PII types with deterministic detection and regulatory grounding.
 ///
 /// Design principles:
@@ -21,6 +25,34 @@ pub enum PiiType {
     NationalId,
     /// Physical street address
     PhysicalAddress,
+    /// Date/time value – a quasi-identifier, generalized rather than masked
+    Timestamp,
+    /// Medicare Beneficiary Identifier (MBI)
+    MedicareBeneficiaryId,
+    /// Social media handle such as `@username`
+    SocialHandle,
+    /// Australian Business Number (11 digits, mod-89 checksum)
+    AustralianAbn,
+    /// Australian Company Number (9 digits, mod-10 checksum)
+    AustralianAcn,
+    /// API key, token, password, or other credential value
+    Secret,
+    /// Partially masked value that still leaks digits (e.g. `XXX-XX-6789`)
+    PartiallyMasked,
+    /// UK/EU VAT identification number (country prefix + national format)
+    VatNumber,
+    /// GPS position in degrees, minutes, and seconds (EXIF `GPSLatitude`)
+    GpsCoordinate,
+    /// Date of birth (HIPAA identifier)
+    DateOfBirth,
+    /// Hardware (MAC-48) address of a network interface
+    MacAddress,
+    /// US passport book number (9 digits, or a letter and 8 digits)
+    UsPassport,
+    /// US driver's license number (state-specific formats)
+    DriversLicense,
+    /// US bank routing transit number (9 digits, ABA checksum)
+    BankAccountRouting,
     /// For NER-detected entities (names, organizations, locations)
     Other(&'static str), // Label like "PERSON", "ORG", "LOC"
 }
@@ -38,15 +70,29 @@ impl PartialEq for PiiType {
             (Self::PassportNumber, Self::PassportNumber) => true,
             (Self::NationalId, Self::NationalId) => true,
             (Self::PhysicalAddress, Self::PhysicalAddress) => true,
+            (Self::Timestamp, Self::Timestamp) => true,
+            (Self::MedicareBeneficiaryId, Self::MedicareBeneficiaryId) => true,
+            (Self::SocialHandle, Self::SocialHandle) => true,
+            (Self::AustralianAbn, Self::AustralianAbn) => true,
+            (Self::AustralianAcn, Self::AustralianAcn) => true,
+            (Self::Secret, Self::Secret) => true,
+            (Self::PartiallyMasked, Self::PartiallyMasked) => true,
+            (Self::VatNumber, Self::VatNumber) => true,
+            (Self::GpsCoordinate, Self::GpsCoordinate) => true,
+            (Self::DateOfBirth, Self::DateOfBirth) => true,
+            (Self::MacAddress, Self::MacAddress) => true,
+            (Self::UsPassport, Self::UsPassport) => true,
+            (Self::DriversLicense, Self::DriversLicense) => true,
+            (Self::BankAccountRouting, Self::BankAccountRouting) => true,
             (Self::Other(a), Self::Other(b)) => a == b,
             _ => false,
         }
     }
 }
 
-impl std::hash::Hash for PiiType {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        std::mem::discriminant(self).hash(state);
+impl core::hash::Hash for PiiType {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::mem::discriminant(self).hash(state);
         if let Self::Other(s) = self {
             s.hash(state);
         }
@@ -57,6 +103,7 @@ impl std::hash::Hash for PiiType {
 ///
 /// Uses `String` instead of `&'static str` for the `Other` variant,
 /// enabling serde support without lifetime constraints.
+#[cfg(feature = "std")]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PiiTypeConfig {
@@ -70,9 +117,24 @@ pub enum PiiTypeConfig {
     PassportNumber,
     NationalId,
     PhysicalAddress,
+    Timestamp,
+    MedicareBeneficiaryId,
+    SocialHandle,
+    AustralianAbn,
+    AustralianAcn,
+    Secret,
+    PartiallyMasked,
+    VatNumber,
+    GpsCoordinate,
+    DateOfBirth,
+    MacAddress,
+    UsPassport,
+    DriversLicense,
+    BankAccountRouting,
     Other(String),
 }
 
+#[cfg(feature = "std")]
 impl From<PiiType> for PiiTypeConfig {
     fn from(p: PiiType) -> Self {
         match p {
@@ -86,11 +148,46 @@ impl From<PiiType> for PiiTypeConfig {
             PiiType::PassportNumber => PiiTypeConfig::PassportNumber,
             PiiType::NationalId => PiiTypeConfig::NationalId,
             PiiType::PhysicalAddress => PiiTypeConfig::PhysicalAddress,
+            PiiType::Timestamp => PiiTypeConfig::Timestamp,
+            PiiType::MedicareBeneficiaryId => PiiTypeConfig::MedicareBeneficiaryId,
+            PiiType::SocialHandle => PiiTypeConfig::SocialHandle,
+            PiiType::AustralianAbn => PiiTypeConfig::AustralianAbn,
+            PiiType::AustralianAcn => PiiTypeConfig::AustralianAcn,
+            PiiType::Secret => PiiTypeConfig::Secret,
+            PiiType::PartiallyMasked => PiiTypeConfig::PartiallyMasked,
+            PiiType::VatNumber => PiiTypeConfig::VatNumber,
+            PiiType::GpsCoordinate => PiiTypeConfig::GpsCoordinate,
+            PiiType::DateOfBirth => PiiTypeConfig::DateOfBirth,
+            PiiType::MacAddress => PiiTypeConfig::MacAddress,
+            PiiType::UsPassport => PiiTypeConfig::UsPassport,
+            PiiType::DriversLicense => PiiTypeConfig::DriversLicense,
+            PiiType::BankAccountRouting => PiiTypeConfig::BankAccountRouting,
             PiiType::Other(label) => PiiTypeConfig::Other(label.to_string()),
         }
     }
 }
 
+/// Serializes by its lowercase config name, e.g. `"phone_number"`
+#[cfg(feature = "serde")]
+impl serde::Serialize for PiiType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PiiTypeConfig::from(*self).serialize(serializer)
+    }
+}
+
+/// Custom `Other` labels can't be deserialized into a `&'static str` and
+/// are rejected; use [`PiiTypeConfig`] to keep them.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PiiType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let config = PiiTypeConfig::deserialize(deserializer)?;
+        config.to_pii_type().ok_or_else(|| {
+            serde::de::Error::custom("custom `other` types can't be deserialized as PiiType")
+        })
+    }
+}
+
+#[cfg(feature = "std")]
 impl PiiTypeConfig {
     /// Convert back to `PiiType`. Returns `None` for `Other` variants
     /// whose label is not a `'static str` (i.e., deserialized from config).
@@ -106,12 +203,86 @@ impl PiiTypeConfig {
             PiiTypeConfig::PassportNumber => Some(PiiType::PassportNumber),
             PiiTypeConfig::NationalId => Some(PiiType::NationalId),
             PiiTypeConfig::PhysicalAddress => Some(PiiType::PhysicalAddress),
+            PiiTypeConfig::Timestamp => Some(PiiType::Timestamp),
+            PiiTypeConfig::MedicareBeneficiaryId => Some(PiiType::MedicareBeneficiaryId),
+            PiiTypeConfig::SocialHandle => Some(PiiType::SocialHandle),
+            PiiTypeConfig::AustralianAbn => Some(PiiType::AustralianAbn),
+            PiiTypeConfig::AustralianAcn => Some(PiiType::AustralianAcn),
+            PiiTypeConfig::Secret => Some(PiiType::Secret),
+            PiiTypeConfig::PartiallyMasked => Some(PiiType::PartiallyMasked),
+            PiiTypeConfig::VatNumber => Some(PiiType::VatNumber),
+            PiiTypeConfig::GpsCoordinate => Some(PiiType::GpsCoordinate),
+            PiiTypeConfig::DateOfBirth => Some(PiiType::DateOfBirth),
+            PiiTypeConfig::MacAddress => Some(PiiType::MacAddress),
+            PiiTypeConfig::UsPassport => Some(PiiType::UsPassport),
+            PiiTypeConfig::DriversLicense => Some(PiiType::DriversLicense),
+            PiiTypeConfig::BankAccountRouting => Some(PiiType::BankAccountRouting),
             PiiTypeConfig::Other(_) => None, // Cannot convert back to &'static str
         }
     }
 }
 
 impl PiiType {
+    /// Every built-in type, i.e. all but `Other(..)`
+    pub const ALL: [PiiType; 24] = [
+        Self::Email,
+        Self::PhoneNumber,
+        Self::Ssn,
+        Self::CreditCard,
+        Self::IpAddressV4,
+        Self::IpAddressV6,
+        Self::Iban,
+        Self::PassportNumber,
+        Self::NationalId,
+        Self::PhysicalAddress,
+        Self::Timestamp,
+        Self::MedicareBeneficiaryId,
+        Self::SocialHandle,
+        Self::AustralianAbn,
+        Self::AustralianAcn,
+        Self::Secret,
+        Self::PartiallyMasked,
+        Self::VatNumber,
+        Self::GpsCoordinate,
+        Self::DateOfBirth,
+        Self::MacAddress,
+        Self::UsPassport,
+        Self::DriversLicense,
+        Self::BankAccountRouting,
+    ];
+
+    /// Canonical lowercase name, the same as the config key: `email`,
+    /// `phone_number`, `credit_card`, ... `Other` types give their label.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Email => "email",
+            Self::PhoneNumber => "phone_number",
+            Self::Ssn => "ssn",
+            Self::CreditCard => "credit_card",
+            Self::IpAddressV4 => "ip_address_v4",
+            Self::IpAddressV6 => "ip_address_v6",
+            Self::Iban => "iban",
+            Self::PassportNumber => "passport_number",
+            Self::NationalId => "national_id",
+            Self::PhysicalAddress => "physical_address",
+            Self::Timestamp => "timestamp",
+            Self::MedicareBeneficiaryId => "medicare_beneficiary_id",
+            Self::SocialHandle => "social_handle",
+            Self::AustralianAbn => "australian_abn",
+            Self::AustralianAcn => "australian_acn",
+            Self::Secret => "secret",
+            Self::PartiallyMasked => "partially_masked",
+            Self::VatNumber => "vat_number",
+            Self::GpsCoordinate => "gps_coordinate",
+            Self::DateOfBirth => "date_of_birth",
+            Self::MacAddress => "mac_address",
+            Self::UsPassport => "us_passport",
+            Self::DriversLicense => "drivers_license",
+            Self::BankAccountRouting => "bank_account_routing",
+            Self::Other(label) => label,
+        }
+    }
+
     /// Regulatory basis for compliance auditing
     pub fn regulatory_basis(&self) -> &'static str {
         match self {
@@ -124,6 +295,21 @@ impl PiiType {
             Self::PassportNumber => "GDPR Art.4(1) + ICAO 9303",
             Self::NationalId => "GDPR Art.4(1) + national ID regulations",
             Self::PhysicalAddress => "GDPR Art.4(1) + CCPA §1798.140(v)",
+            Self::Timestamp => "GDPR Recital 26 (quasi-identifier)",
+            Self::MedicareBeneficiaryId => "HIPAA 45 CFR §164.514(b)(2)(i)(H)",
+            Self::SocialHandle => "GDPR Art.4(1) (online identifier)",
+            Self::AustralianAbn => "Privacy Act 1988 (Cth) + A New Tax System (ABN) Act 1999",
+            Self::AustralianAcn => "Privacy Act 1988 (Cth) + Corporations Act 2001 s.601BC",
+            Self::Secret => "Security credential (GDPR Art. 32 security of processing)",
+            Self::PartiallyMasked => {
+                "Residual identifier digits (GDPR Art. 4(1) identifiable data)"
+            }
+            Self::VatNumber => "GDPR Art.4(1) + Council Directive 2006/112/EC Art.214",
+            Self::GpsCoordinate => "GDPR Art.4(1) (location data) + ePrivacy Directive Art.9",
+            Self::DateOfBirth => "HIPAA 45 CFR §164.514(b)(2)(i)(C)",
+            Self::MacAddress => "GDPR Recital 30 (online identifier)",
+            Self::UsPassport | Self::DriversLicense => "NIST SP 800-122 §2.1",
+            Self::BankAccountRouting => "GLBA 15 U.S.C. §6809(4) + PCI-DSS v4.0",
             Self::Other(_label) => "Contextual PII (NER-detected)",
         }
     }
@@ -141,21 +327,177 @@ impl PiiType {
             Self::PassportNumber => "[REDACTED_PASSPORT]",
             Self::NationalId => "[REDACTED_NATID]",
             Self::PhysicalAddress => "[REDACTED_ADDRESS]",
+            Self::Timestamp => "[REDACTED_TIMESTAMP]",
+            Self::MedicareBeneficiaryId => "[REDACTED_MBI]",
+            Self::SocialHandle => "[REDACTED_HANDLE]",
+            Self::AustralianAbn => "[REDACTED_ABN]",
+            Self::AustralianAcn => "[REDACTED_ACN]",
+            Self::Secret => "[REDACTED_SECRET]",
+            Self::PartiallyMasked => "[REDACTED_PARTIAL]",
+            Self::VatNumber => "[REDACTED_VAT]",
+            Self::GpsCoordinate => "[REDACTED_GPS]",
+            Self::DateOfBirth => "[REDACTED_DOB]",
+            Self::MacAddress => "[REDACTED_MAC]",
+            Self::UsPassport => "[REDACTED_US_PASSPORT]",
+            Self::DriversLicense => "[REDACTED_DL]",
+            Self::BankAccountRouting => "[REDACTED_ROUTING]",
             Self::Other(_label) => "[REDACTED_OTHER]",
         }
     }
 
+    /// Short uppercase label naming the type in tokens: `EMAIL` for
+    /// `[REDACTED_EMAIL]`, or the label itself for `Other`
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Other(label) => label,
+            _ => {
+                let placeholder = self.placeholder();
+                placeholder
+                    .strip_prefix("[REDACTED_")
+                    .and_then(|rest| rest.strip_suffix(']'))
+                    .unwrap_or(placeholder)
+            }
+        }
+    }
+
+    /// Overlap priority (higher = more specific): when detections overlap,
+    /// `MultiDetector` keeps the higher-priority type and only falls back to
+    /// confidence, then span length, on a tie. An SSN inside a longer
+    /// phone-like run stays an SSN.
+    pub fn priority(&self) -> u8 {
+        match self {
+            Self::Ssn => 5,
+            Self::CreditCard => 4,
+            Self::Iban => 3,
+            Self::PassportNumber => 3,
+            Self::NationalId => 3,
+            Self::PhoneNumber => 2,
+            Self::Timestamp => 2,
+            Self::Email => 1,
+            Self::IpAddressV4 | Self::IpAddressV6 => 1,
+            Self::PhysicalAddress => 1,
+            Self::MedicareBeneficiaryId => 3,
+            Self::SocialHandle => 1,
+            Self::AustralianAbn => 3,
+            Self::AustralianAcn => 3,
+            Self::Secret => 4,
+            Self::PartiallyMasked => 2,
+            Self::VatNumber => 3,
+            Self::GpsCoordinate => 3,
+            Self::DateOfBirth => 2,
+            Self::MacAddress => 1,
+            Self::UsPassport => 3,
+            Self::DriversLicense => 3,
+            // Keyword-gated and checksummed: beats a bare nine-digit SSN
+            Self::BankAccountRouting => 6,
+            Self::Other(_) => 0,
+        }
+    }
+
     /// Returns true if this PII type requires checksum validation
     /// (e.g., Luhn algorithm for credit cards)
     pub fn requires_validation(&self) -> bool {
-        matches!(self, Self::CreditCard | Self::Ssn | Self::Iban)
+        matches!(
+            self,
+            Self::CreditCard
+                | Self::Ssn
+                | Self::Iban
+                | Self::MedicareBeneficiaryId
+                | Self::AustralianAbn
+                | Self::AustralianAcn
+                | Self::VatNumber
+                | Self::GpsCoordinate
+                | Self::DateOfBirth
+                | Self::BankAccountRouting
+        )
+    }
+}
+
+impl fmt::Display for PiiType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses the canonical names of [`PiiType::name`]. `Other` labels are
+/// rejected: they only exist for custom detectors.
+impl FromStr for PiiType {
+    type Err = ParsePiiTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|pii_type| pii_type.name() == s)
+            .ok_or_else(|| ParsePiiTypeError {
+                name: s.to_string(),
+            })
+    }
+}
+
+/// Error from parsing an unknown [`PiiType`] name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePiiTypeError {
+    name: String,
+}
+
+impl ParsePiiTypeError {
+    /// The name that failed to parse
+    pub fn name(&self) -> &str {
+        &self.name
     }
 }
 
+impl fmt::Display for ParsePiiTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown PII type {:?}; expected one of ", self.name)?;
+        for (i, pii_type) in PiiType::ALL.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(pii_type.name())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParsePiiTypeError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_display_from_str_round_trip() {
+        for pii_type in PiiType::ALL {
+            assert_eq!(PiiType::from_str(&pii_type.to_string()), Ok(pii_type));
+        }
+        assert_eq!("credit_card".parse(), Ok(PiiType::CreditCard));
+        assert_eq!(
+            PiiType::BankAccountRouting.to_string(),
+            "bank_account_routing"
+        );
+    }
+
+    #[test]
+    fn test_from_str_rejects_unknown_names() {
+        let err = "Email".parse::<PiiType>().unwrap_err();
+        assert_eq!(err.name(), "Email");
+        let message = err.to_string();
+        assert!(message.starts_with("unknown PII type \"Email\"; expected one of email, "));
+        assert!(message.ends_with("bank_account_routing"));
+        assert!("PERSON".parse::<PiiType>().is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_names_match_config_keys() {
+        for pii_type in PiiType::ALL {
+            let json = serde_json::to_string(&PiiTypeConfig::from(pii_type)).unwrap();
+            assert_eq!(json, format!("\"{pii_type}\""));
+        }
+    }
+
     #[test]
     fn test_pii_type_is_copy() {
         // Compile-time proof that enum is Copy (no heap allocations)
@@ -176,6 +518,20 @@ mod tests {
             PiiType::PassportNumber,
             PiiType::NationalId,
             PiiType::PhysicalAddress,
+            PiiType::Timestamp,
+            PiiType::MedicareBeneficiaryId,
+            PiiType::SocialHandle,
+            PiiType::AustralianAbn,
+            PiiType::AustralianAcn,
+            PiiType::Secret,
+            PiiType::PartiallyMasked,
+            PiiType::VatNumber,
+            PiiType::GpsCoordinate,
+            PiiType::DateOfBirth,
+            PiiType::MacAddress,
+            PiiType::UsPassport,
+            PiiType::DriversLicense,
+            PiiType::BankAccountRouting,
         ];
         let placeholders: Vec<_> = types.iter().map(|t| t.placeholder()).collect();
         let unique: std::collections::HashSet<_> = placeholders.iter().collect();
@@ -186,6 +542,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_pii_type_config_round_trip() {
         let types = [
@@ -199,6 +556,20 @@ mod tests {
             PiiType::PassportNumber,
             PiiType::NationalId,
             PiiType::PhysicalAddress,
+            PiiType::Timestamp,
+            PiiType::MedicareBeneficiaryId,
+            PiiType::SocialHandle,
+            PiiType::AustralianAbn,
+            PiiType::AustralianAcn,
+            PiiType::Secret,
+            PiiType::PartiallyMasked,
+            PiiType::VatNumber,
+            PiiType::GpsCoordinate,
+            PiiType::DateOfBirth,
+            PiiType::MacAddress,
+            PiiType::UsPassport,
+            PiiType::DriversLicense,
+            PiiType::BankAccountRouting,
         ];
         for pii_type in &types {
             let config: PiiTypeConfig = (*pii_type).into();
@@ -207,6 +578,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_pii_type_config_serde_json() {
         let config = PiiTypeConfig::Email;
@@ -217,6 +589,7 @@ mod tests {
         assert_eq!(parsed, PiiTypeConfig::Email);
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_pii_type_config_other_serde() {
         let config = PiiTypeConfig::Other("PERSON".to_string());
@@ -227,6 +600,7 @@ mod tests {
         assert_eq!(parsed, PiiTypeConfig::Other("PERSON".to_string()));
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_pii_type_config_other_to_pii_type_returns_none() {
         let config = PiiTypeConfig::Other("PERSON".to_string());
@@ -0,0 +1,135 @@
+//! Reversible tokenization for round trips through untrusted pipelines
+//!
+//! [`Redactor::redact_with_vault`](crate::redactor::Redactor::redact_with_vault)
+//! replaces every PII value with an opaque token like `⟪tok_0001⟫` and
+//! returns a [`TokenVault`] mapping each token back to its value. Unlike a
+//! [`Manifest`](crate::manifest::Manifest), the vault holds the plaintext,
+//! so it must stay with the process that redacted the text. Values are
+//! zeroized when the vault is dropped; after that the tokens cannot be
+//! restored.
+
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::OnceLock;
+use zeroize::Zeroize;
+
+/// Tokens as written by [`TokenVault`], e.g. `⟪tok_0001⟫`
+fn token_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(r"⟪tok_\d{4,}⟫").expect("token pattern is valid"))
+}
+
+/// Token → original value mappings from one `redact_with_vault` call.
+///
+/// Repeats of a value share a token. `Debug` shows only the entry count.
+#[derive(Default)]
+pub struct TokenVault {
+    /// Originals in token order: `⟪tok_0001⟫` is `values[0]`
+    values: Vec<String>,
+    /// Position of each original in `values`
+    index: HashMap<String, usize>,
+}
+
+impl TokenVault {
+    /// Token for `value`, adding it to the vault if it is new
+    pub(crate) fn token_for(&mut self, value: &str) -> String {
+        let index = match self.index.get(value) {
+            Some(&index) => index,
+            None => {
+                self.values.push(value.to_string());
+                self.index.insert(value.to_string(), self.values.len() - 1);
+                self.values.len() - 1
+            }
+        };
+        format!("⟪tok_{:04}⟫", index + 1)
+    }
+
+    /// Original value for `token`, if the vault issued it
+    pub fn get(&self, token: &str) -> Option<&str> {
+        let digits = token.strip_prefix("⟪tok_")?.strip_suffix('⟫')?;
+        let index = digits.parse::<usize>().ok()?.checked_sub(1)?;
+        self.values.get(index).map(String::as_str)
+    }
+
+    /// Replace every token the vault issued in `redacted` with its
+    /// original value. Tokens it doesn't know are left as they are.
+    ///
+    /// Tokens are not escaped: text that already spelled one of the
+    /// vault's tokens, e.g. a literal `⟪tok_0001⟫` in the input, is
+    /// replaced too, so such input doesn't round-trip exactly.
+    pub fn restore(&self, redacted: &str) -> String {
+        token_pattern()
+            .replace_all(redacted, |caps: &regex::Captures| {
+                let token = &caps[0];
+                self.get(token).unwrap_or(token).to_string()
+            })
+            .into_owned()
+    }
+
+    /// Number of distinct values in the vault
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether the vault holds no values
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl fmt::Debug for TokenVault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenVault")
+            .field("len", &self.values.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for TokenVault {
+    fn drop(&mut self) {
+        for value in &mut self.values {
+            value.zeroize();
+        }
+        for (mut value, _) in self.index.drain() {
+            value.zeroize();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_values_share_a_token() {
+        let mut vault = TokenVault::default();
+        assert_eq!(vault.token_for("a@b.com"), "⟪tok_0001⟫");
+        assert_eq!(vault.token_for("c@d.org"), "⟪tok_0002⟫");
+        assert_eq!(vault.token_for("a@b.com"), "⟪tok_0001⟫");
+        assert_eq!(vault.len(), 2);
+        assert_eq!(vault.get("⟪tok_0002⟫"), Some("c@d.org"));
+        assert_eq!(vault.get("⟪tok_0003⟫"), None);
+        assert_eq!(vault.get("⟪tok_0000⟫"), None);
+    }
+
+    #[test]
+    fn test_restore_leaves_unknown_tokens() {
+        let mut vault = TokenVault::default();
+        let token = vault.token_for("a@b.com");
+        assert_eq!(
+            vault.restore(&format!("to {token}, cc ⟪tok_0042⟫")),
+            "to a@b.com, cc ⟪tok_0042⟫"
+        );
+        assert_eq!(format!("{vault:?}"), "TokenVault { len: 1, .. }");
+    }
+
+    #[test]
+    fn test_restore_replaces_literal_tokens_too() {
+        let mut vault = TokenVault::default();
+        let token = vault.token_for("a@b.com");
+        // `⟪tok_0001⟫` was already in the text before redaction
+        let redacted = format!("{token} wrote ⟪tok_0001⟫");
+        assert_eq!(vault.restore(&redacted), "a@b.com wrote a@b.com");
+    }
+}
@@ -0,0 +1,408 @@
+use crate::redactor::Redactor;
+use std::borrow::Cow;
+
+/// Markup-aware redactor for XML and HTML.
+///
+/// Scans text nodes, comments, and a configurable set of attribute values,
+/// leaving tag names, other attributes, processing instructions, and
+/// declarations untouched. Entity references are decoded before scanning,
+/// so `jane&#64;example.com` is found; redacted values are re-escaped so the
+/// output stays well-formed. Values without PII are copied verbatim,
+/// including their original entity spelling.
+///
+/// This is a lightweight scanner, not a validating parser: malformed markup
+/// is redacted on a best-effort basis and never rejected.
+pub struct XmlRedactor {
+    redactor: Redactor,
+    attributes: Vec<String>,
+}
+
+impl XmlRedactor {
+    /// Create a new `XmlRedactor` that redacts text nodes only.
+    pub fn new(redactor: Redactor) -> Self {
+        Self {
+            redactor,
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Also redact the values of these attributes (ASCII case-insensitive),
+    /// e.g. `["value", "title"]`.
+    pub fn with_attributes(mut self, attributes: &[&str]) -> Self {
+        self.attributes = attributes.iter().map(|a| a.to_string()).collect();
+        self
+    }
+
+    /// Redact PII inside an XML/HTML document, preserving its markup.
+    pub fn redact_xml(&self, input: &str) -> String {
+        let mut out = String::with_capacity(input.len());
+        // Start of the pending text node; stray `<` (as in `a < b`) stays in it
+        let mut text_start = 0;
+        let mut pos = 0;
+
+        while let Some(offset) = input[pos..].find('<') {
+            let lt = pos + offset;
+            let rest = &input[lt..];
+
+            let markup_len = if let Some(body) = rest.strip_prefix("<![CDATA[") {
+                body.find("]]>")
+                    .map(|end| "<![CDATA[".len() + end + "]]>".len())
+            } else if rest.starts_with("<!--") {
+                rest.find("-->").map(|end| end + "-->".len())
+            } else if rest.starts_with("<!") || rest.starts_with("<?") {
+                rest.find('>').map(|end| end + 1)
+            } else {
+                tag_end(rest)
+            };
+            let Some(markup_len) = markup_len else {
+                pos = lt + 1;
+                continue;
+            };
+
+            self.push_text(&mut out, &input[text_start..lt]);
+            let markup = &rest[..markup_len];
+            if let Some(cdata) = markup
+                .strip_prefix("<![CDATA[")
+                .and_then(|m| m.strip_suffix("]]>"))
+            {
+                // CDATA content is literal text: redact without entity handling
+                out.push_str("<![CDATA[");
+                out.push_str(&self.redactor.redact(cdata));
+                out.push_str("]]>");
+            } else if let Some(comment) = markup
+                .strip_prefix("<!--")
+                .and_then(|m| m.strip_suffix("-->"))
+            {
+                self.push_comment(&mut out, comment);
+            } else if markup.starts_with("<!") || markup.starts_with("<?") {
+                out.push_str(markup);
+            } else {
+                self.push_tag(&mut out, markup);
+            }
+
+            pos = lt + markup_len;
+            text_start = pos;
+        }
+
+        self.push_text(&mut out, &input[text_start..]);
+        out
+    }
+
+    /// Consume the `XmlRedactor` and return the inner [`Redactor`].
+    pub fn into_inner(self) -> Redactor {
+        self.redactor
+    }
+
+    fn redacts_attribute(&self, name: &str) -> bool {
+        self.attributes.iter().any(|a| a.eq_ignore_ascii_case(name))
+    }
+
+    /// Redact a raw (still escaped) text node and append it
+    fn push_text(&self, out: &mut String, raw: &str) {
+        if raw.is_empty() {
+            return;
+        }
+        match self.redact_raw(raw) {
+            Some(redacted) => escape_into(out, &redacted, None),
+            None => out.push_str(raw),
+        }
+    }
+
+    /// Redact a comment body and append the comment. Redacted bodies are
+    /// kept valid: no `--` inside, and no `-` right before the closing `-->`.
+    fn push_comment(&self, out: &mut String, body: &str) {
+        out.push_str("<!--");
+        match self.redactor.redact(body) {
+            Cow::Borrowed(body) => out.push_str(body),
+            Cow::Owned(redacted) => {
+                let mut prev = None;
+                for c in redacted.chars() {
+                    if c == '-' && prev == Some('-') {
+                        out.push(' ');
+                    }
+                    out.push(c);
+                    prev = Some(c);
+                }
+                if prev == Some('-') {
+                    out.push(' ');
+                }
+            }
+        }
+        out.push_str("-->");
+    }
+
+    /// Decode and redact `raw`; `None` if nothing was redacted
+    fn redact_raw(&self, raw: &str) -> Option<String> {
+        let decoded = decode_entities(raw);
+        match self.redactor.redact(&decoded) {
+            Cow::Borrowed(_) => None,
+            Cow::Owned(redacted) => Some(redacted),
+        }
+    }
+
+    /// Append a start/end tag, redacting configured attribute values
+    fn push_tag(&self, out: &mut String, tag: &str) {
+        let bytes = tag.as_bytes();
+        let mut copied = 0;
+        let mut i = 1;
+
+        while i < bytes.len() {
+            // Attribute name
+            if !is_name_byte(bytes[i]) {
+                i += 1;
+                continue;
+            }
+            let name_start = i;
+            while i < bytes.len() && is_name_byte(bytes[i]) {
+                i += 1;
+            }
+            let name = &tag[name_start..i];
+
+            // Optional `= "value"`, `= 'value'`, or `= value`
+            let mut j = i;
+            while j < bytes.len() && bytes[j].is_ascii_whitespace() {
+                j += 1;
+            }
+            if j >= bytes.len() || bytes[j] != b'=' {
+                continue;
+            }
+            j += 1;
+            while j < bytes.len() && bytes[j].is_ascii_whitespace() {
+                j += 1;
+            }
+            let quote = bytes.get(j).copied().filter(|&b| b == b'"' || b == b'\'');
+            let (value_start, value_end) = match quote {
+                Some(quote) => {
+                    let Some(len) = tag[j + 1..].find(quote as char) else {
+                        break;
+                    };
+                    (j + 1, j + 1 + len)
+                }
+                None => {
+                    // Unquoted: ends at whitespace, `>`, or `/>`
+                    let mut end = j;
+                    while end < bytes.len()
+                        && !bytes[end].is_ascii_whitespace()
+                        && bytes[end] != b'>'
+                        && !bytes[end..].starts_with(b"/>")
+                    {
+                        end += 1;
+                    }
+                    (j, end)
+                }
+            };
+
+            if value_end > value_start && self.redacts_attribute(name) {
+                if let Some(redacted) = self.redact_raw(&tag[value_start..value_end]) {
+                    out.push_str(&tag[copied..value_start]);
+                    match quote {
+                        Some(quote) => escape_into(out, &redacted, Some(quote as char)),
+                        // Quoted, so a replacement with spaces stays one value
+                        None => {
+                            out.push('"');
+                            escape_into(out, &redacted, Some('"'));
+                            out.push('"');
+                        }
+                    }
+                    copied = value_end;
+                }
+            }
+            i = value_end + usize::from(quote.is_some());
+        }
+
+        out.push_str(&tag[copied..]);
+    }
+}
+
+fn is_name_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-' | b':' | b'.') || b >= 0x80
+}
+
+/// End (exclusive) of the tag starting at `s[0] == '<'`, honoring quotes
+fn tag_end(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    let first = *bytes.get(1)?;
+    if !(first.is_ascii_alphabetic() || first == b'/') {
+        return None;
+    }
+    let mut quote = None;
+    for (i, &b) in bytes.iter().enumerate().skip(1) {
+        match (quote, b) {
+            (Some(q), _) if b == q => quote = None,
+            (Some(_), _) => {}
+            (None, b'"' | b'\'') => quote = Some(b),
+            (None, b'>') => return Some(i + 1),
+            (None, b'<') => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Decode the predefined XML entities and numeric character references.
+/// Unknown or malformed references are kept literally.
+fn decode_entities(raw: &str) -> Cow<'_, str> {
+    if !raw.contains('&') {
+        return Cow::Borrowed(raw);
+    }
+
+    let mut out = String::with_capacity(raw.len());
+    let mut rest = raw;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        let decoded = rest.find(';').filter(|&end| end <= 12).and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| entity.strip_prefix('#').map(str::parse::<u32>))
+                    .and_then(Result::ok)
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end + 1))
+        });
+
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
+}
+
+/// Escape `text` for a text node, or an attribute value quoted by `quote`
+fn escape_into(out: &mut String, text: &str, quote: Option<char>) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' if quote == Some('"') => out.push_str("&quot;"),
+            '\'' if quote == Some('\'') => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::email::EmailDetector;
+    use crate::policy::RedactionPolicy;
+    use crate::types::PiiType;
+
+    fn xml_redactor() -> XmlRedactor {
+        let redactor = Redactor::new(
+            vec![Box::new(EmailDetector::new())],
+            RedactionPolicy::default(),
+        );
+        XmlRedactor::new(redactor).with_attributes(&["value", "title"])
+    }
+
+    #[test]
+    fn test_redacts_email_in_text_node() {
+        let input = r#"<p class="contact">Mail <b>jane@example.com</b> today</p>"#;
+        assert_eq!(
+            xml_redactor().redact_xml(input),
+            r#"<p class="contact">Mail <b>████@███████.com</b> today</p>"#
+        );
+    }
+
+    #[test]
+    fn test_redacts_configured_attributes_only() {
+        let input =
+            r#"<input class="jane@example.com" value="jane@example.com" TITLE='bob@example.org'/>"#;
+        assert_eq!(
+            xml_redactor().redact_xml(input),
+            r#"<input class="jane@example.com" value="████@███████.com" TITLE='███@███████.org'/>"#
+        );
+    }
+
+    #[test]
+    fn test_redacts_unquoted_attribute_values() {
+        let input = "<input value=jane@example.com><input title=bob@example.org/><i value=é>";
+        assert_eq!(
+            xml_redactor().redact_xml(input),
+            r#"<input value="████@███████.com"><input title="███@███████.org"/><i value=é>"#
+        );
+        let input = "<input class=jane@example.com value = jane@example.com disabled>";
+        assert_eq!(
+            xml_redactor().redact_xml(input),
+            r#"<input class=jane@example.com value = "████@███████.com" disabled>"#
+        );
+    }
+
+    #[test]
+    fn test_decodes_entities_before_scanning() {
+        let input = "<p>jane&#64;example.com &amp; co</p>";
+        assert_eq!(
+            xml_redactor().redact_xml(input),
+            "<p>████@███████.com &amp; co</p>"
+        );
+    }
+
+    #[test]
+    fn test_unchanged_markup_is_copied_verbatim() {
+        let input = "<?xml version=\"1.0\"?>\n<!DOCTYPE note>\n<!-- no pii here -->\
+                     <note a=\"1 &lt; 2\">x &gt; y</note>";
+        assert_eq!(xml_redactor().redact_xml(input), input);
+    }
+
+    #[test]
+    fn test_redacts_comment_bodies() {
+        let input = "<!-- jane@example.com --><p>x</p>";
+        assert_eq!(
+            xml_redactor().redact_xml(input),
+            "<!-- ████@███████.com --><p>x</p>"
+        );
+    }
+
+    #[test]
+    fn test_redacted_comment_stays_well_formed() {
+        let redactor = Redactor::new(
+            vec![Box::new(EmailDetector::new())],
+            RedactionPolicy::builder()
+                .with_placeholder(PiiType::Email, "-redacted-")
+                .build(),
+        );
+        let xml = XmlRedactor::new(redactor);
+        assert_eq!(
+            xml.redact_xml("<!--by -jane@example.com--><!--jane@example.com-->"),
+            "<!--by - -redacted- --><!---redacted- -->"
+        );
+    }
+
+    #[test]
+    fn test_cdata_and_stray_angle_brackets() {
+        let input = "<a><![CDATA[jane@example.com]]></a> 1 < 2 jane@example.com";
+        assert_eq!(
+            xml_redactor().redact_xml(input),
+            "<a><![CDATA[████@███████.com]]></a> 1 &lt; 2 ████@███████.com"
+        );
+    }
+
+    #[test]
+    fn test_quotes_in_attribute_tags_do_not_end_tag() {
+        let input = r#"<a title="x > y jane@example.com">t</a>"#;
+        assert_eq!(
+            xml_redactor().redact_xml(input),
+            r#"<a title="x &gt; y ████@███████.com">t</a>"#
+        );
+    }
+}
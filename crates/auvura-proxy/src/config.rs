@@ -125,7 +125,9 @@ pub struct PolicyConfig {
     #[serde(default)]
     pub allowlist: Vec<String>,
 
-    /// Global redaction mode: "mask", "replace", "hash", or "tokenize"
+    /// Global redaction mode: "mask", "replace", "hash", "tokenize",
+    /// "remove", "fixed_length_mask:<n>", "partial_reveal:<lead>", or
+    /// "format_preserving[:<char>]"
     #[serde(default)]
     pub mode: Option<String>,
 
@@ -558,12 +560,33 @@ impl Config {
                 "hash" => RedactionMode::Hash,
                 "tokenize" => RedactionMode::Tokenize,
                 "mask" | "" => RedactionMode::Mask,
-                _ => {
-                    eprintln!(
-                        "Warning: unknown redaction mode '{}', using default",
-                        mode_str
-                    );
-                    RedactionMode::Mask
+                "remove" => RedactionMode::Remove,
+                "format_preserving" => RedactionMode::FormatPreserving { mask_char: 'X' },
+                other => {
+                    let parsed = if let Some(len) = other.strip_prefix("fixed_length_mask:") {
+                        len.parse().ok().map(RedactionMode::FixedLengthMask)
+                    } else if let Some(lead) = other.strip_prefix("partial_reveal:") {
+                        lead.parse()
+                            .ok()
+                            .map(|lead| RedactionMode::PartialReveal { lead, keep_last: 0 })
+                    } else if let Some(mask) = other.strip_prefix("format_preserving:") {
+                        let mut chars = mask.chars();
+                        match (chars.next(), chars.next()) {
+                            (Some(mask_char), None) => {
+                                Some(RedactionMode::FormatPreserving { mask_char })
+                            }
+                            _ => None,
+                        }
+                    } else {
+                        None
+                    };
+                    parsed.unwrap_or_else(|| {
+                        eprintln!(
+                            "Warning: unknown redaction mode '{}', using default",
+                            mode_str
+                        );
+                        RedactionMode::Mask
+                    })
                 }
             };
             builder = builder.with_mode(mode);
@@ -776,6 +799,39 @@ phone_countries = ["DE"]
         assert_ne!(result, "Intl: +12025550123");
     }
 
+    #[test]
+    fn test_build_redactor_with_fixed_length_mask() {
+        let toml_str = r#"
+[policy]
+mode = "fixed_length_mask:6"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let redactor = config.build_redactor(None::<auvura_core::audit::NoopAuditLogger>);
+        assert_eq!(redactor.redact("SSN 123-45-6789"), "SSN ██████");
+    }
+
+    #[test]
+    fn test_build_redactor_with_partial_reveal() {
+        let toml_str = r#"
+[policy]
+mode = "partial_reveal:3"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let redactor = config.build_redactor(None::<auvura_core::audit::NoopAuditLogger>);
+        assert_eq!(redactor.redact("SSN 123-45-6789"), "SSN 123████████");
+    }
+
+    #[test]
+    fn test_build_redactor_with_format_preserving() {
+        let toml_str = r#"
+[policy]
+mode = "format_preserving:*"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let redactor = config.build_redactor(None::<auvura_core::audit::NoopAuditLogger>);
+        assert_eq!(redactor.redact("SSN 123-45-6789"), "SSN ***-**-****");
+    }
+
     #[test]
     fn test_parse_cors_config() {
         let toml_str = r#"
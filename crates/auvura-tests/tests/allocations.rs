@@ -0,0 +1,79 @@
+//! Allocation counting for the zero-copy paths.
+//!
+//! A counting global allocator tallies allocations per thread, so tests
+//! running in parallel don't disturb each other's counts.
+
+use auvura_core::detectors::DetectorRegistry;
+use auvura_core::policy::RedactionPolicy;
+use auvura_core::redactor::Redactor;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::borrow::Cow;
+use std::cell::Cell;
+
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|n| n.set(n.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Allocations `f` makes on this thread
+fn allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let out = f();
+    (out, ALLOCATIONS.with(Cell::get) - before)
+}
+
+fn default_redactor() -> Redactor {
+    let policy = RedactionPolicy::default();
+    let registry = DetectorRegistry::with_defaults(policy.enabled_types());
+    Redactor::from_registry(registry, policy).unwrap()
+}
+
+/// Prose with spaces, dots, dashes, and colons, which are detector
+/// anchors, but no PII
+fn pii_free_text(lines: usize) -> String {
+    "Status: the quarterly report is attached - nothing in it names anyone.\n".repeat(lines)
+}
+
+#[test]
+fn redact_without_pii_does_not_allocate() {
+    let redactor = default_redactor();
+    for lines in [1, 20, 1000] {
+        let text = pii_free_text(lines);
+        // Warm up lazily compiled patterns and per-thread scratch buffers
+        redactor.redact(&text);
+
+        let (result, count) = allocations(|| redactor.redact(&text));
+        assert!(matches!(result, Cow::Borrowed(_)));
+        assert_eq!(count, 0, "{lines} lines");
+
+        let (ranges, count) = allocations(|| redactor.redaction_ranges(&text));
+        assert!(ranges.is_empty());
+        assert_eq!(count, 0, "{lines} lines");
+    }
+}
+
+#[test]
+fn redact_with_pii_allocates_its_output() {
+    let redactor = default_redactor();
+    let text = "Mail jane@example.com about SSN 123-45-6789.";
+    redactor.redact(text);
+
+    let (result, count) = allocations(|| redactor.redact(text));
+    assert!(matches!(result, Cow::Owned(_)));
+    assert!(count > 0);
+}
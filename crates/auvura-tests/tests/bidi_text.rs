@@ -0,0 +1,121 @@
+//! Right-to-left and bidirectional text integration tests.
+//!
+//! Arabic and Hebrew documents embed left-to-right PII between invisible
+//! bidi controls (marks, embeddings, isolates). Redaction must mask the PII
+//! while leaving the surrounding text and every control in place.
+
+use auvura_core::detector::PiiDetector;
+use auvura_core::detectors::credit_card::CreditCardDetector;
+use auvura_core::detectors::email::EmailDetector;
+use auvura_core::detectors::phone_number::PhoneNumberDetector;
+use auvura_core::detectors::secret::SecretKeyDetector;
+use auvura_core::detectors::ssn::SSNDetector;
+use auvura_core::detectors::{self, is_bidi_control};
+use auvura_core::policy::RedactionPolicy;
+use auvura_core::redactor::Redactor;
+use auvura_core::types::PiiType;
+
+const LRI: char = '\u{2066}';
+const RLI: char = '\u{2067}';
+const PDI: char = '\u{2069}';
+const LRM: char = '\u{200E}';
+const RLM: char = '\u{200F}';
+
+fn bidi_redactor() -> Redactor {
+    let detectors: Vec<Box<dyn PiiDetector>> = vec![
+        Box::new(EmailDetector::new()),
+        Box::new(PhoneNumberDetector::new()),
+        Box::new(SSNDetector::new()),
+        Box::new(CreditCardDetector::new()),
+        Box::new(SecretKeyDetector::new()),
+    ];
+    let policy = RedactionPolicy::builder().enable(PiiType::Secret).build();
+    Redactor::new(detectors, policy)
+}
+
+fn controls(text: &str) -> Vec<char> {
+    text.chars().filter(|&c| is_bidi_control(c)).collect()
+}
+
+#[test]
+fn email_in_rtl_text_between_isolates() {
+    let input = format!("{RLI}مرحبا، راسلني على {LRI}jane@example.com{PDI} شكرا{PDI}");
+    let result = bidi_redactor().redact(&input);
+    assert_eq!(
+        result,
+        format!("{RLI}مرحبا، راسلني على {LRI}████@███████.com{PDI} شكرا{PDI}")
+    );
+    assert_eq!(controls(&result), controls(&input));
+}
+
+#[test]
+fn hebrew_with_marks_around_numbers() {
+    let input = format!("מספר {LRM}123-45-6789{LRM} וכרטיס {LRM}4111 1111 1111 1111{RLM} תודה");
+    let result = bidi_redactor().redact(&input);
+    assert_eq!(
+        result,
+        format!("מספר {LRM}███-██-████{LRM} וכרטיס {LRM}████ ████ ████ 1111{RLM} תודה")
+    );
+    assert_eq!(controls(&result), controls(&input));
+}
+
+#[test]
+fn phone_adjacent_to_controls_is_detected() {
+    // Multi-byte neighbors used to panic the boundary checks
+    let input = format!("טלפון:{LRI}+1 202 555 0123{PDI}");
+    let result = bidi_redactor().redact(&input);
+    assert!(!result.contains("555"));
+    assert!(result.starts_with(&format!("טלפון:{LRI}")));
+    assert!(result.ends_with(PDI));
+    assert_eq!(controls(&result), controls(&input));
+}
+
+#[test]
+fn secret_value_stops_at_control() {
+    let input = format!("{RLI}token=abc123{PDI} סוף");
+    let result = bidi_redactor().redact(&input);
+    assert_eq!(result, format!("{RLI}token=██████{PDI} סוף"));
+}
+
+#[test]
+fn every_default_detector_survives_bidi_neighbors() {
+    let samples = [
+        "jane@example.com",
+        "+1 202 555 0123",
+        "123-45-6789",
+        "4111 1111 1111 1111",
+        "192.168.1.20",
+        "DE89370400440532013000",
+        "token=abc123",
+    ];
+    let all_controls = [
+        '\u{061C}', LRM, RLM, '\u{202A}', '\u{202B}', '\u{202C}', '\u{202D}', '\u{202E}', LRI, RLI,
+        '\u{2068}', PDI,
+    ];
+    for pii_type in [
+        PiiType::Email,
+        PiiType::PhoneNumber,
+        PiiType::Ssn,
+        PiiType::CreditCard,
+        PiiType::IpAddressV4,
+        PiiType::Iban,
+        PiiType::Secret,
+    ] {
+        let redactor = Redactor::new(
+            vec![detectors::default_detector(pii_type).unwrap()],
+            RedactionPolicy::builder().enable(pii_type).build(),
+        );
+        for c in all_controls {
+            for sample in samples {
+                let input = format!("שלום {c}{sample}{c} עולם");
+                let result = redactor.redact(&input);
+                assert_eq!(
+                    controls(&result),
+                    controls(&input),
+                    "{pii_type:?} dropped a control in {input:?}"
+                );
+                assert!(result.starts_with("שלום ") && result.ends_with(" עולם"));
+            }
+        }
+    }
+}
@@ -272,3 +272,34 @@ fn ip_address_various_formats() {
     assert!(!result2.contains("192.168.1.1"));
     assert!(result2.contains(":8080"));
 }
+
+#[test]
+fn bom_prefixed_document_with_email() {
+    let redactor = full_redactor();
+    let input = "\u{FEFF}Contact john@example.com\r\n";
+    let result = redactor.redact(input);
+    assert_eq!(result, "\u{FEFF}Contact ████@███████.com\r\n");
+
+    let detectors: Vec<Box<dyn PiiDetector>> = vec![Box::new(EmailDetector::new())];
+    let policy = RedactionPolicy::builder().strip_bom(true).build();
+    let redactor = Redactor::new(detectors, policy);
+    assert_eq!(redactor.redact(input), "Contact ████@███████.com\r\n");
+}
+
+#[test]
+fn crlf_separated_record_with_phone() {
+    let detectors: Vec<Box<dyn PiiDetector>> = vec![Box::new(PhoneNumberDetector::new())];
+    let policy = RedactionPolicy::builder().normalize_newlines(true).build();
+    let redactor = Redactor::new(detectors, policy);
+
+    let input = "id,name,phone\r\n1,Bob,(202) 555-0123\r\n2,Al,none\r\n";
+    let result = redactor.redact(input);
+    assert_eq!(
+        result,
+        "id,name,phone\r\n1,Bob,(███) ███-████\r\n2,Al,none\r\n"
+    );
+    assert_eq!(
+        redactor.redaction_ranges(input),
+        vec![(21, 35, auvura_core::types::PiiType::PhoneNumber)]
+    );
+}
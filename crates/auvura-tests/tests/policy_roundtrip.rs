@@ -220,3 +220,60 @@ fn policy_functionality_preserved_after_round_trip() {
     assert!(!result_original.contains("john@example.com"));
     assert!(result_original.contains("123-45-6789"));
 }
+
+#[test]
+fn policy_loads_from_toml_like_hipaa_preset() {
+    let policy: RedactionPolicy = toml::from_str(
+        r#"
+        enabled_types = [
+            "credit_card", "date_of_birth", "email", "iban", "ip_address_v4", "ip_address_v6",
+            "medicare_beneficiary_id", "national_id", "passport_number",
+            "phone_number", "physical_address", "ssn",
+        ]
+        allowlist = ["hospital", "clinic", "medical center"]
+        strict_validation = true
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&policy).unwrap(),
+        serde_json::to_value(RedactionPolicy::hipaa()).unwrap()
+    );
+}
+
+#[test]
+fn policy_serde_round_trip_preserves_settings() {
+    let policy = RedactionPolicy::builder()
+        .enable(PiiType::Email)
+        .disable(PiiType::CreditCard)
+        .strict_validation(false)
+        .with_placeholder(PiiType::Email, "<email>")
+        .with_allowlist(vec!["support@example.com"])
+        .with_blocklist(vec!["Project Falcon"])
+        .build();
+
+    let json = serde_json::to_value(&policy).unwrap();
+    assert!(json["enabled_types"]
+        .as_array()
+        .unwrap()
+        .contains(&serde_json::json!("email")));
+
+    let restored: RedactionPolicy = serde_json::from_value(json.clone()).unwrap();
+    assert!(!restored.requires_validation());
+    assert!(!restored.is_enabled(PiiType::CreditCard));
+    assert_eq!(restored.custom_placeholder(PiiType::Email), Some("<email>"));
+    assert_eq!(restored.allowlist_terms(), ["support@example.com"]);
+    assert_eq!(restored.blocklist_terms(), ["Project Falcon"]);
+    assert_eq!(serde_json::to_value(&restored).unwrap(), json);
+}
+
+#[test]
+fn pii_type_serializes_as_lowercase_name() {
+    assert_eq!(
+        serde_json::to_string(&PiiType::PhoneNumber).unwrap(),
+        "\"phone_number\""
+    );
+    let parsed: PiiType = serde_json::from_str("\"ssn\"").unwrap();
+    assert_eq!(parsed, PiiType::Ssn);
+}
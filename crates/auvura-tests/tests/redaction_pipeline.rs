@@ -8,21 +8,24 @@ use auvura_core::detectors::email::EmailDetector;
 use auvura_core::detectors::ip::{Ipv4Detector, Ipv6Detector};
 use auvura_core::detectors::phone_number::PhoneNumberDetector;
 use auvura_core::detectors::ssn::SSNDetector;
-use auvura_core::policy::RedactionPolicy;
+use auvura_core::policy::{RedactionMode, RedactionPolicy};
 use auvura_core::redactor::Redactor;
 use auvura_core::types::PiiType;
 
 /// Build a full-featured redactor with all detectors enabled.
 fn full_redactor() -> Redactor {
-    let detectors: Vec<Box<dyn PiiDetector>> = vec![
+    Redactor::new(all_detectors(), RedactionPolicy::default())
+}
+
+fn all_detectors() -> Vec<Box<dyn PiiDetector>> {
+    vec![
         Box::new(EmailDetector::new()),
         Box::new(PhoneNumberDetector::new()),
         Box::new(SSNDetector::new()),
         Box::new(CreditCardDetector::new()),
         Box::new(Ipv4Detector::new()),
         Box::new(Ipv6Detector::new()),
-    ];
-    Redactor::new(detectors, RedactionPolicy::default())
+    ]
 }
 
 #[test]
@@ -233,3 +236,30 @@ fn repeated_pii_patterns() {
     // But @ symbols should remain (from structured redaction)
     assert!(result.matches('@').count() >= 3);
 }
+
+#[test]
+fn redacting_redacted_output_is_a_no_op() {
+    let input = "Mail john@example.com, call (202) 555-0124, SSN 123-45-6789, \
+                 card 4111 1111 1111 1111, host 192.168.1.100";
+
+    for mode in [
+        RedactionMode::Mask,
+        RedactionMode::Replace,
+        RedactionMode::Hash,
+        RedactionMode::Tokenize,
+    ] {
+        let policy = RedactionPolicy::builder().with_mode(mode).build();
+        let redactor = Redactor::new(all_detectors(), policy);
+
+        let once = redactor.redact(input).into_owned();
+        assert_ne!(once, input, "{mode}");
+        assert!(!redactor.verify(input), "{mode}");
+
+        let twice = redactor.redact(&once);
+        assert!(
+            matches!(twice, std::borrow::Cow::Borrowed(_)),
+            "{mode}: {twice}"
+        );
+        assert!(redactor.verify(&once), "{mode}: {once}");
+    }
+}